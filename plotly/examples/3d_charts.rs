@@ -0,0 +1,242 @@
+use itertools_num::linspace;
+use plotly::common::{Domain, Mode, Title};
+use plotly::cone::Anchor;
+use plotly::layout::{
+    AspectMode, AspectRatio, Camera, CameraPosition, LayoutScene, SceneAxis, SceneId,
+};
+use plotly::mesh3d::{Contour, IntensityMode};
+use plotly::scatter3d::{Marker3D, Projection, ProjectionAxis};
+use plotly::surface::Lighting;
+use plotly::volume::{OpacityScale, Slice, Slices};
+use plotly::{Cone, Layout, Mesh3D, Plot, Scatter3D, Surface, Volume};
+
+// 3D Scatter Plots
+fn simple_scatter3d_plot(show: bool) {
+    let n: usize = 100;
+    let t: Vec<f64> = linspace(0., 10., n).collect();
+    let x: Vec<f64> = t.iter().map(|t| t.cos()).collect();
+    let y: Vec<f64> = t.iter().map(|t| t.sin()).collect();
+
+    let trace = Scatter3D::new(x, y, t.clone())
+        .mode(Mode::Markers)
+        .marker(Marker3D::new().size(4).color_array(t));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("simple_scatter3d_plot")));
+}
+
+fn scatter3d_plot_with_projections(show: bool) {
+    let n: usize = 50;
+    let t: Vec<f64> = linspace(0., 4., n).collect();
+    let x: Vec<f64> = t.iter().map(|t| (3. * t).cos()).collect();
+    let y: Vec<f64> = t.iter().map(|t| (3. * t).sin()).collect();
+
+    let trace = Scatter3D::new(x, y, t)
+        .mode(Mode::LinesMarkers)
+        .marker(Marker3D::new().size(3))
+        .projection(
+            Projection::new()
+                .x(ProjectionAxis::new().show(true).opacity(0.5))
+                .y(ProjectionAxis::new().show(true).opacity(0.5)),
+        );
+    let layout = Layout::new().scene(
+        LayoutScene::new()
+            .x_axis(SceneAxis::new().title(Title::new("cos(3t)")))
+            .y_axis(SceneAxis::new().title(Title::new("sin(3t)")))
+            .z_axis(SceneAxis::new().title(Title::new("t"))),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("scatter3d_plot_with_projections"))
+    );
+}
+
+fn scatter3d_plots_in_two_scenes(show: bool) {
+    let t: Vec<f64> = linspace(0., 10., 100).collect();
+    let x: Vec<f64> = t.iter().map(|t| t.cos()).collect();
+    let y: Vec<f64> = t.iter().map(|t| t.sin()).collect();
+
+    let trace1 = Scatter3D::new(x.clone(), y.clone(), t.clone()).mode(Mode::Lines);
+    let trace2 = Scatter3D::new(x, y, t)
+        .mode(Mode::Lines)
+        .scene(SceneId::new(2));
+    let layout = Layout::new()
+        .scene(LayoutScene::new().domain(Domain::new().x(vec![0., 0.5])))
+        .scene_n(
+            2,
+            LayoutScene::new()
+                .domain(Domain::new().x(vec![0.5, 1.]))
+                .camera(Camera::new().eye(CameraPosition::new(0., 0., 2.5))),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("scatter3d_plots_in_two_scenes"))
+    );
+}
+
+// Surface Plots
+fn surface_plot_with_camera_and_aspect_ratio(show: bool) {
+    let n: usize = 50;
+    let x: Vec<f64> = linspace(-5., 5., n).collect();
+    let y: Vec<f64> = linspace(-5., 5., n).collect();
+    let z: Vec<Vec<f64>> = y
+        .iter()
+        .map(|y| x.iter().map(|x| (x * x + y * y).sqrt().sin()).collect())
+        .collect();
+
+    let trace = Surface::new(z).x(x).y(y);
+    let layout = Layout::new().scene(
+        LayoutScene::new()
+            .camera(Camera::new().eye(CameraPosition::new(1.8, 1.8, 0.8)))
+            .aspect_mode(AspectMode::Manual)
+            .aspect_ratio(AspectRatio::new(1., 1., 0.4))
+            .z_axis(SceneAxis::new().title(Title::new("sin(r)"))),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("surface_plot_with_camera_and_aspect_ratio"))
+    );
+}
+
+// Mesh Plots
+fn mesh3d_tetrahedron(show: bool) {
+    let trace = Mesh3D::new(
+        vec![0., 1., 0.5, 0.5],
+        vec![0., 0., 0.9, 0.3],
+        vec![0., 0., 0., 0.8],
+    )
+    .i(vec![0, 0, 0, 1])
+    .j(vec![1, 2, 3, 2])
+    .k(vec![2, 3, 1, 3])
+    .intensity(vec![0., 0.33, 0.66, 1.])
+    .intensity_mode(IntensityMode::Vertex)
+    .contour(Contour::new().show(true).color("black").width(4.))
+    .lighting(Lighting::new().ambient(0.6).diffuse(0.8));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("mesh3d_tetrahedron")));
+}
+
+fn mesh3d_from_obj(show: bool) {
+    // An octahedron, as it would be read from a file with `Mesh3D::from_obj`.
+    let obj = "
+v 1 0 0
+v -1 0 0
+v 0 1 0
+v 0 -1 0
+v 0 0 1
+v 0 0 -1
+f 1 3 5
+f 3 2 5
+f 2 4 5
+f 4 1 5
+f 3 1 6
+f 2 3 6
+f 4 2 6
+f 1 4 6
+";
+    let trace = Mesh3D::from_obj_str(obj)
+        .unwrap()
+        .face_color(vec![
+            "red", "green", "blue", "orange", "purple", "cyan", "yellow", "grey",
+        ])
+        .flat_shading(true);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("mesh3d_from_obj")));
+}
+
+// Volume Plots
+fn volume_plot_with_slices(show: bool) {
+    let grid: Vec<f64> = linspace(-2., 2., 15).collect();
+    let (mut x, mut y, mut z, mut value) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for xi in grid.iter() {
+        for yi in grid.iter() {
+            for zi in grid.iter() {
+                x.push(*xi);
+                y.push(*yi);
+                z.push(*zi);
+                value.push((-(xi * xi + yi * yi + zi * zi)).exp());
+            }
+        }
+    }
+
+    let trace = Volume::new(x, y, z, value)
+        .opacity(0.3)
+        .opacity_scale(OpacityScale::Max)
+        .slices(Slices::new().z(Slice::new().show(true).locations(vec![0.])));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("volume_plot_with_slices")));
+}
+
+// Vector Field Plots
+fn cone_plot(show: bool) {
+    let trace = Cone::new(
+        vec![1., 2., 3.],
+        vec![1., 2., 3.],
+        vec![1., 2., 3.],
+        vec![1., 0., 0.],
+        vec![0., 2., 0.],
+        vec![0., 0., 3.],
+    )
+    .anchor(Anchor::Tip);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("cone_plot")));
+}
+
+fn main() -> std::io::Result<()> {
+    // 3D Scatter Plots
+    simple_scatter3d_plot(true);
+    scatter3d_plot_with_projections(true);
+    scatter3d_plots_in_two_scenes(true);
+
+    // Surface Plots
+    surface_plot_with_camera_and_aspect_ratio(true);
+
+    // Mesh Plots
+    mesh3d_tetrahedron(true);
+    mesh3d_from_obj(true);
+
+    // Volume Plots
+    volume_plot_with_slices(true);
+
+    // Vector Field Plots
+    cone_plot(true);
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use itertools_num::linspace;
 use plotly::common::{
-    ColorScale, ColorScalePalette, DashType, Fill, Font, Line, LineShape, Marker, Mode, Title,
+    ColorScale, ColorScalePalette, DashType, Domain, Fill, Font, Line, LineShape, Marker, Mode,
+    Title,
 };
-use plotly::layout::{Axis, BarMode, Layout, Legend, TicksDirection};
-use plotly::{Bar, NamedColor, Plot, Rgb, Rgba, Scatter};
+use plotly::hierarchy::Hierarchy;
+use plotly::layout::{Axis, BarMode, GridPattern, Layout, LayoutGrid, Legend, TicksDirection};
+use plotly::pie::{Marker as PieMarker, MarkerLine, TextInfo};
+use plotly::sunburst::{BranchValues, TextInfo as HierarchyTextInfo};
+use plotly::treemap::{PathBar, Tiling};
+use plotly::{Bar, NamedColor, Pie, Plot, Rgb, Rgba, Scatter, Sunburst, Treemap};
 use rand_distr::{Distribution, Normal, Uniform};
 
 // Scatter Plots
@@ -589,6 +594,133 @@ fn stacked_bar_chart(show: bool) {
     println!("{}", plot.to_inline_html(Some("stacked_bar_chart")));
 }
 
+// Pie Charts
+fn basic_pie_chart(show: bool) {
+    let t = Pie::new(
+        vec!["Residential", "Non-Residential", "Utility"],
+        vec![19, 26, 55],
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_pie_chart")));
+}
+
+fn styled_pie_chart(show: bool) {
+    let t = Pie::new(
+        vec!["Oxygen", "Hydrogen", "Carbon", "Nitrogen"],
+        vec![4500, 2500, 1053, 500],
+    )
+    .hole(0.4)
+    .pull_array(vec![0., 0., 0.2, 0.])
+    .text_info(TextInfo::LABEL | TextInfo::PERCENT)
+    .marker(
+        PieMarker::new()
+            .colors(vec!["gold", "mediumturquoise", "darkorange", "lightgreen"])
+            .line(MarkerLine::new().color(NamedColor::Black).width(2.)),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("styled_pie_chart")));
+}
+
+fn multiple_pie_charts(show: bool) {
+    let labels = vec![
+        "US",
+        "China",
+        "European Union",
+        "Russian Federation",
+        "Brazil",
+    ];
+    let trace1 = Pie::new(labels.clone(), vec![16, 15, 12, 6, 5])
+        .name("GHG Emissions")
+        .domain(Domain::new().row(0).column(0));
+    let trace2 = Pie::new(labels, vec![27, 11, 25, 8, 1])
+        .name("CO2 Emissions")
+        .domain(Domain::new().row(0).column(1));
+
+    let layout = Layout::new().grid(
+        LayoutGrid::new()
+            .rows(1)
+            .columns(2)
+            .pattern(GridPattern::Independent),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("multiple_pie_charts")));
+}
+
+// Sunburst and Treemap Charts
+fn basic_sunburst_chart(show: bool) {
+    let t = Sunburst::new(
+        vec![
+            "Eve", "Cain", "Seth", "Enos", "Noam", "Abel", "Awan", "Enoch", "Azura",
+        ],
+        vec![
+            "", "Eve", "Eve", "Seth", "Seth", "Eve", "Eve", "Awan", "Eve",
+        ],
+        vec![10, 14, 12, 10, 2, 6, 6, 4, 4],
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_sunburst_chart")));
+}
+
+fn sunburst_chart_of_costs(show: bool) {
+    let costs = vec![
+        ("infrastructure/compute", 420.),
+        ("infrastructure/storage", 130.),
+        ("infrastructure/network", 60.),
+        ("staff/engineering", 900.),
+        ("staff/support", 250.),
+        ("licenses", 80.),
+    ];
+    let hierarchy = Hierarchy::from_paths(costs, '/').with_totals();
+    let t = Sunburst::from_hierarchy(hierarchy)
+        .branch_values(BranchValues::Total)
+        .text_info(HierarchyTextInfo::LABEL | HierarchyTextInfo::PERCENT_ROOT);
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("sunburst_chart_of_costs")));
+}
+
+fn treemap_of_disk_usage(show: bool) {
+    let files = vec![
+        ("usr/lib/libc.so", 2.1),
+        ("usr/lib/libstdc++.so", 1.9),
+        ("usr/bin/bash", 1.2),
+        ("usr/share/doc", 0.6),
+        ("var/log/syslog", 0.8),
+        ("home/user/photos", 3.4),
+    ];
+    let t = Treemap::from_hierarchy(Hierarchy::from_paths(files, '/'))
+        .tiling(Tiling::new().pad(4.))
+        .path_bar(PathBar::new().visible(true));
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("treemap_of_disk_usage")));
+}
+
 fn main() -> std::io::Result<()> {
     // Scatter Plots
     simple_scatter_plot(true);
@@ -611,5 +743,15 @@ fn main() -> std::io::Result<()> {
     basic_bar_chart(true);
     grouped_bar_chart(true);
     stacked_bar_chart(true);
+
+    // Pie Charts
+    basic_pie_chart(true);
+    styled_pie_chart(true);
+    multiple_pie_charts(true);
+
+    // Sunburst and Treemap Charts
+    basic_sunburst_chart(true);
+    sunburst_chart_of_costs(true);
+    treemap_of_disk_usage(true);
     Ok(())
 }
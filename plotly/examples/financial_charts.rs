@@ -1,6 +1,7 @@
-use plotly::common::{TickFormatStop, Title};
+use plotly::common::{Line, TickFormatStop, Title};
 use plotly::layout::{Axis, RangeSelector, RangeSlider, SelectorButton, SelectorStep, StepMode};
-use plotly::{Candlestick, Layout, Ohlc, Plot, Scatter};
+use plotly::waterfall::{Connector, Marker, Measure, TextInfo};
+use plotly::{Candlestick, Layout, Ohlc, Plot, Scatter, Waterfall};
 use serde::Deserialize;
 use std::env;
 use std::path::PathBuf;
@@ -315,6 +316,46 @@ fn simple_ohlc_chart(show: bool) {
     println!("{}", plot.to_inline_html(Some("simple_ohlc_chart")));
 }
 
+// Waterfall Charts
+fn profit_and_loss_waterfall_chart(show: bool) {
+    let t = Waterfall::new(
+        vec![
+            "Sales",
+            "Consulting",
+            "Net revenue",
+            "Purchases",
+            "Other expenses",
+            "Profit before tax",
+        ],
+        vec![60, 80, 0, -40, -20, 0],
+    )
+    .measure(vec![
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+    ])
+    .text_info(TextInfo::DELTA)
+    .connector(Connector::new().line(Line::new().color("rgb(63, 63, 63)")))
+    .increasing(Marker::new().color("#2CA02C"))
+    .decreasing(Marker::new().color("#D62728"))
+    .totals(Marker::new().color("#1F77B4"));
+
+    let layout = Layout::new().title(Title::new("Profit and loss statement 2018"));
+    let mut plot = Plot::new();
+    plot.add_trace(t);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("profit_and_loss_waterfall_chart"))
+    );
+}
+
 fn main() -> std::io::Result<()> {
     // Time Series and Date Axes
     time_series_plot_with_custom_date_range(true);
@@ -328,5 +369,8 @@ fn main() -> std::io::Result<()> {
     // OHLC Charts
     simple_ohlc_chart(true);
 
+    // Waterfall Charts
+    profit_and_loss_waterfall_chart(true);
+
     Ok(())
 }
@@ -1,7 +1,8 @@
 use itertools_num::linspace;
 use plotly::common::{Fill, Font, Mode};
 use plotly::layout::{
-    Axis, GridPattern, Layout, LayoutGrid, Margin, Shape, ShapeLayer, ShapeLine, ShapeType,
+    Axis, AxisRef, GridPattern, Layout, LayoutGrid, Margin, Shape, ShapeLayer, ShapeLine,
+    ShapePath, ShapeType, XAxisId, XRef, YAxisId, YRef,
 };
 use plotly::{Bar, NamedColor, Plot, Scatter};
 use rand::thread_rng;
@@ -102,8 +103,8 @@ fn lines_positioned_relative_to_the_plot_and_to_the_axes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Line)
             .x0(4)
             .y0(0)
@@ -113,8 +114,8 @@ fn lines_positioned_relative_to_the_plot_and_to_the_axes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("paper")
-            .y_ref("paper")
+            .x_ref(XRef::Paper)
+            .y_ref(YRef::Paper)
             .shape_type(ShapeType::Line)
             .x0(0.0)
             .y0(0.0)
@@ -148,8 +149,8 @@ fn creating_tangent_lines_with_shapes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .opacity(0.7)
             .shape_type(ShapeType::Line)
             .x0(1.)
@@ -160,8 +161,8 @@ fn creating_tangent_lines_with_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .opacity(0.7)
             .shape_type(ShapeType::Line)
             .x0(2.5)
@@ -172,8 +173,8 @@ fn creating_tangent_lines_with_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .opacity(0.7)
             .shape_type(ShapeType::Line)
             .x0(1.90)
@@ -206,8 +207,8 @@ fn rectangles_positioned_relative_to_the_axes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Rect)
             .x0(1.)
             .y0(1.)
@@ -217,8 +218,8 @@ fn rectangles_positioned_relative_to_the_axes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Rect)
             .x0(3.)
             .y0(1.)
@@ -254,8 +255,8 @@ fn rectangle_positioned_relative_to_the_plot_and_to_the_axes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Rect)
             .x0(2.5)
             .y0(0.0)
@@ -266,8 +267,8 @@ fn rectangle_positioned_relative_to_the_plot_and_to_the_axes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("paper")
-            .y_ref("paper")
+            .x_ref(XRef::Paper)
+            .y_ref(YRef::Paper)
             .shape_type(ShapeType::Rect)
             .x0(0.25)
             .y0(0.0)
@@ -332,8 +333,8 @@ fn highlighting_time_series_regions_with_rectangle_shapes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("paper")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::Paper)
             .shape_type(ShapeType::Rect)
             .x0("2015-02-04")
             .y0(0)
@@ -346,8 +347,8 @@ fn highlighting_time_series_regions_with_rectangle_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("paper")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::Paper)
             .shape_type(ShapeType::Rect)
             .x0("2015-02-20")
             .y0(0)
@@ -386,8 +387,8 @@ fn circles_positioned_relative_to_the_axes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(1)
             .y0(1)
@@ -397,8 +398,8 @@ fn circles_positioned_relative_to_the_axes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(3)
             .y0(3)
@@ -474,8 +475,8 @@ fn highlighting_clusters_of_scatter_points_with_circle_shapes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(x0min)
             .y0(y0min)
@@ -487,8 +488,8 @@ fn highlighting_clusters_of_scatter_points_with_circle_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(x1min)
             .y0(y1min)
@@ -500,8 +501,8 @@ fn highlighting_clusters_of_scatter_points_with_circle_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(x2min)
             .y0(y2min)
@@ -513,8 +514,8 @@ fn highlighting_clusters_of_scatter_points_with_circle_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(x1min)
             .y0(y0min)
@@ -571,8 +572,8 @@ fn venn_diagram_with_circle_shapes(show: bool) {
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(0)
             .y0(0)
@@ -585,8 +586,8 @@ fn venn_diagram_with_circle_shapes(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x")
-            .y_ref("y")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Circle)
             .x0(1.5)
             .y0(0.)
@@ -612,23 +613,23 @@ fn adding_shapes_to_subplots(show: bool) {
     let mut plot = Plot::new();
     plot.add_trace(
         Scatter::new(vec![2, 6], vec![1, 1])
-            .x_axis("x1")
-            .y_axis("y1"),
+            .x_axis(XAxisId::new(1))
+            .y_axis(YAxisId::new(1)),
     );
     plot.add_trace(
         Bar::new(vec![1, 2, 3], vec![4, 5, 6])
-            .x_axis("x2")
-            .y_axis("y2"),
+            .x_axis(XAxisId::new(2))
+            .y_axis(YAxisId::new(2)),
     );
     plot.add_trace(
         Scatter::new(vec![10, 20], vec![40, 50])
-            .x_axis("x3")
-            .y_axis("y3"),
+            .x_axis(XAxisId::new(3))
+            .y_axis(YAxisId::new(3)),
     );
     plot.add_trace(
         Bar::new(vec![11, 13, 15], vec![8, 11, 20])
-            .x_axis("x4")
-            .y_axis("y4"),
+            .x_axis(XAxisId::new(4))
+            .y_axis(YAxisId::new(4)),
     );
 
     let mut layout = Layout::new()
@@ -638,19 +639,19 @@ fn adding_shapes_to_subplots(show: bool) {
                 .columns(2)
                 .pattern(GridPattern::Independent),
         )
-        .x_axis(Axis::new().domain(&[0.0, 0.48]).anchor("x1"))
-        .y_axis(Axis::new().domain(&[0.52, 1.]).anchor("y1"))
-        .x_axis2(Axis::new().domain(&[0.52, 1.0]).anchor("x2"))
-        .y_axis2(Axis::new().domain(&[0.5, 1.]).anchor("y2"))
-        .x_axis3(Axis::new().domain(&[0.0, 0.48]).anchor("x3"))
-        .y_axis3(Axis::new().domain(&[0.0, 0.48]).anchor("y3"))
-        .x_axis4(Axis::new().domain(&[0.52, 1.0]).anchor("x4"))
-        .y_axis4(Axis::new().domain(&[0.0, 0.48]).anchor("y4"));
+        .x_axis(Axis::new().domain(&[0.0, 0.48]).anchor(AxisRef::x(1)))
+        .y_axis(Axis::new().domain(&[0.52, 1.]).anchor(AxisRef::y(1)))
+        .x_axis_n(2, Axis::new().domain(&[0.52, 1.0]).anchor(AxisRef::x(2)))
+        .y_axis_n(2, Axis::new().domain(&[0.5, 1.]).anchor(AxisRef::y(2)))
+        .x_axis_n(3, Axis::new().domain(&[0.0, 0.48]).anchor(AxisRef::x(3)))
+        .y_axis_n(3, Axis::new().domain(&[0.0, 0.48]).anchor(AxisRef::y(3)))
+        .x_axis_n(4, Axis::new().domain(&[0.52, 1.0]).anchor(AxisRef::x(4)))
+        .y_axis_n(4, Axis::new().domain(&[0.0, 0.48]).anchor(AxisRef::y(4)));
 
     layout.add_shape(
         Shape::new()
-            .x_ref("x1")
-            .y_ref("y1")
+            .x_ref(XRef::x(1))
+            .y_ref(YRef::y(1))
             .shape_type(ShapeType::Line)
             .x0(3)
             .y0(0.5)
@@ -660,8 +661,8 @@ fn adding_shapes_to_subplots(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x2")
-            .y_ref("y2")
+            .x_ref(XRef::x(2))
+            .y_ref(YRef::y(2))
             .shape_type(ShapeType::Rect)
             .x0(4)
             .y0(2)
@@ -670,8 +671,8 @@ fn adding_shapes_to_subplots(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x3")
-            .y_ref("y3")
+            .x_ref(XRef::x(3))
+            .y_ref(YRef::y(3))
             .shape_type(ShapeType::Rect)
             .x0(10)
             .y0(20)
@@ -680,8 +681,8 @@ fn adding_shapes_to_subplots(show: bool) {
     );
     layout.add_shape(
         Shape::new()
-            .x_ref("x4")
-            .y_ref("y4")
+            .x_ref(XRef::x(4))
+            .y_ref(YRef::y(4))
             .shape_type(ShapeType::Circle)
             .x0(5)
             .y0(12)
@@ -725,26 +726,43 @@ fn svg_paths(show: bool) {
     layout.add_shape(
         Shape::new()
             .shape_type(ShapeType::Path)
-            .path("M 4,4 Q 6,0 8,4")
+            .path(ShapePath::new().move_to(4, 4).quadratic_to(6, 0, 8, 4))
             .line(ShapeLine::new().color(NamedColor::RoyalBlue)),
     );
     layout.add_shape(
         Shape::new()
             .shape_type(ShapeType::Path)
-            .path("M 1,4 C 2,8 6,4 8,8")
+            .path(ShapePath::new().move_to(1, 4).cubic_to(2, 8, 6, 4, 8, 8))
             .line(ShapeLine::new().color(NamedColor::MediumPurple)),
     );
     layout.add_shape(
         Shape::new()
             .shape_type(ShapeType::Path)
-            .path("M 1 1 L 1 3 L 4 1 Z")
+            .path(
+                ShapePath::new()
+                    .move_to(1, 1)
+                    .line_to(1, 3)
+                    .line_to(4, 1)
+                    .close(),
+            )
             .fill_color(NamedColor::LightPink)
             .line(ShapeLine::new().color(NamedColor::Crimson)),
     );
     layout.add_shape(
         Shape::new()
             .shape_type(ShapeType::Path)
-            .path("M 3,7 L2,8 L2,9 L3,10, L4,10 L5,9 L5,8 L4,7 Z")
+            .path(
+                ShapePath::new()
+                    .move_to(3, 7)
+                    .line_to(2, 8)
+                    .line_to(2, 9)
+                    .line_to(3, 10)
+                    .line_to(4, 10)
+                    .line_to(5, 9)
+                    .line_to(5, 8)
+                    .line_to(4, 7)
+                    .close(),
+            )
             .fill_color(NamedColor::PaleTurquoise)
             .line(ShapeLine::new().color(NamedColor::LightSeaGreen)),
     );
@@ -1,6 +1,8 @@
 use plotly::common::{Mode};
-use plotly::{Plot, Scatter};
-use ndarray::{Array, Ix1, Ix2};
+use plotly::streamtube::Starts;
+use plotly::{Cone, Isosurface, Plot, Scatter, Streamtube, Volume};
+use plotly::volume::{Caps, Cap, IsoSurfaces};
+use ndarray::{Array, Ix1, Ix2, Ix3};
 use plotly::ndarray::ArrayTraces;
 
 
@@ -69,10 +71,90 @@ fn multiple_ndarray_traces_over_rows(show: bool) {
     println!("{}", plot.to_inline_html(Some("multiple_ndarray_traces_over_rows")));
 }
 
+fn volume_from_ndarray(show: bool) {
+    let n: usize = 20;
+    let x: Array<f64, Ix1> = Array::linspace(-1., 1., n);
+    let values: Array<f64, Ix3> =
+        Array::from_shape_fn((n, n, n), |(i, j, k)| x[i].powi(2) + x[j].powi(2) + x[k].powi(2));
+
+    let trace = Volume::from_array(x.clone(), x.clone(), x, values)
+        .iso_min(0.1)
+        .iso_max(0.8)
+        .opacity(0.1)
+        .surface(IsoSurfaces::new().count(15));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("volume_from_ndarray")));
+}
+
+fn isosurface_from_ndarray(show: bool) {
+    let n: usize = 20;
+    let x: Array<f64, Ix1> = Array::linspace(-1., 1., n);
+    let values: Array<f64, Ix3> =
+        Array::from_shape_fn((n, n, n), |(i, j, k)| x[i].powi(2) + x[j].powi(2) - x[k]);
+
+    let trace = Isosurface::from_array(x.clone(), x.clone(), x, values)
+        .iso_min(-0.5)
+        .iso_max(0.5)
+        .surface(IsoSurfaces::new().count(3))
+        .caps(Caps::new().x(Cap::new().show(false)).y(Cap::new().show(false)));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("isosurface_from_ndarray")));
+}
+
+fn cone_from_ndarray(show: bool) {
+    let n: usize = 6;
+    let x: Array<f64, Ix1> = Array::linspace(-1., 1., n);
+    let u: Array<f64, Ix3> = Array::from_shape_fn((n, n, n), |(_, j, _)| -x[j]);
+    let v: Array<f64, Ix3> = Array::from_shape_fn((n, n, n), |(i, _, _)| x[i]);
+    let w: Array<f64, Ix3> = Array::from_shape_fn((n, n, n), |(_, _, k)| 0.2 * x[k]);
+
+    let trace = Cone::from_array(x.clone(), x.clone(), x, u, v, w).size_ref(0.5);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("cone_from_ndarray")));
+}
+
+fn streamtube_from_ndarray(show: bool) {
+    let n: usize = 10;
+    let x: Array<f64, Ix1> = Array::linspace(-1., 1., n);
+    let u: Array<f64, Ix3> = Array::from_shape_fn((n, n, n), |(_, j, _)| -x[j]);
+    let v: Array<f64, Ix3> = Array::from_shape_fn((n, n, n), |(i, _, _)| x[i]);
+    let w: Array<f64, Ix3> = Array::from_elem((n, n, n), 0.3);
+
+    let trace = Streamtube::from_array(x.clone(), x.clone(), x, u, v, w)
+        .starts(Starts::new(vec![0.2, 0.5, 0.8], vec![0., 0., 0.], vec![-1., -1., -1.]))
+        .size_ref(0.5);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("streamtube_from_ndarray")));
+}
+
 fn main() -> std::io::Result<()> {
     single_ndarray_trace(true);
     multiple_ndarray_traces_over_columns(true);
     multiple_ndarray_traces_over_rows(true);
+    volume_from_ndarray(true);
+    isosurface_from_ndarray(true);
+    cone_from_ndarray(true);
+    streamtube_from_ndarray(true);
 
     Ok(())
 }
@@ -1,6 +1,9 @@
-use plotly::common::{ColorScale, ColorScalePalette, Title};
+use plotly::common::{
+    ColorScale, ColorScalePalette, Domain, Fill, Marker, Mode, RotationDirection, Title,
+};
 use plotly::contour::Contours;
-use plotly::{Contour, HeatMap, Layout, Plot};
+use plotly::layout::{AngularAxis, GridShape, LayoutPolar, PolarBarMode, PolarId, RadialAxis};
+use plotly::{BarPolar, Contour, HeatMap, Layout, NamedColor, Plot, ScatterPolar, ScatterPolarGL};
 use std::f64::consts::PI;
 
 // Contour Plots
@@ -128,6 +131,114 @@ fn basic_heat_map(show: bool) {
     println!("{}", plot.to_inline_html(Some("basic_heat_map")));
 }
 
+// Polar Charts
+fn basic_polar_chart(show: bool) {
+    let theta: Vec<f64> = (0..=360).step_by(5).map(|t| t as f64).collect();
+    let r: Vec<f64> = theta
+        .iter()
+        .map(|t| 1. + (4. * t * PI / 180.).sin().abs())
+        .collect();
+    let trace = ScatterPolar::new(r, theta).mode(Mode::Lines);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_polar_chart")));
+}
+
+fn radar_chart(show: bool) {
+    let categories = vec!["Speed", "Handling", "Comfort", "Safety", "Economy", "Speed"];
+    let trace1 = ScatterPolar::new(vec![4, 3, 2, 5, 3, 4], categories.clone())
+        .name("Car A")
+        .fill(Fill::ToSelf);
+    let trace2 = ScatterPolar::new(vec![2, 4, 5, 3, 4, 2], categories)
+        .name("Car B")
+        .fill(Fill::ToSelf);
+
+    let layout = Layout::new().polar(
+        LayoutPolar::new()
+            .grid_shape(GridShape::Linear)
+            .radial_axis(RadialAxis::new().range(vec![0, 5])),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("radar_chart")));
+}
+
+fn wind_rose_chart(show: bool) {
+    let directions = vec!["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    let trace1 = BarPolar::new(vec![2.5, 1.5, 1., 1.5, 2., 3., 3.5, 3.], directions.clone())
+        .name("< 5 m/s")
+        .marker(Marker::new().color(NamedColor::LightSkyBlue));
+    let trace2 = BarPolar::new(vec![2., 1., 0.5, 1., 2.5, 3.5, 4., 3.], directions.clone())
+        .name("5-10 m/s")
+        .marker(Marker::new().color(NamedColor::SteelBlue));
+    let trace3 = BarPolar::new(vec![1., 0.5, 0., 0.5, 1.5, 2.5, 3., 2.], directions)
+        .name("> 10 m/s")
+        .marker(Marker::new().color(NamedColor::MidnightBlue));
+
+    let layout = Layout::new()
+        .title(Title::new("Wind Speed Distribution"))
+        .polar(
+            LayoutPolar::new()
+                .bar_mode(PolarBarMode::Stack)
+                .bar_gap(0.1)
+                .angular_axis(AngularAxis::new().direction(RotationDirection::Clockwise)),
+        );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.add_trace(trace3);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("wind_rose_chart")));
+}
+
+fn polar_subplots(show: bool) {
+    let theta: Vec<f64> = (0..180).map(|t| t as f64).collect();
+    let r: Vec<f64> = theta.iter().map(|t| (3. * t * PI / 180.).cos()).collect();
+    let trace1 = ScatterPolar::new(r, theta).name("Rose").mode(Mode::Lines);
+
+    let n = 2_000;
+    let theta: Vec<f64> = (0..n).map(|i| 180. * i as f64 / n as f64).collect();
+    let r: Vec<f64> = (0..n).map(|i| ((i * 7919) % 1000) as f64 / 1000.).collect();
+    let trace2 = ScatterPolarGL::new(r, theta)
+        .name("WebGL points")
+        .mode(Mode::Markers)
+        .marker(Marker::new().size(2))
+        .subplot(PolarId::new(2));
+
+    let layout = Layout::new()
+        .polar(LayoutPolar::new().domain(Domain::new().x(vec![0., 0.46])))
+        .polar_n(
+            2,
+            LayoutPolar::new()
+                .domain(Domain::new().x(vec![0.54, 1.]))
+                .sector(vec![0., 180.])
+                .hole(0.2),
+        );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("polar_subplots")));
+}
+
 fn main() -> std::io::Result<()> {
     // Contour Plots
     simple_contour_plot(true);
@@ -137,5 +248,11 @@ fn main() -> std::io::Result<()> {
 
     // Heatmaps
     basic_heat_map(true);
+
+    // Polar Charts
+    basic_polar_chart(true);
+    radar_chart(true);
+    wind_rose_chart(true);
+    polar_subplots(true);
     Ok(())
 }
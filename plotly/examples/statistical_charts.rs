@@ -1,9 +1,16 @@
 use itertools_num::linspace;
 use plotly::box_plot::{BoxMean, BoxPoints};
 use plotly::common::{ErrorData, ErrorType, Line, Marker, Mode, Orientation, Title};
+use plotly::contour::{Contours, ContoursColoring};
 use plotly::histogram::{Bins, Cumulative, HistFunc, HistNorm};
-use plotly::layout::{Axis, BarMode, BoxMode, Layout, Margin};
-use plotly::{Bar, BoxPlot, Histogram, NamedColor, Plot, Rgb, Rgba, Scatter};
+use plotly::layout::{
+    Axis, AxisRef, BarMode, BoxMode, Layout, Margin, ViolinMode, XAxisId, YAxisId,
+};
+use plotly::violin::{MeanLine, ViolinBox, ViolinSide};
+use plotly::{
+    Bar, BoxPlot, Histogram, Histogram2d, Histogram2dContour, NamedColor, Plot, Rgb, Rgba, Scatter,
+    Violin,
+};
 use rand_distr::{Distribution, Normal, Uniform};
 
 // Error Bars
@@ -646,6 +653,119 @@ fn specify_binning_function(show: bool) {
     println!("{}", plot.to_inline_html(Some("specify_binning_function")));
 }
 
+// 2D Histograms
+fn basic_2d_histogram(show: bool) {
+    let x = sample_normal_distribution(10_000, 0.0, 1.0);
+    let y = sample_normal_distribution(10_000, 0.0, 0.5);
+    let trace = Histogram2d::new(x, y).n_bins_x(50).n_bins_y(50);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_2d_histogram")));
+}
+
+fn normalized_2d_histogram_with_bins(show: bool) {
+    let x = sample_uniform_distribution(10_000, 0.0, 4.0);
+    let y = sample_normal_distribution(10_000, 2.0, 1.0);
+    let trace = Histogram2d::new(x, y)
+        .hist_norm(HistNorm::Probability)
+        .x_bins(Bins::new(0., 4., 0.25))
+        .y_bins(Bins::new(-1., 5., 0.25))
+        .x_gap(1.)
+        .y_gap(1.);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("normalized_2d_histogram_with_bins"))
+    );
+}
+
+fn histogram_2d_contour_with_marginals(show: bool) {
+    let x = sample_normal_distribution(10_000, 0.0, 1.0);
+    let y = sample_normal_distribution(10_000, 1.0, 2.0);
+    let trace = Histogram2dContour::new(x, y)
+        .x_bin_group("x")
+        .y_bin_group("y")
+        .contours(Contours::new().coloring(ContoursColoring::HeatMap));
+    let (x_marginal, y_marginal) = trace.marginals();
+
+    let layout = Layout::new()
+        .show_legend(false)
+        .x_axis(Axis::new().domain(&[0., 0.85]))
+        .y_axis(Axis::new().domain(&[0., 0.85]))
+        .x_axis_n(2, Axis::new().domain(&[0.86, 1.]).anchor(AxisRef::y(1)))
+        .y_axis_n(2, Axis::new().domain(&[0.86, 1.]).anchor(AxisRef::x(1)));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.add_trace(x_marginal.y_axis(YAxisId::new(2)));
+    plot.add_trace(y_marginal.x_axis(XAxisId::new(2)));
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("histogram_2d_contour_with_marginals"))
+    );
+}
+
+// Violin Plots
+fn basic_violin_plot(show: bool) {
+    let y = vec![
+        10.07, 12.3, 11.15, 9.74, 13.61, 10.5, 8.92, 11.84, 12.95, 10.21, 9.33, 11.02, 14.1, 10.66,
+    ];
+    let trace = Violin::new(y)
+        .name("total bill")
+        .box_(ViolinBox::new().visible(true))
+        .mean_line(MeanLine::new().visible(true))
+        .points(BoxPoints::All)
+        .jitter(0.05);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_violin_plot")));
+}
+
+fn split_violin_plot(show: bool) {
+    let x = vec!["Thur", "Thur", "Thur", "Thur", "Fri", "Fri", "Fri", "Fri"];
+    let trace1 = Violin::new_xy(
+        x.clone(),
+        vec![10.1, 12.3, 8.5, 16.2, 11.4, 15.5, 9.8, 17.3],
+    )
+    .name("Male")
+    .side(ViolinSide::Negative)
+    .line(Line::new().color(NamedColor::Blue));
+    let trace2 = Violin::new_xy(x, vec![9.2, 11.8, 13.4, 8.7, 10.3, 14.7, 12.1, 9.9])
+        .name("Female")
+        .side(ViolinSide::Positive)
+        .line(Line::new().color(NamedColor::Green));
+
+    let layout = Layout::new()
+        .violin_mode(ViolinMode::Overlay)
+        .violin_gap(0.);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("split_violin_plot")));
+}
+
 fn main() -> std::io::Result<()> {
     // Error Bars
     basic_symmetric_error_bars(true);
@@ -676,5 +796,14 @@ fn main() -> std::io::Result<()> {
     normalized_histogram(true);
     specify_binning_function(true);
 
+    // 2D Histograms
+    basic_2d_histogram(true);
+    normalized_2d_histogram_with_bins(true);
+    histogram_2d_contour_with_marginals(true);
+
+    // Violin Plots
+    basic_violin_plot(true);
+    split_violin_plot(true);
+
     Ok(())
 }
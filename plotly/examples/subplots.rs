@@ -1,14 +1,16 @@
 use plotly::common::{Font, Side, Title};
-use plotly::layout::{Axis, GridPattern, Layout, LayoutGrid, Legend, RowOrder};
-use plotly::{Plot, Rgb, Scatter};
+use plotly::layout::{
+    Axis, AxisRef, GridPattern, Layout, LayoutGrid, Legend, RowOrder, XAxisId, YAxisId,
+};
+use plotly::{Plot, Rgb, Scatter, Subplots};
 
 // Subplots
 fn simple_subplot(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisId::new(2))
+        .y_axis(YAxisId::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -31,8 +33,8 @@ fn custom_sized_subplot(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisId::new(2))
+        .y_axis(YAxisId::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -40,8 +42,8 @@ fn custom_sized_subplot(show: bool) {
 
     let layout = Layout::new()
         .x_axis(Axis::new().domain(&[0., 0.7]))
-        .y_axis2(Axis::new().anchor("x2"))
-        .x_axis2(Axis::new().domain(&[0.8, 1.]));
+        .y_axis_n(2, Axis::new().anchor(AxisRef::x(2)))
+        .x_axis_n(2, Axis::new().domain(&[0.8, 1.]));
     plot.set_layout(layout);
     if show {
         plot.show();
@@ -53,14 +55,14 @@ fn multiple_subplots(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisId::new(2))
+        .y_axis(YAxisId::new(2));
     let trace3 = Scatter::new(vec![300, 400, 500], vec![600, 700, 800])
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisId::new(3))
+        .y_axis(YAxisId::new(3));
     let trace4 = Scatter::new(vec![4000, 5000, 6000], vec![7000, 8000, 9000])
-        .x_axis("x4")
-        .y_axis("y4");
+        .x_axis(XAxisId::new(4))
+        .y_axis(YAxisId::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -85,11 +87,11 @@ fn stacked_subplots(show: bool) {
     let trace1 = Scatter::new(vec![0, 1, 2], vec![10, 11, 12]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![100, 110, 120])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisId::new(2))
+        .y_axis(YAxisId::new(2));
     let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200])
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisId::new(3))
+        .y_axis(YAxisId::new(3));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -114,8 +116,8 @@ fn stacked_subplots_with_shared_x_axis(show: bool) {
     let trace1 = Scatter::new(vec![0, 1, 2], vec![10, 11, 12]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![100, 110, 120])
         .name("trace2")
-        .y_axis("y2");
-    let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200]).y_axis("y3");
+        .y_axis(YAxisId::new(2));
+    let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200]).y_axis(YAxisId::new(3));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -125,8 +127,8 @@ fn stacked_subplots_with_shared_x_axis(show: bool) {
     let layout = Layout::new()
         .y_axis(Axis::new().domain(&[0., 0.33]))
         .legend(Legend::new().trace_order("reversed"))
-        .y_axis2(Axis::new().domain(&[0.33, 0.66]))
-        .y_axis3(Axis::new().domain(&[0.66, 1.]));
+        .y_axis_n(2, Axis::new().domain(&[0.33, 0.66]))
+        .y_axis_n(3, Axis::new().domain(&[0.66, 1.]));
     plot.set_layout(layout);
     if show {
         plot.show();
@@ -141,16 +143,16 @@ fn multiple_custom_sized_subplots(show: bool) {
     let trace1 = Scatter::new(vec![1, 2], vec![1, 2]).name("(1,1)");
     let trace2 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("(1,2,1)")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisId::new(2))
+        .y_axis(YAxisId::new(2));
     let trace3 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("(1,2,2)")
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisId::new(3))
+        .y_axis(YAxisId::new(3));
     let trace4 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("{(2,1), (2,2)}")
-        .x_axis("x4")
-        .y_axis("y4");
+        .x_axis(XAxisId::new(4))
+        .y_axis(YAxisId::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -160,14 +162,14 @@ fn multiple_custom_sized_subplots(show: bool) {
 
     let layout = Layout::new()
         .title(Title::new("Multiple Custom Sized Subplots"))
-        .x_axis(Axis::new().domain(&[0., 0.45]).anchor("y1"))
-        .y_axis(Axis::new().domain(&[0.5, 1.]).anchor("x1"))
-        .x_axis2(Axis::new().domain(&[0.55, 1.]).anchor("y2"))
-        .y_axis2(Axis::new().domain(&[0.8, 1.]).anchor("x2"))
-        .x_axis3(Axis::new().domain(&[0.55, 1.]).anchor("y3"))
-        .y_axis3(Axis::new().domain(&[0.5, 0.75]).anchor("x3"))
-        .x_axis4(Axis::new().domain(&[0., 1.]).anchor("y4"))
-        .y_axis4(Axis::new().domain(&[0., 0.45]).anchor("x4"));
+        .x_axis(Axis::new().domain(&[0., 0.45]).anchor(AxisRef::y(1)))
+        .y_axis(Axis::new().domain(&[0.5, 1.]).anchor(AxisRef::x(1)))
+        .x_axis_n(2, Axis::new().domain(&[0.55, 1.]).anchor(AxisRef::y(2)))
+        .y_axis_n(2, Axis::new().domain(&[0.8, 1.]).anchor(AxisRef::x(2)))
+        .x_axis_n(3, Axis::new().domain(&[0.55, 1.]).anchor(AxisRef::y(3)))
+        .y_axis_n(3, Axis::new().domain(&[0.5, 0.75]).anchor(AxisRef::x(3)))
+        .x_axis_n(4, Axis::new().domain(&[0., 1.]).anchor(AxisRef::y(4)))
+        .y_axis_n(4, Axis::new().domain(&[0., 0.45]).anchor(AxisRef::x(4)));
     plot.set_layout(layout);
     if show {
         plot.show();
@@ -178,12 +180,47 @@ fn multiple_custom_sized_subplots(show: bool) {
     );
 }
 
+fn subplots_builder(show: bool) {
+    let mut subplots = Subplots::new(2, 2)
+        .shared_x_axes(true)
+        .span(2, 1, 1, 2)
+        .row_heights(vec![2., 1.])
+        .secondary_y(1, 2, Axis::new().title(Title::new("secondary")))
+        .subplot_titles(vec!["First", "Second", "Wide"]);
+    subplots.add_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1"),
+        1,
+        1,
+    );
+    subplots.add_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![6, 5, 4]).name("trace2"),
+        1,
+        2,
+    );
+    subplots.add_secondary_y_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![60, 40, 50]).name("trace3"),
+        1,
+        2,
+    );
+    subplots.add_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![1, 3, 2]).name("trace4"),
+        2,
+        1,
+    );
+
+    let plot = subplots.into_plot(Layout::new().title(Title::new("Subplots Builder")));
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("subplots_builder")));
+}
+
 // Multiple Axes
 fn two_y_axes(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![40, 50, 60]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![4, 5, 6])
         .name("trace2")
-        .y_axis("y2");
+        .y_axis(YAxisId::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -192,11 +229,12 @@ fn two_y_axes(show: bool) {
     let layout = Layout::new()
         .title(Title::new("Double Y Axis Example"))
         .y_axis(Axis::new().title(Title::new("yaxis title")))
-        .y_axis2(
+        .y_axis_n(
+            2,
             Axis::new()
                 .title(Title::new("yaxis2 title").font(Font::new().color(Rgb::new(148, 103, 189))))
                 .tick_font(Font::new().color(Rgb::new(148, 103, 189)))
-                .overlaying("y")
+                .overlaying(AxisRef::y(1))
                 .side(Side::Right),
         );
     plot.set_layout(layout);
@@ -210,9 +248,10 @@ fn multiple_axes(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![40, 50, 60])
         .name("trace2")
-        .y_axis("y2");
-    let trace3 = Scatter::new(vec![4, 5, 6], vec![40_000, 50_000, 60_000]).y_axis("y3");
-    let trace4 = Scatter::new(vec![5, 6, 7], vec![400_000, 500_000, 600_000]).y_axis("y4");
+        .y_axis(YAxisId::new(2));
+    let trace3 = Scatter::new(vec![4, 5, 6], vec![40_000, 50_000, 60_000]).y_axis(YAxisId::new(3));
+    let trace4 =
+        Scatter::new(vec![5, 6, 7], vec![400_000, 500_000, 600_000]).y_axis(YAxisId::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -229,29 +268,32 @@ fn multiple_axes(show: bool) {
                 .title(Title::new("yaxis title").font(Font::new().color("#1f77b4")))
                 .tick_font(Font::new().color("#1f77b4")),
         )
-        .y_axis2(
+        .y_axis_n(
+            2,
             Axis::new()
                 .title(Title::new("yaxis2 title").font(Font::new().color("#ff7f0e")))
                 .tick_font(Font::new().color("#ff7f0e"))
-                .anchor("free")
-                .overlaying("y")
+                .anchor(AxisRef::Free)
+                .overlaying(AxisRef::y(1))
                 .side(Side::Left)
                 .position(0.15),
         )
-        .y_axis3(
+        .y_axis_n(
+            3,
             Axis::new()
                 .title(Title::new("yaxis3 title").font(Font::new().color("#d62728")))
                 .tick_font(Font::new().color("#d62728"))
-                .anchor("x")
-                .overlaying("y")
+                .anchor(AxisRef::x(1))
+                .overlaying(AxisRef::y(1))
                 .side(Side::Right),
         )
-        .y_axis4(
+        .y_axis_n(
+            4,
             Axis::new()
                 .title(Title::new("yaxis4 title").font(Font::new().color("#9467bd")))
                 .tick_font(Font::new().color("#9467bd"))
-                .anchor("free")
-                .overlaying("y")
+                .anchor(AxisRef::Free)
+                .overlaying(AxisRef::y(1))
                 .side(Side::Right)
                 .position(0.85),
         );
@@ -270,6 +312,7 @@ fn main() -> std::io::Result<()> {
     stacked_subplots(true);
     stacked_subplots_with_shared_x_axis(true);
     multiple_custom_sized_subplots(true);
+    subplots_builder(true);
 
     // Multiple Axes
     two_y_axes(true);
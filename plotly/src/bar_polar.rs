@@ -0,0 +1,184 @@
+//! Polar bar chart
+
+use crate::common::{Dim, HoverInfo, Label, Marker, PlotType};
+use crate::layout::{PolarId, ThetaUnit};
+use crate::private::NumOrStringWrapper;
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Bars extending radially from the center of a polar subplot, e.g. the sectors of a wind rose.
+/// The bars of the traces sharing a subplot are stacked or overlaid according to the `bar_mode`
+/// of its `LayoutPolar`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct BarPolar<R, Theta> {
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r: Option<Vec<R>>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theta: Option<Vec<Theta>>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// Alternate to `r`, the first radial coordinate of a linear space with step `dr`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r0: Option<NumOrStringWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dr: Option<f64>,
+    /// Alternate to `theta`, the first angular coordinate of a linear space with step `dtheta`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theta0: Option<NumOrStringWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtheta: Option<f64>,
+    /// Unit of the numeric `theta` coordinates, degrees by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    /// The polar subplot the bars are drawn in, `PolarId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subplot: Option<PolarId>,
+    /// Radial coordinate the bars start from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<Dim<f64>>,
+    /// Angular shift of the bars, in the unit of `theta`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<Dim<f64>>,
+    /// Angular width of the bars, in the unit of `theta`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "customdata")]
+    custom_data: Option<Vec<NumOrStringWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+}
+
+impl<R, Theta> BarPolar<R, Theta> {
+    /// Bars of length `r` at the angular coordinates `theta`.
+    pub fn new(r: Vec<R>, theta: Vec<Theta>) -> Self
+    where
+        R: Serialize + Default,
+        Theta: Serialize + Default,
+    {
+        BarPolar {
+            r: Some(r),
+            theta: Some(theta),
+            r#type: PlotType::BarPolar,
+            ..Default::default()
+        }
+    }
+}
+
+impl<R, Theta> Trace for BarPolar<R, Theta>
+where
+    R: Serialize,
+    Theta: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::RotationDirection;
+    use crate::layout::{
+        AngularAxis, GridShape, Layout, LayoutPolar, PolarBarMode, RadialAxis, ThetaUnit,
+    };
+    use crate::validation::{assert_trace_json, validate_figure};
+    use crate::NamedColor;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_bar_polar() {
+        let trace = BarPolar::new(vec![2.5, 1.5], vec!["N", "E"])
+            .name("11-14 m/s")
+            .base(0.5)
+            .width_array(vec![40., 50.])
+            .marker(Marker::new().color(NamedColor::Teal));
+        let expected = json!({
+            "r": [2.5, 1.5],
+            "theta": ["N", "E"],
+            "type": "barpolar",
+            "name": "11-14 m/s",
+            "base": 0.5,
+            "width": [40., 50.],
+            "marker": {"color": "teal"},
+        });
+        assert_trace_json(&trace, expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_polar() {
+        let layout = Layout::new()
+            .polar(
+                LayoutPolar::new()
+                    .sector(vec![0., 180.])
+                    .hole(0.1)
+                    .background_color(NamedColor::Ivory)
+                    .bar_mode(PolarBarMode::Overlay)
+                    .bar_gap(0.)
+                    .radial_axis(RadialAxis::new().range(vec![0, 5]).angle(45.))
+                    .angular_axis(
+                        AngularAxis::new()
+                            .direction(RotationDirection::Clockwise)
+                            .rotation(90.)
+                            .theta_unit(ThetaUnit::Radians),
+                    ),
+            )
+            .polar_n(3, LayoutPolar::new().grid_shape(GridShape::Linear));
+        let expected = json!({
+            "polar": {
+                "sector": [0., 180.],
+                "hole": 0.1,
+                "bgcolor": "ivory",
+                "radialaxis": {"range": [0, 5], "angle": 45.},
+                "angularaxis": {"thetaunit": "radians", "direction": "clockwise", "rotation": 90.},
+                "barmode": "overlay",
+                "bargap": 0.,
+            },
+            "polar3": {"gridshape": "linear"},
+        });
+        let value = to_value(&layout).unwrap();
+        assert_eq!(value, expected);
+        assert!(layout.get_polar(PolarId::new(3)).is_some());
+        assert!(layout.get_polar(PolarId::new(2)).is_none());
+        assert_eq!(
+            validate_figure(&json!({"data": [], "layout": value})),
+            vec![]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "polar subplot indices start at 1")]
+    fn test_layout_polar_index_zero() {
+        let _ = Layout::new().polar_n(0, LayoutPolar::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "polar subplot indices start at 1")]
+    fn test_polar_id_index_zero() {
+        let _ = BarPolar::new(vec![1], vec![1]).subplot(PolarId::new(0));
+    }
+}
@@ -2,18 +2,25 @@
 
 use crate::common::color::NamedColor;
 use crate::common::{Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType};
-use crate::private;
+use crate::layout::{XAxisId, YAxisId};
 use crate::Trace;
+use plotly_derive::FieldSetter;
 use serde::Serialize;
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Candlestick<T, O>
 {
+    #[field_setter(skip)]
     r#type: PlotType,
+    #[field_setter(skip)]
     x: Vec<T>,
+    #[field_setter(skip)]
     open: Vec<O>,
+    #[field_setter(skip)]
     high: Vec<O>,
+    #[field_setter(skip)]
     low: Vec<O>,
+    #[field_setter(skip)]
     close: Vec<O>,
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
@@ -32,9 +39,9 @@ pub struct Candlestick<T, O>
     #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
     hover_info: Option<HoverInfo>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisId>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<Line>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "whiskerwidth")]
@@ -57,14 +64,14 @@ impl<T, O> Candlestick<T, O>
         high: Vec<O>,
         low: Vec<O>,
         close: Vec<O>,
-    ) -> Box<Self> 
+    ) -> Self 
     where
         T: Serialize + Default,
         O: Serialize + Default,
     {
         let iline = Line::new().width(1.0).color(NamedColor::Green);
         let dline = Line::new().width(1.0).color(NamedColor::Red);
-        Box::new(Candlestick {
+        Candlestick {
             r#type: PlotType::Candlestick,
             x,
             open,
@@ -74,99 +81,7 @@ impl<T, O> Candlestick<T, O>
             increasing: Some(Direction::Increasing { line: iline }),
             decreasing: Some(Direction::Decreasing { line: dline }),
             ..Default::default()
-        })
-    }
-
-    pub fn name(&mut self, name: &str) -> &mut Self {
-        self.name = Some(name.to_owned());
-        self
-    }
-
-    pub fn visible(&mut self, visible: bool) -> &mut Self {
-        self.visible = Some(visible);
-        self
-    }
-
-    pub fn show_legend(&mut self, show_legend: bool) -> &mut Self {
-        self.show_legend = Some(show_legend);
-        self
-    }
-
-    pub fn legend_group(&mut self, legend_group: &str) -> &mut Self {
-        self.legend_group = Some(legend_group.to_owned());
-        self
-    }
-
-    pub fn opacity(&mut self, opacity: f64) -> &mut Self {
-        self.opacity = Some(opacity);
-        self
-    }
-
-    pub fn text(&mut self, text: &str) -> &mut Self {
-        self.text = Some(Dim::Scalar(text.to_owned()));
-        self
-    }
-
-    pub fn text_array<S: AsRef<str>>(&mut self, text: Vec<S>) -> &mut Self {
-        let text = private::owned_string_vector(text);
-        self.text = Some(Dim::Vector(text));
-        self
-    }
-
-    pub fn hover_text(&mut self, hover_text: &str) -> &mut Self {
-        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
-        self
-    }
-
-    pub fn hover_text_array<S: AsRef<str>>(&mut self, hover_text: Vec<S>) -> &mut Self {
-        let hover_text = private::owned_string_vector(hover_text);
-        self.hover_text = Some(Dim::Vector(hover_text));
-        self
-    }
-
-    pub fn hover_info(&mut self, hover_info: HoverInfo) -> &mut Self {
-        self.hover_info = Some(hover_info);
-        self
-    }
-
-    pub fn x_axis(&mut self, axis: &str) -> &mut Self {
-        self.x_axis = Some(axis.to_owned());
-        self
-    }
-
-    pub fn y_axis(&mut self, axis: &str) -> &mut Self {
-        self.y_axis = Some(axis.to_owned());
-        self
-    }
-
-    pub fn line(&mut self, line: Line) -> &mut Self {
-        self.line = Some(line);
-        self
-    }
-
-    pub fn whisker_width(&mut self, whisker_width: f64) -> &mut Self {
-        self.whisker_width = Some(whisker_width);
-        self
-    }
-
-    pub fn increasing(&mut self, increasing: Direction) -> &mut Self {
-        self.increasing = Some(increasing);
-        self
-    }
-
-    pub fn decreasing(&mut self, decreasing: Direction) -> &mut Self {
-        self.decreasing = Some(decreasing);
-        self
-    }
-
-    pub fn hover_label(&mut self, hover_label: Label) -> &mut Self {
-        self.hover_label = Some(hover_label);
-        self
-    }
-
-    pub fn x_calendar(&mut self, x_calendar: Calendar) -> &mut Self {
-        self.x_calendar = Some(x_calendar);
-        self
+        }
     }
 }
 
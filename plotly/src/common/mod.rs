@@ -1,10 +1,15 @@
-use serde::Serialize;
+use plotly_derive::FieldSetter;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::BitOr;
 
 pub mod color;
 
 use crate::common::color::ColorWrapper;
+use crate::layout::{ArrayShow, TicksDirection};
 use crate::private;
-use crate::private::{to_num_or_string_wrapper, NumOrString, NumOrStringWrapper};
+use crate::private::NumOrStringWrapper;
 use color::Color;
 
 #[derive(Serialize, Clone, Debug)]
@@ -14,6 +19,16 @@ pub enum Direction {
     Decreasing { line: Line },
 }
 
+/// Clockwise or counterclockwise, e.g. the order of the sectors of a pie or the direction of the
+/// angles of a polar subplot.
+#[derive(Serialize, Clone, Debug)]
+pub enum RotationDirection {
+    #[serde(rename = "clockwise")]
+    Clockwise,
+    #[serde(rename = "counterclockwise")]
+    CounterClockwise,
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub enum Visible {
     #[serde(rename = "x")]
@@ -64,6 +79,19 @@ pub enum TextPosition {
     None,
 }
 
+/// Orientation of the text drawn inside the sectors of `Pie`-like traces.
+#[derive(Serialize, Clone, Debug)]
+pub enum InsideTextOrientation {
+    #[serde(rename = "horizontal")]
+    Horizontal,
+    #[serde(rename = "radial")]
+    Radial,
+    #[serde(rename = "tangential")]
+    Tangential,
+    #[serde(rename = "auto")]
+    Auto,
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub enum ConstrainText {
     #[serde(rename = "inside")]
@@ -166,24 +194,52 @@ pub enum PlotType {
     ScatterGL,
     #[serde(rename = "scatter3d")]
     Scatter3D,
+    #[serde(rename = "scatterpolar")]
+    ScatterPolar,
+    #[serde(rename = "scatterpolargl")]
+    ScatterPolarGL,
     #[serde(rename = "bar")]
     Bar,
+    #[serde(rename = "barpolar")]
+    BarPolar,
     #[serde(rename = "box")]
     Box,
     #[serde(rename = "candlestick")]
     Candlestick,
+    #[serde(rename = "cone")]
+    Cone,
     #[serde(rename = "contour")]
     Contour,
     #[serde(rename = "heatmap")]
     HeatMap,
     #[serde(rename = "histogram")]
     Histogram,
+    #[serde(rename = "histogram2d")]
+    Histogram2d,
     #[serde(rename = "histogram2dcontour")]
     Histogram2dContour,
+    #[serde(rename = "isosurface")]
+    Isosurface,
+    #[serde(rename = "mesh3d")]
+    Mesh3D,
     #[serde(rename = "ohlc")]
     Ohlc,
+    #[serde(rename = "pie")]
+    Pie,
+    #[serde(rename = "sunburst")]
+    Sunburst,
+    #[serde(rename = "streamtube")]
+    Streamtube,
     #[serde(rename = "surface")]
     Surface,
+    #[serde(rename = "treemap")]
+    Treemap,
+    #[serde(rename = "violin")]
+    Violin,
+    #[serde(rename = "volume")]
+    Volume,
+    #[serde(rename = "waterfall")]
+    Waterfall,
 }
 
 impl Default for PlotType {
@@ -596,7 +652,7 @@ pub enum LineShape {
     Vhv,
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Line {
     #[serde(skip_serializing_if = "Option::is_none")]
     width: Option<f64>,
@@ -634,81 +690,6 @@ impl Line {
     pub fn new() -> Line {
         Default::default()
     }
-
-    pub fn width(mut self, width: f64) -> Line {
-        self.width = Some(width);
-        self
-    }
-
-    pub fn shape(mut self, shape: LineShape) -> Line {
-        self.shape = Some(shape);
-        self
-    }
-
-    pub fn smoothing(mut self, smoothing: f64) -> Line {
-        self.smoothing = Some(smoothing);
-        self
-    }
-
-    pub fn dash(mut self, dash: DashType) -> Line {
-        self.dash = Some(dash);
-        self
-    }
-
-    pub fn simplify(mut self, simplify: bool) -> Line {
-        self.simplify = Some(simplify);
-        self
-    }
-
-    pub fn color<C: Color>(mut self, color: C) -> Line {
-        self.color = Some(color.to_color());
-        self
-    }
-
-    pub fn cauto(mut self, cauto: bool) -> Line {
-        self.cauto = Some(cauto);
-        self
-    }
-
-    pub fn cmin(mut self, cmin: f64) -> Line {
-        self.cmin = Some(cmin);
-        self
-    }
-
-    pub fn cmax(mut self, cmax: f64) -> Line {
-        self.cmax = Some(cmax);
-        self
-    }
-
-    pub fn cmid(mut self, cmid: f64) -> Line {
-        self.cmid = Some(cmid);
-        self
-    }
-
-    pub fn color_scale(mut self, color_scale: ColorScale) -> Line {
-        self.color_scale = Some(color_scale);
-        self
-    }
-
-    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Line {
-        self.auto_color_scale = Some(auto_color_scale);
-        self
-    }
-
-    pub fn reverse_scale(mut self, reverse_scale: bool) -> Line {
-        self.reverse_scale = Some(reverse_scale);
-        self
-    }
-
-    pub fn outlier_color<C: Color>(mut self, outlier_color: C) -> Line {
-        self.outlier_color = Some(outlier_color.to_color());
-        self
-    }
-
-    pub fn outlier_width(mut self, outlier_width: usize) -> Line {
-        self.outlier_width = Some(outlier_width);
-        self
-    }
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -802,7 +783,7 @@ impl Gradient {
     }
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct TickFormatStop {
     enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none", rename = "dtickrange")]
@@ -822,35 +803,9 @@ impl TickFormatStop {
             ..Default::default()
         }
     }
-
-    pub fn enabled(mut self, enabled: bool) -> TickFormatStop {
-        self.enabled = enabled;
-        self
-    }
-
-    pub fn dtick_range<C: NumOrString>(mut self, range: Vec<C>) -> TickFormatStop {
-        let wrapped = to_num_or_string_wrapper(range);
-        self.dtick_range = Some(wrapped);
-        self
-    }
-
-    pub fn value(mut self, value: &str) -> TickFormatStop {
-        self.value = Some(value.to_owned());
-        self
-    }
-
-    pub fn name(mut self, name: &str) -> TickFormatStop {
-        self.name = Some(name.to_owned());
-        self
-    }
-
-    pub fn template_item_name(mut self, name: &str) -> TickFormatStop {
-        self.template_item_name = Some(name.to_owned());
-        self
-    }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
 pub struct ColorBar {
     #[serde(skip_serializing_if = "Option::is_none", rename = "thicknessmode")]
     thickness_mode: Option<ThicknessMode>,
@@ -891,7 +846,7 @@ pub struct ColorBar {
     #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
     tick_text: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    ticks: Option<String>,
+    ticks: Option<TicksDirection>,
     #[serde(rename = "ticklen")]
     tick_len: usize,
     #[serde(rename = "tickwidth")]
@@ -911,16 +866,17 @@ pub struct ColorBar {
     #[serde(skip_serializing_if = "Option::is_none", rename = "tickprefix")]
     tick_prefix: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showtickprefix")]
-    show_tick_prefix: Option<String>,
+    show_tick_prefix: Option<ArrayShow>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
     tick_suffix: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showticksuffix")]
-    show_tick_suffix: Option<String>,
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "separatethousands")]
     separate_thousands: bool,
     #[serde(skip_serializing_if = "Option::is_none", rename = "exponentformat")]
     exponent_format: Option<ExponentFormat>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showexponent")]
-    show_exponent: Option<String>,
+    show_exponent: Option<ArrayShow>,
     #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<Title>,
 }
@@ -949,202 +905,34 @@ impl ColorBar {
             tick_width: 1,
             show_tick_labels: true,
             separate_thousands: true,
-            ..Default::default()
+            thickness_mode: None,
+            len_mode: None,
+            outline_color: None,
+            border_color: None,
+            background_color: None,
+            tick_mode: None,
+            tick0: None,
+            dtick: None,
+            tick_vals: None,
+            tick_text: None,
+            ticks: None,
+            tick_color: None,
+            tick_font: None,
+            tick_angle: None,
+            tick_format: None,
+            tick_format_stops: None,
+            tick_prefix: None,
+            show_tick_prefix: None,
+            tick_suffix: None,
+            show_tick_suffix: None,
+            exponent_format: None,
+            show_exponent: None,
+            title: None,
         }
     }
-
-    pub fn thickness_mode(mut self, thickness_mode: ThicknessMode) -> ColorBar {
-        self.thickness_mode = Some(thickness_mode);
-        self
-    }
-
-    pub fn thickness(mut self, thickness: usize) -> ColorBar {
-        self.thickness = thickness;
-        self
-    }
-
-    pub fn len_mode(mut self, len_mode: ThicknessMode) -> ColorBar {
-        self.len_mode = Some(len_mode);
-        self
-    }
-
-    pub fn len(mut self, len: usize) -> ColorBar {
-        self.len = len;
-        self
-    }
-
-    pub fn x(mut self, x: f64) -> ColorBar {
-        self.x = x;
-        self
-    }
-
-    pub fn x_anchor(mut self, x_anchor: Anchor) -> ColorBar {
-        self.x_anchor = x_anchor;
-        self
-    }
-
-    pub fn x_pad(mut self, x_pad: f64) -> ColorBar {
-        self.x_pad = x_pad;
-        self
-    }
-
-    pub fn y(mut self, y: f64) -> ColorBar {
-        self.y = y;
-        self
-    }
-
-    pub fn y_anchor(mut self, y_anchor: Anchor) -> ColorBar {
-        self.y_anchor = y_anchor;
-        self
-    }
-
-    pub fn y_pad(mut self, y_pad: f64) -> ColorBar {
-        self.y_pad = y_pad;
-        self
-    }
-
-    pub fn outline_color<C: Color>(mut self, outline_color: C) -> ColorBar {
-        self.outline_color = Some(outline_color.to_color());
-        self
-    }
-
-    pub fn outline_width(mut self, outline_width: usize) -> ColorBar {
-        self.outline_width = outline_width;
-        self
-    }
-
-    pub fn border_color<C: Color>(mut self, border_color: C) -> ColorBar {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
-
-    pub fn border_width(mut self, border_width: usize) -> ColorBar {
-        self.border_width = border_width;
-        self
-    }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> ColorBar {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn tick_mode(mut self, tick_mode: TickMode) -> ColorBar {
-        self.tick_mode = Some(tick_mode);
-        self
-    }
-
-    pub fn n_ticks(mut self, n_ticks: usize) -> ColorBar {
-        self.n_ticks = n_ticks;
-        self
-    }
-
-    pub fn tick0(mut self, tick0: f64) -> ColorBar {
-        self.tick0 = Some(tick0);
-        self
-    }
-
-    pub fn dtick(mut self, dtick: f64) -> ColorBar {
-        self.dtick = Some(dtick);
-        self
-    }
-
-    pub fn tick_vals(mut self, tick_vals: Vec<f64>) -> ColorBar {
-        self.tick_vals = Some(tick_vals);
-        self
-    }
-
-    pub fn tick_text(mut self, tick_text: Vec<String>) -> ColorBar {
-        self.tick_text = Some(tick_text);
-        self
-    }
-
-    pub fn ticks(mut self, ticks: &str) -> ColorBar {
-        self.ticks = Some(ticks.to_owned());
-        self
-    }
-
-    pub fn tick_len(mut self, tick_len: usize) -> ColorBar {
-        self.tick_len = tick_len;
-        self
-    }
-
-    pub fn tick_width(mut self, tick_width: usize) -> ColorBar {
-        self.tick_width = tick_width;
-        self
-    }
-
-    pub fn tick_color<C: Color>(mut self, tick_color: C) -> ColorBar {
-        self.tick_color = Some(tick_color.to_color());
-        self
-    }
-
-    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> ColorBar {
-        self.show_tick_labels = show_tick_labels;
-        self
-    }
-
-    pub fn tick_font(mut self, tick_font: Font) -> ColorBar {
-        self.tick_font = Some(tick_font);
-        self
-    }
-
-    pub fn tick_angle(mut self, tick_angle: f64) -> ColorBar {
-        self.tick_angle = Some(tick_angle);
-        self
-    }
-
-    pub fn tick_format(mut self, tick_format: &str) -> ColorBar {
-        self.tick_format = Some(tick_format.to_owned());
-        self
-    }
-
-    pub fn tick_format_stops(mut self, tick_format_stops: Vec<TickFormatStop>) -> ColorBar {
-        self.tick_format_stops = Some(tick_format_stops);
-        self
-    }
-
-    pub fn tick_prefix(mut self, tick_prefix: &str) -> ColorBar {
-        self.tick_prefix = Some(tick_prefix.to_owned());
-        self
-    }
-
-    pub fn show_tick_prefix(mut self, show_tick_prefix: &str) -> ColorBar {
-        self.show_tick_prefix = Some(show_tick_prefix.to_owned());
-        self
-    }
-
-    pub fn tick_suffix(mut self, tick_suffix: &str) -> ColorBar {
-        self.tick_suffix = Some(tick_suffix.to_owned());
-        self
-    }
-
-    pub fn show_tick_suffix(mut self, show_tick_suffix: &str) -> ColorBar {
-        self.show_tick_suffix = Some(show_tick_suffix.to_owned());
-        self
-    }
-
-    pub fn separate_thousands(mut self, separate_thousands: bool) -> ColorBar {
-        self.separate_thousands = separate_thousands;
-        self
-    }
-
-    pub fn exponent_format(mut self, exponent_format: ExponentFormat) -> ColorBar {
-        self.exponent_format = Some(exponent_format);
-        self
-    }
-
-    pub fn show_exponent(mut self, show_exponent: &str) -> ColorBar {
-        self.show_exponent = Some(show_exponent.to_owned());
-        self
-    }
-
-    pub fn title(mut self, title: Title) -> ColorBar {
-        self.title = Some(title);
-        self
-    }
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Marker {
     #[serde(skip_serializing_if = "Option::is_none")]
     symbol: Option<MarkerSymbol>,
@@ -1158,7 +946,7 @@ pub struct Marker {
     size_ref: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "sizemin")]
     size_min: Option<usize>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemin")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemode")]
     size_mode: Option<SizeMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<Line>,
@@ -1192,120 +980,31 @@ impl Marker {
     pub fn new() -> Marker {
         Default::default()
     }
+}
 
-    pub fn symbol(mut self, symbol: MarkerSymbol) -> Marker {
-        self.symbol = Some(symbol);
-        self
-    }
-
-    pub fn opacity(mut self, opacity: f64) -> Marker {
-        self.opacity = Some(opacity);
-        self
-    }
-
-    pub fn size(mut self, size: usize) -> Marker {
-        self.size = Some(Dim::Scalar(size));
-        self
-    }
-
-    pub fn size_array(mut self, size: Vec<usize>) -> Marker {
-        self.size = Some(Dim::Vector(size));
-        self
-    }
-
-    pub fn max_displayed(mut self, size: usize) -> Marker {
-        self.max_displayed = Some(size);
-        self
-    }
-
-    pub fn size_ref(mut self, size: usize) -> Marker {
-        self.size_ref = Some(size);
-        self
-    }
-
-    pub fn size_min(mut self, size: usize) -> Marker {
-        self.size_min = Some(size);
-        self
-    }
-
-    pub fn size_mode(mut self, mode: SizeMode) -> Marker {
-        self.size_mode = Some(mode);
-        self
-    }
-
-    pub fn line(mut self, line: Line) -> Marker {
-        self.line = Some(line);
-        self
-    }
-
-    pub fn gradient(mut self, gradient: Gradient) -> Marker {
-        self.gradient = Some(gradient);
-        self
-    }
-
-    pub fn color<C: Color>(mut self, color: C) -> Marker {
-        self.color = Some(Dim::Scalar(color.to_color()));
-        self
-    }
-
-    pub fn color_array<C: Color>(mut self, color: Vec<C>) -> Marker {
-        let color = private::to_color_array(color);
-        self.color = Some(Dim::Vector(color));
-        self
-    }
-
-    pub fn cauto(mut self, cauto: bool) -> Marker {
-        self.cauto = Some(cauto);
-        self
-    }
-
-    pub fn cmin(mut self, cmin: f64) -> Marker {
-        self.cmin = Some(cmin);
-        self
-    }
-
-    pub fn cmax(mut self, cmax: f64) -> Marker {
-        self.cmax = Some(cmax);
-        self
-    }
-
-    pub fn cmid(mut self, cmid: f64) -> Marker {
-        self.cmid = Some(cmid);
-        self
-    }
-
-    pub fn color_scale(mut self, color_scale: ColorScale) -> Marker {
-        self.color_scale = Some(color_scale);
-        self
-    }
-
-    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Marker {
-        self.auto_color_scale = Some(auto_color_scale);
-        self
-    }
-
-    pub fn reverse_scale(mut self, reverse_scale: bool) -> Marker {
-        self.reverse_scale = Some(reverse_scale);
-        self
-    }
-
-    pub fn show_scale(mut self, show_scale: bool) -> Marker {
-        self.show_scale = Some(show_scale);
-        self
-    }
-
-    pub fn color_bar(mut self, colorbar: ColorBar) -> Marker {
-        self.color_bar = Some(colorbar);
-        self
-    }
+/// Area of the plot occupied by a trace drawn outside of cartesian axes, such as a `Pie`, either
+/// as `x` and `y` ranges in normalized coordinates or as a cell of the layout grid.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Domain {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Vec<f64>>,
+    /// Row of the layout grid, starting at 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    row: Option<usize>,
+    /// Column of the layout grid, starting at 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
 
-    pub fn outlier_color<C: Color>(mut self, outlier_color: C) -> Marker {
-        self.outlier_color = Some(outlier_color.to_color());
-        self
+impl Domain {
+    pub fn new() -> Domain {
+        Default::default()
     }
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Font {
     #[serde(skip_serializing_if = "Option::is_none")]
     family: Option<String>,
@@ -1319,21 +1018,6 @@ impl Font {
     pub fn new() -> Font {
         Default::default()
     }
-
-    pub fn family(mut self, family: &str) -> Font {
-        self.family = Some(family.to_owned());
-        self
-    }
-
-    pub fn size(mut self, size: usize) -> Font {
-        self.size = Some(size);
-        self
-    }
-
-    pub fn color<C: Color>(mut self, color: C) -> Font {
-        self.color = Some(color.to_color());
-        self
-    }
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -1371,8 +1055,9 @@ impl Pad {
     }
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Title {
+    #[field_setter(skip)]
     text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     font: Option<Font>,
@@ -1407,54 +1092,23 @@ impl Title {
             ..Default::default()
         }
     }
+}
 
-    pub fn font(mut self, font: Font) -> Title {
-        self.font = Some(font);
-        self
-    }
-
-    pub fn side(mut self, side: Side) -> Title {
-        self.side = Some(side);
-        self
-    }
-
-    pub fn x_ref(mut self, xref: Reference) -> Title {
-        self.x_ref = Some(xref);
-        self
-    }
-
-    pub fn y_ref(mut self, yref: Reference) -> Title {
-        self.y_ref = Some(yref);
-        self
-    }
-
-    pub fn x(mut self, x: f64) -> Title {
-        self.x = Some(x);
-        self
-    }
-
-    pub fn y(mut self, y: f64) -> Title {
-        self.y = Some(y);
-        self
-    }
-
-    pub fn x_anchor(mut self, anchor: Anchor) -> Title {
-        self.x_anchor = Some(anchor);
-        self
-    }
-
-    pub fn y_anchor(mut self, anchor: Anchor) -> Title {
-        self.y_anchor = Some(anchor);
-        self
-    }
-
-    pub fn pad(mut self, pad: Pad) -> Title {
-        self.pad = Some(pad);
-        self
-    }
+/// Horizontal alignment of the text of a hover label.
+#[derive(Serialize, Clone, Debug)]
+pub enum LabelAlign {
+    #[serde(rename = "left")]
+    Left,
+    #[serde(rename = "right")]
+    Right,
+    #[serde(rename = "auto")]
+    Auto,
+    /// Any other value, passed to plotly.js as is.
+    #[serde(untagged)]
+    Raw(String),
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Label {
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<ColorWrapper>,
@@ -1463,7 +1117,7 @@ pub struct Label {
     #[serde(skip_serializing_if = "Option::is_none")]
     font: Option<Font>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    align: Option<String>,
+    align: Option<LabelAlign>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "namelength")]
     name_length: Option<Dim<i32>>,
 }
@@ -1472,36 +1126,6 @@ impl Label {
     pub fn new() -> Label {
         Default::default()
     }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> Label {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn border_color<C: Color>(mut self, border_color: C) -> Label {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
-
-    pub fn font(mut self, font: Font) -> Label {
-        self.font = Some(font);
-        self
-    }
-
-    pub fn align(mut self, align: &str) -> Label {
-        self.align = Some(align.to_owned());
-        self
-    }
-
-    pub fn name_length(mut self, name_length: i32) -> Label {
-        self.name_length = Some(Dim::Scalar(name_length));
-        self
-    }
-
-    pub fn name_length_array(mut self, name_length: Vec<i32>) -> Label {
-        self.name_length = Some(Dim::Vector(name_length));
-        self
-    }
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -1522,8 +1146,9 @@ impl Default for ErrorType {
     }
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct ErrorData {
+    #[field_setter(skip)]
     r#type: ErrorType,
     #[serde(skip_serializing_if = "Option::is_none")]
     array: Option<Vec<f64>>,
@@ -1558,64 +1183,193 @@ impl ErrorData {
             ..Default::default()
         }
     }
+}
 
-    pub fn array(mut self, array: Vec<f64>) -> ErrorData {
-        self.array = Some(array);
-        self
-    }
+/// The flag names of a `Flags` set, e.g. the names of the parts of a trace that show text.
+pub trait FlagNames {
+    /// The names of the flags that combine with each other, in the order of their bits.
+    const NAMES: &'static [&'static str];
+    /// The names of the flags that only stand alone, e.g. "all", numbered after `NAMES`. They are
+    /// ignored when combined with other flags.
+    const EXCLUSIVE: &'static [&'static str] = &[];
+    /// The value of a set without flags.
+    const EMPTY: &'static str;
+}
 
-    pub fn visible(mut self, visible: bool) -> ErrorData {
-        self.visible = Some(visible);
-        self
-    }
+/// A set of flags of a plotly.js flag list attribute, combined with `|`. Serializes as the names
+/// of its flags joined with "+", e.g. "label+percent".
+pub struct Flags<N> {
+    bits: u8,
+    raw: Option<String>,
+    names: PhantomData<N>,
+}
 
-    pub fn symmetric(mut self, symmetric: bool) -> ErrorData {
-        self.symmetric = Some(symmetric);
-        self
+impl<N> Flags<N> {
+    pub(crate) const fn empty() -> Flags<N> {
+        Flags {
+            bits: 0,
+            raw: None,
+            names: PhantomData,
+        }
     }
 
-    pub fn array_minus(mut self, array_minus: Vec<f64>) -> ErrorData {
-        self.array_minus = Some(array_minus);
-        self
+    pub(crate) const fn flag(bit: u8) -> Flags<N> {
+        Flags {
+            bits: 1 << bit,
+            raw: None,
+            names: PhantomData,
+        }
     }
 
-    pub fn value(mut self, value: f64) -> ErrorData {
-        self.value = Some(value);
-        self
+    /// Any other value, passed to plotly.js as is. Combined with other flags, it is joined to
+    /// their names with "+".
+    pub fn raw(value: &str) -> Flags<N> {
+        Flags {
+            bits: 0,
+            raw: Some(value.to_owned()),
+            names: PhantomData,
+        }
     }
+}
 
-    pub fn value_minus(mut self, value_minus: f64) -> ErrorData {
-        self.value_minus = Some(value_minus);
-        self
+impl<N> Clone for Flags<N> {
+    fn clone(&self) -> Self {
+        Flags {
+            bits: self.bits,
+            raw: self.raw.clone(),
+            names: PhantomData,
+        }
     }
+}
 
-    pub fn trace_ref(mut self, trace_ref: usize) -> ErrorData {
-        self.trace_ref = Some(trace_ref);
-        self
+impl<N> fmt::Debug for Flags<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Flags")
+            .field("bits", &self.bits)
+            .field("raw", &self.raw)
+            .finish()
     }
+}
 
-    pub fn trace_ref_minus(mut self, trace_ref_minus: usize) -> ErrorData {
-        self.trace_ref_minus = Some(trace_ref_minus);
-        self
+impl<N> PartialEq for Flags<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits && self.raw == other.raw
     }
+}
 
-    pub fn copy_ystyle(mut self, copy_ystyle: bool) -> ErrorData {
-        self.copy_ystyle = Some(copy_ystyle);
-        self
-    }
+impl<N> Eq for Flags<N> {}
+
+impl<N> BitOr for Flags<N> {
+    type Output = Flags<N>;
 
-    pub fn color<C: Color>(mut self, color: C) -> ErrorData {
-        self.color = Some(color.to_color());
-        self
+    fn bitor(self, other: Flags<N>) -> Flags<N> {
+        let raw = match (self.raw, other.raw) {
+            (Some(raw), Some(other)) => Some(format!("{}+{}", raw, other)),
+            (raw, other) => raw.or(other),
+        };
+        Flags {
+            bits: self.bits | other.bits,
+            raw,
+            names: PhantomData,
+        }
     }
+}
 
-    pub fn thickness(mut self, thickness: f64) -> ErrorData {
-        self.thickness = Some(thickness);
-        self
+impl<N: FlagNames> Serialize for Flags<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bit = self.bits.trailing_zeros() as usize;
+        if self.raw.is_none() && self.bits.is_power_of_two() && bit >= N::NAMES.len() {
+            return serializer.serialize_str(N::EXCLUSIVE[bit - N::NAMES.len()]);
+        }
+        let mut flags: Vec<&str> = N::NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.bits & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+            .collect();
+        if let Some(raw) = &self.raw {
+            flags.push(raw);
+        }
+        if flags.is_empty() {
+            serializer.serialize_str(N::EMPTY)
+        } else {
+            serializer.serialize_str(&flags.join("+"))
+        }
     }
+}
 
-    pub fn width(mut self, width: usize) -> ErrorData {
-        self.width = Some(width);
-        self
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_derived_setters() {
+        let marker = Marker::new()
+            .size(10)
+            .size_mode(SizeMode::Area)
+            .color_array(vec!["red", "blue"])
+            .outlier_color("#00FF00");
+        let expected = json!({
+            "size": 10,
+            "sizemode": "area",
+            "color": ["red", "blue"],
+            "outliercolor": "#00FF00",
+        });
+        assert_eq!(to_value(marker).unwrap(), expected);
+
+        let label = Label::new()
+            .align(LabelAlign::Left)
+            .name_length_array(vec![1, -1]);
+        let expected = json!({"align": "left", "namelength": [1, -1]});
+        assert_eq!(to_value(label).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_derived_setters_with_wrappers() {
+        let stop = TickFormatStop::new()
+            .dtick_range(vec!["M1", "M12"])
+            .value("%b '%y");
+        let expected = json!({"enabled": true, "dtickrange": ["M1", "M12"], "value": "%b '%y"});
+        assert_eq!(to_value(stop).unwrap(), expected);
+
+        let error = ErrorData::new(ErrorType::Data).array(vec![0.1, 0.2]);
+        let expected = json!({"type": "data", "array": [0.1, 0.2]});
+        assert_eq!(to_value(error).unwrap(), expected);
+    }
+
+    enum PartNames {}
+
+    impl FlagNames for PartNames {
+        const NAMES: &'static [&'static str] = &["label", "value"];
+        const EXCLUSIVE: &'static [&'static str] = &["all"];
+        const EMPTY: &'static str = "none";
+    }
+
+    #[test]
+    fn test_serialize_flags() {
+        let (label, value, all) = (Flags::<PartNames>::flag(0), Flags::flag(1), Flags::flag(2));
+        let flags = vec![
+            Flags::empty(),
+            value.clone() | label.clone(),
+            all.clone(),
+            all | value.clone(),
+            Flags::raw("label+percent") | value,
+            Flags::raw("text") | label | Flags::raw("percent"),
+        ];
+        assert_eq!(
+            to_value(flags).unwrap(),
+            json!([
+                "none",
+                "label+value",
+                "all",
+                "value",
+                "value+label+percent",
+                "label+text+percent"
+            ])
+        );
     }
 }
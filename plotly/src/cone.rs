@@ -0,0 +1,241 @@
+//! Cone plot
+
+use crate::common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType};
+use crate::layout::SceneId;
+use crate::surface::{Lighting, Position};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+#[cfg(feature = "plotly_ndarray")]
+use ndarray::{Array, Ix1, Ix3};
+
+/// Part of the cone placed at its `(x, y, z)` position.
+#[derive(Serialize, Clone, Debug)]
+pub enum Anchor {
+    #[serde(rename = "tip")]
+    Tip,
+    #[serde(rename = "tail")]
+    Tail,
+    /// The center of mass of the cone.
+    #[serde(rename = "cm")]
+    CenterOfMass,
+    #[serde(rename = "center")]
+    Center,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum SizeMode {
+    /// `size_ref` is relative to the largest vector norm and the distance between the cones.
+    #[serde(rename = "scaled")]
+    Scaled,
+    /// `size_ref` is in the units of the vectors.
+    #[serde(rename = "absolute")]
+    Absolute,
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Cone<X, Y, Z, U, V, W> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    z: Vec<Z>,
+    #[field_setter(skip)]
+    u: Vec<U>,
+    #[field_setter(skip)]
+    v: Vec<V>,
+    #[field_setter(skip)]
+    w: Vec<W>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// The scene the cones are drawn in, `SceneId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<SceneId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemode")]
+    size_mode: Option<SizeMode>,
+    /// Scale factor of the cones, interpreted according to the `size_mode`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizeref")]
+    size_ref: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lighting: Option<Lighting>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lightposition")]
+    light_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<X, Y, Z, U, V, W> Cone<X, Y, Z, U, V, W> {
+    /// Cones at the `(x, y, z)` positions pointing in the direction of the `(u, v, w)` vectors.
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>, u: Vec<U>, v: Vec<V>, w: Vec<W>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+        Z: Serialize + Default,
+        U: Serialize + Default,
+        V: Serialize + Default,
+        W: Serialize + Default,
+    {
+        Cone {
+            x,
+            y,
+            z,
+            u,
+            v,
+            w,
+            r#type: PlotType::Cone,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "plotly_ndarray")]
+impl<X, Y, Z, U, V, W> Cone<X, Y, Z, U, V, W>
+where
+    X: Serialize + Clone + Default,
+    Y: Serialize + Clone + Default,
+    Z: Serialize + Clone + Default,
+    U: Serialize + Clone + Default,
+    V: Serialize + Clone + Default,
+    W: Serialize + Clone + Default,
+{
+    /// Cones at every point of a grid, where `u[[i, j, k]]`, `v[[i, j, k]]` and `w[[i, j, k]]` are
+    /// the components of the vector at `(x[i], y[j], z[k])`. This function requires the
+    /// `plotly_ndarray` feature.
+    ///
+    /// # Panics
+    /// When the shape of the components is not the lengths of `x`, `y` and `z`.
+    pub fn from_array(
+        x: Array<X, Ix1>,
+        y: Array<Y, Ix1>,
+        z: Array<Z, Ix1>,
+        u: Array<U, Ix3>,
+        v: Array<V, Ix3>,
+        w: Array<W, Ix3>,
+    ) -> Self {
+        let (x, y, z, u, v, w) = crate::private::grid_vector_field_from(x, y, z, u, v, w);
+        Cone::new(x, y, z, u, v, w)
+    }
+}
+
+impl<X, Y, Z, U, V, W> Trace for Cone<X, Y, Z, U, V, W>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+    U: Serialize,
+    V: Serialize,
+    W: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_cone() {
+        let cone = Cone::new(
+            vec![0, 1],
+            vec![0, 1],
+            vec![0, 1],
+            vec![1., 0.],
+            vec![0., 1.],
+            vec![0., 0.5],
+        )
+        .anchor(Anchor::Tail)
+        .size_mode(SizeMode::Absolute)
+        .size_ref(2.)
+        .show_scale(false);
+        let expected = json!({
+            "x": [0, 1],
+            "y": [0, 1],
+            "z": [0, 1],
+            "u": [1., 0.],
+            "v": [0., 1.],
+            "w": [0., 0.5],
+            "type": "cone",
+            "anchor": "tail",
+            "sizemode": "absolute",
+            "sizeref": 2.,
+            "showscale": false,
+        });
+        assert_trace_json(&cone, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    fn test_cone_from_array() {
+        let grid = Array::from(vec![0., 1.]);
+        let u = Array::from_shape_fn((2, 2, 2), |(i, _, _)| i as f64);
+        let v = Array::from_shape_fn((2, 2, 2), |(_, j, _)| j as f64);
+        let w = Array::from_shape_fn((2, 2, 2), |(_, _, k)| k as f64);
+        let cone = Cone::from_array(grid.clone(), grid.clone(), grid, u, v, w);
+        let value = serde_json::to_value(&cone).unwrap();
+        assert_eq!(value["x"], value["u"]);
+        assert_eq!(value["y"], value["v"]);
+        assert_eq!(value["z"], value["w"]);
+        assert_eq!(value["z"], json!([0., 1., 0., 1., 0., 1., 0., 1.]));
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    #[should_panic(expected = "the u, v and w components must have the same shape")]
+    fn test_cone_from_array_shape_mismatch() {
+        let grid = Array::from(vec![0., 1.]);
+        let _ = Cone::from_array(
+            grid.clone(),
+            grid.clone(),
+            grid,
+            Array::<f64, _>::zeros((2, 2, 2)),
+            Array::<f64, _>::zeros((2, 2, 2)),
+            Array::<f64, _>::zeros((2, 2, 1)),
+        );
+    }
+}
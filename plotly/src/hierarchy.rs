@@ -0,0 +1,224 @@
+//! Flattening of trees into the `ids`, `labels`, `parents` and `values` arrays of hierarchical
+//! traces such as `Sunburst` and `Treemap`.
+
+use std::collections::HashMap;
+
+/// A node of a tree that can be flattened into a `Hierarchy`.
+pub trait TreeNode: Sized {
+    fn label(&self) -> String;
+    /// Value of the node itself, excluding the values of its children.
+    fn value(&self) -> f64;
+    fn children(&self) -> &[Self];
+}
+
+/// A tree flattened into parallel arrays, in depth-first order. The id of a node is the path of
+/// labels from its root, joined with the separator given to `from_paths` or with `/` for
+/// `from_tree`, and roots have an empty parent.
+///
+/// The values are those of the nodes themselves, which is what plotly.js expects with the default
+/// `BranchValues::Remainder`. Use `with_totals` for `BranchValues::Total`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hierarchy {
+    ids: Vec<String>,
+    labels: Vec<String>,
+    parents: Vec<String>,
+    values: Vec<f64>,
+}
+
+impl Hierarchy {
+    /// Builds a hierarchy from a list of paths such as `"usr/lib/libc.so"` with their sizes. Missing
+    /// intermediate nodes are created with a value of 0, paths listed several times add up and empty
+    /// segments are ignored.
+    pub fn from_paths<I, S>(paths: I, separator: char) -> Hierarchy
+    where
+        I: IntoIterator<Item = (S, f64)>,
+        S: AsRef<str>,
+    {
+        let mut hierarchy = Hierarchy::default();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (path, value) in paths {
+            let mut id = String::new();
+            let mut last = None;
+            for segment in path.as_ref().split(separator).filter(|s| !s.is_empty()) {
+                let parent = id.clone();
+                if !id.is_empty() {
+                    id.push(separator);
+                }
+                id.push_str(segment);
+                let i = match index.get(&id) {
+                    Some(&i) => i,
+                    None => {
+                        index.insert(id.clone(), hierarchy.ids.len());
+                        hierarchy.push(id.clone(), segment.to_owned(), parent, 0.);
+                        hierarchy.ids.len() - 1
+                    }
+                };
+                last = Some(i);
+            }
+            if let Some(i) = last {
+                hierarchy.values[i] += value;
+            }
+        }
+        hierarchy
+    }
+
+    /// Builds a hierarchy from the given roots and all their descendants. The labels of siblings
+    /// must be distinct for the ids to be unique.
+    pub fn from_tree<N: TreeNode>(roots: &[N]) -> Hierarchy {
+        let mut hierarchy = Hierarchy::default();
+        for root in roots {
+            hierarchy.visit(root, "");
+        }
+        hierarchy
+    }
+
+    fn visit<N: TreeNode>(&mut self, node: &N, parent: &str) {
+        let label = node.label();
+        let id = if parent.is_empty() {
+            label.clone()
+        } else {
+            format!("{}/{}", parent, label)
+        };
+        self.push(id.clone(), label, parent.to_owned(), node.value());
+        for child in node.children() {
+            self.visit(child, &id);
+        }
+    }
+
+    fn push(&mut self, id: String, label: String, parent: String, value: f64) {
+        self.ids.push(id);
+        self.labels.push(label);
+        self.parents.push(parent);
+        self.values.push(value);
+    }
+
+    /// Replaces the value of every node by the sum of its own value and those of its descendants.
+    pub fn with_totals(mut self) -> Hierarchy {
+        let index: HashMap<&str, usize> = self
+            .ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.as_str(), i))
+            .collect();
+        let mut totals = self.values.clone();
+        // Parents always precede their children, so walking backwards adds every subtree to its
+        // parent once it is complete.
+        for i in (0..self.ids.len()).rev() {
+            if let Some(&parent) = index.get(self.parents[i].as_str()) {
+                totals[parent] += totals[i];
+            }
+        }
+        self.values = totals;
+        self
+    }
+
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn parents(&self) -> &[String] {
+        &self.parents
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Splits the hierarchy into its ids, labels, parents and values.
+    pub fn into_parts(self) -> (Vec<String>, Vec<String>, Vec<String>, Vec<f64>) {
+        (self.ids, self.labels, self.parents, self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dir {
+        name: &'static str,
+        size: f64,
+        entries: Vec<Dir>,
+    }
+
+    impl TreeNode for Dir {
+        fn label(&self) -> String {
+            self.name.to_owned()
+        }
+
+        fn value(&self) -> f64 {
+            self.size
+        }
+
+        fn children(&self) -> &[Dir] {
+            &self.entries
+        }
+    }
+
+    fn dir(name: &'static str, size: f64, entries: Vec<Dir>) -> Dir {
+        Dir {
+            name,
+            size,
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_from_paths() {
+        let hierarchy = Hierarchy::from_paths(
+            vec![
+                ("usr/lib/libc.so", 2.),
+                ("/usr/bin/ls", 1.),
+                ("usr/lib", 0.5),
+                ("etc/hosts", 3.),
+                ("usr/bin/ls", 1.),
+            ],
+            '/',
+        );
+        assert_eq!(
+            hierarchy.ids(),
+            [
+                "usr",
+                "usr/lib",
+                "usr/lib/libc.so",
+                "usr/bin",
+                "usr/bin/ls",
+                "etc",
+                "etc/hosts"
+            ]
+        );
+        assert_eq!(
+            hierarchy.labels(),
+            ["usr", "lib", "libc.so", "bin", "ls", "etc", "hosts"]
+        );
+        assert_eq!(
+            hierarchy.parents(),
+            ["", "usr", "usr/lib", "usr", "usr/bin", "", "etc"]
+        );
+        assert_eq!(hierarchy.values(), [0., 0.5, 2., 0., 2., 0., 3.]);
+        assert_eq!(
+            hierarchy.with_totals().values(),
+            [4.5, 2.5, 2., 2., 2., 3., 3.]
+        );
+    }
+
+    #[test]
+    fn test_from_tree() {
+        let tree = vec![dir(
+            "root",
+            1.,
+            vec![
+                dir("a", 2., vec![dir("b", 3., vec![])]),
+                dir("c", 4., vec![]),
+            ],
+        )];
+        let (ids, labels, parents, values) = Hierarchy::from_tree(&tree).into_parts();
+        assert_eq!(ids, ["root", "root/a", "root/a/b", "root/c"]);
+        assert_eq!(labels, ["root", "a", "b", "c"]);
+        assert_eq!(parents, ["", "root", "root/a", "root"]);
+        assert_eq!(values, [1., 2., 3., 4.]);
+    }
+}
@@ -1,10 +1,11 @@
 //! Histogram plot
 
 use crate::common::{Calendar, Dim, ErrorData, HoverInfo, Label, Marker, Orientation, PlotType};
+use crate::layout::{XAxisId, YAxisId};
 use crate::Trace;
+use plotly_derive::FieldSetter;
 use serde::Serialize;
 
-use crate::private;
 use crate::private::copy_iterable_to_vec;
 
 #[cfg(feature = "plotly_ndarray")]
@@ -71,7 +72,7 @@ pub enum CurrentBin {
     Half,
 }
 
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
 pub struct Cumulative {
     #[serde(skip_serializing_if = "Option::is_none")]
     enabled: Option<bool>,
@@ -89,25 +90,11 @@ impl Cumulative {
             current_bin: None,
         }
     }
-
-    pub fn enabled(mut self, enabled: bool) -> Cumulative {
-        self.enabled = Some(enabled);
-        self
-    }
-
-    pub fn direction(mut self, direction: HistDirection) -> Cumulative {
-        self.direction = Some(direction);
-        self
-    }
-
-    pub fn current_bin(mut self, current_bin: CurrentBin) -> Cumulative {
-        self.current_bin = Some(current_bin);
-        self
-    }
 }
 
-#[derive(Serialize, Clone, Debug)]
-pub struct Histogram<H> {
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Histogram<X, Y = X> {
+    #[field_setter(skip)]
     r#type: PlotType,
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
@@ -119,10 +106,12 @@ pub struct Histogram<H> {
     legend_group: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    x: Option<Vec<H>>,
+    x: Option<Vec<X>>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    y: Option<Vec<H>>,
+    y: Option<Vec<Y>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     text: Option<Dim<String>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
@@ -132,9 +121,9 @@ pub struct Histogram<H> {
     #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
     hover_template: Option<Dim<String>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisId>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisId>,
     orientation: Option<Orientation>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
     hist_func: Option<HistFunc>,
@@ -174,7 +163,7 @@ pub struct Histogram<H> {
     y_calendar: Option<Calendar>,
 }
 
-impl<H> Default for Histogram<H> {
+impl<X, Y> Default for Histogram<X, Y> {
     fn default() -> Self {
         Histogram {
             r#type: PlotType::Histogram,
@@ -215,32 +204,46 @@ impl<H> Default for Histogram<H> {
 }
 
 impl<H> Histogram<H> {
-    pub fn new(x: Vec<H>) -> Box<Self> {
-        Box::new(Histogram {
+    pub fn new(x: Vec<H>) -> Self {
+        Histogram {
             r#type: PlotType::Histogram,
             x: Some(x),
             ..Default::default()
-        })
+        }
     }
 
-    pub fn new_xy(x: Vec<H>, y: Vec<H>) -> Box<Self> {
-        Box::new(Histogram {
+    pub fn new_vertical(y: Vec<H>) -> Self {
+        let y = copy_iterable_to_vec(y);
+        Histogram {
             r#type: PlotType::Histogram,
-            x: Some(x),
             y: Some(y),
             ..Default::default()
-        })
+        }
     }
+}
 
-    pub fn new_vertical(y: Vec<H>) -> Box<Self> {
-        let y = copy_iterable_to_vec(y);
-        Box::new(Histogram {
+impl<X, Y> Histogram<X, Y> {
+    /// A histogram of the `x` samples that aggregates the `y` values of each bin with the
+    /// `hist_func`, or of the `y` samples aggregating the `x` values with `Orientation::Horizontal`.
+    pub fn new_xy(x: Vec<X>, y: Vec<Y>) -> Self {
+        Self::from_samples(Some(x), Some(y))
+    }
+
+    pub(crate) fn from_samples(x: Option<Vec<X>>, y: Option<Vec<Y>>) -> Self {
+        Histogram {
             r#type: PlotType::Histogram,
-            y: Some(y),
+            x,
+            y,
             ..Default::default()
-        })
+        }
     }
+}
 
+#[cfg(feature = "plotly_ndarray")]
+impl<H> Histogram<H>
+where
+    H: Serialize + Clone + Default + 'static,
+{
     /// Produces `Histogram` traces from a 2 dimensional tensor (`traces_matrix`) indexed by `x`. This
     /// function requires the `ndarray` feature.
     ///
@@ -253,7 +256,7 @@ impl<H> Histogram<H> {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use plotly::common::Mode;
     /// use plotly::{Plot, Histogram, ArrayTraces};
     /// use ndarray::{Array, Ix1, Ix2};
@@ -292,14 +295,13 @@ impl<H> Histogram<H> {
     ///     Ok(())
     /// }
     /// ```
-    #[cfg(feature = "plotly_ndarray")]
     pub fn to_traces(
         &self,
         traces_matrix: Array<H, Ix2>,
         array_traces: ArrayTraces,
     ) -> Vec<Box<dyn Trace>> {
         let mut traces: Vec<Box<dyn Trace>> = Vec::new();
-        let mut trace_vectors = private::trace_vectors_from(traces_matrix, array_traces);
+        let mut trace_vectors = crate::private::trace_vectors_from(traces_matrix, array_traces);
         trace_vectors.reverse();
         while !trace_vectors.is_empty() {
             let mut sc = Box::new(self.clone());
@@ -313,187 +315,19 @@ impl<H> Histogram<H> {
         traces
     }
 
-    #[cfg(feature = "plotly_ndarray")]
-    pub fn from_array(x: Array<H, Ix1>) -> Box<Self> {
-        Box::new(Histogram {
+    pub fn from_array(x: Array<H, Ix1>) -> Self {
+        Histogram {
             r#type: PlotType::Histogram,
             x: Some(x.to_vec()),
             ..Default::default()
-        })
-    }
-
-    pub fn name(&mut self, name: &str) -> &mut Self {
-        self.name = Some(name.to_owned());
-        self
-    }
-
-    pub fn visible(&mut self, visible: bool) -> &mut Self {
-        self.visible = Some(visible);
-        self
-    }
-
-    pub fn show_legend(&mut self, show_legend: bool) -> &mut Self {
-        self.show_legend = Some(show_legend);
-        self
-    }
-
-    pub fn legend_group(&mut self, legend_group: &str) -> &mut Self {
-        self.legend_group = Some(legend_group.to_owned());
-        self
-    }
-
-    pub fn opacity(&mut self, opacity: f64) -> &mut Self {
-        self.opacity = Some(opacity);
-        self
-    }
-
-    pub fn text(&mut self, text: &str) -> &mut Self {
-        self.text = Some(Dim::Scalar(text.to_owned()));
-        self
-    }
-
-    pub fn text_array<S: AsRef<str>>(&mut self, text: Vec<S>) -> &mut Self {
-        let text = private::owned_string_vector(text);
-        self.text = Some(Dim::Vector(text));
-        self
-    }
-
-    pub fn hover_text(&mut self, hover_text: &str) -> &mut Self {
-        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
-        self
-    }
-
-    pub fn hover_text_array<S: AsRef<str>>(&mut self, hover_text: Vec<S>) -> &mut Self {
-        let hover_text = private::owned_string_vector(hover_text);
-        self.hover_text = Some(Dim::Vector(hover_text));
-        self
-    }
-
-    pub fn hover_info(&mut self, hover_info: HoverInfo) -> &mut Self {
-        self.hover_info = Some(hover_info);
-        self
-    }
-
-    pub fn hover_template(&mut self, hover_template: &str) -> &mut Self {
-        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
-        self
-    }
-
-    pub fn x_axis(&mut self, axis: &str) -> &mut Self {
-        self.x_axis = Some(axis.to_owned());
-        self
-    }
-
-    pub fn y_axis(&mut self, axis: &str) -> &mut Self {
-        self.y_axis = Some(axis.to_owned());
-        self
-    }
-
-    pub fn hover_template_array<S: AsRef<str>>(&mut self, hover_template: Vec<S>) -> &mut Self {
-        let hover_template = private::owned_string_vector(hover_template);
-        self.hover_template = Some(Dim::Vector(hover_template));
-        self
-    }
-
-    pub fn orientation(&mut self, orientation: Orientation) -> &mut Self {
-        self.orientation = Some(orientation);
-        self
-    }
-
-    pub fn hist_func(&mut self, hist_func: HistFunc) -> &mut Self {
-        self.hist_func = Some(hist_func);
-        self
-    }
-
-    pub fn hist_norm(&mut self, hist_norm: HistNorm) -> &mut Self {
-        self.hist_norm = Some(hist_norm);
-        self
-    }
-
-    pub fn alignment_group(&mut self, alignment_group: &str) -> &mut Self {
-        self.alignment_group = Some(alignment_group.to_owned());
-        self
-    }
-
-    pub fn offset_group(&mut self, offset_group: &str) -> &mut Self {
-        self.offset_group = Some(offset_group.to_owned());
-        self
-    }
-
-    pub fn n_bins_x(&mut self, n_bins_x: usize) -> &mut Self {
-        self.n_bins_x = Some(n_bins_x);
-        self
-    }
-
-    pub fn n_bins_y(&mut self, n_bins_y: usize) -> &mut Self {
-        self.n_bins_y = Some(n_bins_y);
-        self
-    }
-
-    pub fn auto_bin_x(&mut self, auto_bin_x: bool) -> &mut Self {
-        self.auto_bin_x = Some(auto_bin_x);
-        self
-    }
-
-    pub fn auto_bin_y(&mut self, auto_bin_y: bool) -> &mut Self {
-        self.auto_bin_y = Some(auto_bin_y);
-        self
-    }
-
-    pub fn bin_group(&mut self, bin_group: &str) -> &mut Self {
-        self.bin_group = Some(bin_group.to_owned());
-        self
-    }
-
-    pub fn x_bins(&mut self, x_bins: Bins) -> &mut Self {
-        self.x_bins = Some(x_bins);
-        self
-    }
-
-    pub fn y_bins(&mut self, y_bins: Bins) -> &mut Self {
-        self.y_bins = Some(y_bins);
-        self
-    }
-
-    pub fn marker(&mut self, marker: Marker) -> &mut Self {
-        self.marker = Some(marker);
-        self
-    }
-
-    pub fn error_x(&mut self, error_x: ErrorData) -> &mut Self {
-        self.error_x = Some(error_x);
-        self
-    }
-
-    pub fn error_y(&mut self, error_y: ErrorData) -> &mut Self {
-        self.error_y = Some(error_y);
-        self
-    }
-
-    pub fn cumulative(&mut self, cumulative: Cumulative) -> &mut Self {
-        self.cumulative = Some(cumulative);
-        self
-    }
-
-    pub fn hover_label(&mut self, hover_label: Label) -> &mut Self {
-        self.hover_label = Some(hover_label);
-        self
-    }
-
-    pub fn x_calendar(&mut self, x_calendar: Calendar) -> &mut Self {
-        self.x_calendar = Some(x_calendar);
-        self
-    }
-
-    pub fn y_calendar(&mut self, y_calendar: Calendar) -> &mut Self {
-        self.y_calendar = Some(y_calendar);
-        self
+        }
     }
 }
 
-impl<H> Trace for Histogram<H>
+impl<X, Y> Trace for Histogram<X, Y>
 where
-    H: Serialize,
+    X: Serialize,
+    Y: Serialize,
 {
     fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
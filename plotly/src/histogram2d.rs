@@ -0,0 +1,284 @@
+//! 2D histogram
+
+use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, Orientation, PlotType};
+use crate::histogram::{Bins, HistFunc, HistNorm};
+use crate::layout::{XAxisId, YAxisId};
+use crate::private::TruthyEnum;
+use crate::{Histogram, Trace};
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Smoothing of the bins colors.
+#[derive(Serialize, Clone, Debug)]
+pub enum ZSmooth {
+    #[serde(rename = "fast")]
+    Fast,
+    #[serde(rename = "best")]
+    Best,
+    #[serde(rename = "false")]
+    False,
+}
+
+/// The settings of a 2D histogram its marginal histograms share.
+pub(crate) struct Marginals<'a> {
+    pub(crate) z: Option<&'a [f64]>,
+    pub(crate) hist_func: Option<&'a HistFunc>,
+    pub(crate) n_bins_x: Option<usize>,
+    pub(crate) n_bins_y: Option<usize>,
+    pub(crate) x_bins: Option<&'a Bins>,
+    pub(crate) y_bins: Option<&'a Bins>,
+    pub(crate) x_bin_group: Option<&'a str>,
+    pub(crate) y_bin_group: Option<&'a str>,
+    pub(crate) hist_norm: Option<&'a HistNorm>,
+}
+
+pub(crate) fn marginal_histograms<X, Y>(
+    x: &[X],
+    y: &[Y],
+    marginals: Marginals,
+) -> (Histogram<X, f64>, Histogram<f64, Y>)
+where
+    X: Clone,
+    Y: Clone,
+{
+    let weights = marginals.z.map(|z| z.to_vec());
+    let mut x_histogram = Histogram::from_samples(Some(x.to_vec()), weights.clone());
+    let mut y_histogram = Histogram::from_samples(weights.clone(), Some(y.to_vec()));
+    if weights.is_some() {
+        y_histogram = y_histogram.orientation(Orientation::Horizontal);
+    }
+    if let Some(hist_func) = marginals.hist_func {
+        x_histogram = x_histogram.hist_func(hist_func.clone());
+        y_histogram = y_histogram.hist_func(hist_func.clone());
+    }
+    if let Some(n_bins_x) = marginals.n_bins_x {
+        x_histogram = x_histogram.n_bins_x(n_bins_x);
+    }
+    if let Some(n_bins_y) = marginals.n_bins_y {
+        y_histogram = y_histogram.n_bins_y(n_bins_y);
+    }
+    if let Some(x_bins) = marginals.x_bins {
+        x_histogram = x_histogram.x_bins(x_bins.clone());
+    }
+    if let Some(y_bins) = marginals.y_bins {
+        y_histogram = y_histogram.y_bins(y_bins.clone());
+    }
+    if let Some(x_bin_group) = marginals.x_bin_group {
+        x_histogram = x_histogram.bin_group(x_bin_group);
+    }
+    if let Some(y_bin_group) = marginals.y_bin_group {
+        y_histogram = y_histogram.bin_group(y_bin_group);
+    }
+    if let Some(hist_norm) = marginals.hist_norm {
+        x_histogram = x_histogram.hist_norm(hist_norm.clone());
+        y_histogram = y_histogram.hist_norm(hist_norm.clone());
+    }
+    (x_histogram, y_histogram)
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Histogram2d<X, Y> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    /// Weights of the samples, aggregated with the `hist_func`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<XAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<YAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    /// Maximum number of bins along x, used when `x_bins` are not set.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    /// Maximum number of bins along y, used when `y_bins` are not set.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybins")]
+    y_bins: Option<Bins>,
+    /// 2D histograms of the same bin group share their x and y bins.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bingroup")]
+    bin_group: Option<String>,
+    /// Traces of the same x bin group share their x bins, including 1D histograms with that
+    /// `bin_group`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    /// Traces of the same y bin group share their y bins, including 1D histograms with that
+    /// `bin_group`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    /// Horizontal gap between the bins in pixels.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xgap")]
+    x_gap: Option<f64>,
+    /// Vertical gap between the bins in pixels.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ygap")]
+    y_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zsmooth")]
+    z_smooth: Option<TruthyEnum<ZSmooth>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+}
+
+impl<X, Y> Histogram2d<X, Y> {
+    /// A 2D histogram of the `(x, y)` samples.
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+    {
+        Histogram2d {
+            x,
+            y,
+            r#type: PlotType::Histogram2d,
+            ..Default::default()
+        }
+    }
+
+    /// Histograms of the x and y samples, to draw along the axes of the 2D histogram, e.g. in the
+    /// cells above and to the right of it with `Subplots`. They use the same number of bins, bins,
+    /// bin groups and normalization; set the `x_bin_group` and `y_bin_group` for automatic bins to
+    /// match. When `z` is set, the marginals aggregate it with the same histogram function.
+    pub fn marginals(&self) -> (Histogram<X, f64>, Histogram<f64, Y>)
+    where
+        X: Clone,
+        Y: Clone,
+    {
+        marginal_histograms(
+            &self.x,
+            &self.y,
+            Marginals {
+                z: self.z.as_deref(),
+                hist_func: self.hist_func.as_ref(),
+                n_bins_x: self.n_bins_x,
+                n_bins_y: self.n_bins_y,
+                x_bins: self.x_bins.as_ref(),
+                y_bins: self.y_bins.as_ref(),
+                x_bin_group: self.x_bin_group.as_deref(),
+                y_bin_group: self.y_bin_group.as_deref(),
+                hist_norm: self.hist_norm.as_ref(),
+            },
+        )
+    }
+}
+
+impl<X, Y> Trace for Histogram2d<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::validate_figure;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_histogram2d() {
+        let histogram = Histogram2d::new(vec![1., 2., 2.], vec![3, 4, 4])
+            .z(vec![1., 0.5, 2.])
+            .hist_func(HistFunc::Sum)
+            .hist_norm(HistNorm::Percent)
+            .x_bins(Bins::new(0., 3., 0.5))
+            .n_bins_y(4)
+            .x_bin_group("x")
+            .y_bin_group("y")
+            .x_gap(1.)
+            .z_smooth(ZSmooth::False)
+            .zmax(100.);
+        let expected = json!({
+            "x": [1., 2., 2.],
+            "y": [3, 4, 4],
+            "type": "histogram2d",
+            "z": [1., 0.5, 2.],
+            "histfunc": "sum",
+            "histnorm": "percent",
+            "nbinsy": 4,
+            "xbins": {"start": 0., "end": 3., "size": 0.5},
+            "xbingroup": "x",
+            "ybingroup": "y",
+            "xgap": 1.,
+            "zsmooth": false,
+            "zmax": 100.,
+        });
+        let value = to_value(&histogram).unwrap();
+        assert_eq!(value, expected);
+
+        let (x_histogram, y_histogram) = histogram.marginals();
+        let x_value = to_value(&x_histogram).unwrap();
+        let y_value = to_value(&y_histogram).unwrap();
+        assert_eq!(x_value["x"], json!([1., 2., 2.]));
+        assert_eq!(x_value["y"], json!([1., 0.5, 2.]));
+        assert_eq!(x_value["histfunc"], json!("sum"));
+        assert_eq!(x_value["xbins"], expected["xbins"]);
+        assert_eq!(x_value["bingroup"], json!("x"));
+        assert_eq!(x_value["histnorm"], json!("percent"));
+        assert_eq!(y_value["y"], json!([3, 4, 4]));
+        assert_eq!(y_value["x"], json!([1., 0.5, 2.]));
+        assert_eq!(y_value["orientation"], json!("h"));
+        assert_eq!(y_value["histfunc"], json!("sum"));
+        assert_eq!(y_value["nbinsy"], json!(4));
+        assert_eq!(y_value["bingroup"], json!("y"));
+        assert_eq!(
+            validate_figure(&json!({"data": [value, x_value, y_value], "layout": {}})),
+            vec![]
+        );
+    }
+}
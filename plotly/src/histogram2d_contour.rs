@@ -0,0 +1,197 @@
+//! 2D histogram contour plot
+
+use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, Line, PlotType};
+use crate::contour::Contours;
+use crate::histogram::{Bins, HistFunc, HistNorm};
+use crate::histogram2d::{marginal_histograms, Marginals};
+use crate::layout::{XAxisId, YAxisId};
+use crate::{Histogram, Trace};
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Histogram2dContour<X, Y> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    /// Weights of the samples, aggregated with the `hist_func`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<XAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<YAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    /// Maximum number of bins along x, used when `x_bins` are not set.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    /// Maximum number of bins along y, used when `y_bins` are not set.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybins")]
+    y_bins: Option<Bins>,
+    /// 2D histograms of the same bin group share their x and y bins.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bingroup")]
+    bin_group: Option<String>,
+    /// Traces of the same x bin group share their x bins, including 1D histograms with that
+    /// `bin_group`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    /// Traces of the same y bin group share their y bins, including 1D histograms with that
+    /// `bin_group`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocontour")]
+    auto_contour: Option<bool>,
+    /// Maximum number of contour levels, used with `auto_contour`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ncontours")]
+    n_contours: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contours: Option<Contours>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+}
+
+impl<X, Y> Histogram2dContour<X, Y> {
+    /// Contours of the 2D histogram of the `(x, y)` samples.
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+    {
+        Histogram2dContour {
+            x,
+            y,
+            r#type: PlotType::Histogram2dContour,
+            ..Default::default()
+        }
+    }
+
+    /// Histograms of the x and y samples, to draw along the axes of the contours. See
+    /// `Histogram2d::marginals`.
+    pub fn marginals(&self) -> (Histogram<X, f64>, Histogram<f64, Y>)
+    where
+        X: Clone,
+        Y: Clone,
+    {
+        marginal_histograms(
+            &self.x,
+            &self.y,
+            Marginals {
+                z: self.z.as_deref(),
+                hist_func: self.hist_func.as_ref(),
+                n_bins_x: self.n_bins_x,
+                n_bins_y: self.n_bins_y,
+                x_bins: self.x_bins.as_ref(),
+                y_bins: self.y_bins.as_ref(),
+                x_bin_group: self.x_bin_group.as_deref(),
+                y_bin_group: self.y_bin_group.as_deref(),
+                hist_norm: self.hist_norm.as_ref(),
+            },
+        )
+    }
+}
+
+impl<X, Y> Trace for Histogram2dContour<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contour::ContoursColoring;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_histogram2d_contour() {
+        let histogram = Histogram2dContour::new(vec![1., 2., 2.], vec![3., 4., 4.])
+            .hist_norm(HistNorm::Probability)
+            .n_bins_x(10)
+            .y_bins(Bins::new(2., 5., 1.))
+            .n_contours(5)
+            .contours(
+                Contours::new()
+                    .coloring(ContoursColoring::HeatMap)
+                    .show_labels(true),
+            )
+            .line(Line::new().width(0.5));
+        let expected = json!({
+            "x": [1., 2., 2.],
+            "y": [3., 4., 4.],
+            "type": "histogram2dcontour",
+            "histnorm": "probability",
+            "nbinsx": 10,
+            "ybins": {"start": 2., "end": 5., "size": 1.},
+            "ncontours": 5,
+            "contours": {"coloring": "heatmap", "showlabels": true},
+            "line": {"width": 0.5},
+        });
+        assert_trace_json(&histogram, expected.clone());
+
+        let (x_histogram, y_histogram) = histogram.marginals();
+        assert_eq!(to_value(&x_histogram).unwrap()["nbinsx"], json!(10));
+        assert_eq!(to_value(&y_histogram).unwrap()["ybins"], expected["ybins"]);
+        assert_eq!(to_value(&y_histogram).unwrap().get("x"), None);
+    }
+}
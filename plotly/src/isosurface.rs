@@ -0,0 +1,215 @@
+//! Isosurface plot
+
+use crate::common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType};
+use crate::layout::SceneId;
+use crate::mesh3d::Contour;
+use crate::surface::{Lighting, Position};
+use crate::volume::{Caps, IsoSurfaces, Slices, SpaceFrame};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+#[cfg(feature = "plotly_ndarray")]
+use ndarray::{Array, Ix1, Ix3};
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Isosurface<X, Y, Z, V> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    z: Vec<Z>,
+    #[field_setter(skip)]
+    value: Vec<V>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// The scene the isosurfaces are drawn in, `SceneId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<SceneId>,
+    /// Value of the lowest isosurface, the minimum of the values by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "isomin")]
+    iso_min: Option<f64>,
+    /// Value of the highest isosurface, the maximum of the values by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "isomax")]
+    iso_max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    surface: Option<IsoSurfaces>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slices: Option<Slices>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caps: Option<Caps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contour: Option<Contour>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lighting: Option<Lighting>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lightposition")]
+    light_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<X, Y, Z, V> Isosurface<X, Y, Z, V> {
+    /// Isosurfaces of the value given at the coordinates of every point of the grid.
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>, value: Vec<V>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+        Z: Serialize + Default,
+        V: Serialize + Default,
+    {
+        Isosurface {
+            x,
+            y,
+            z,
+            value,
+            r#type: PlotType::Isosurface,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "plotly_ndarray")]
+impl<X, Y, Z, V> Isosurface<X, Y, Z, V>
+where
+    X: Serialize + Clone + Default,
+    Y: Serialize + Clone + Default,
+    Z: Serialize + Clone + Default,
+    V: Serialize + Clone + Default,
+{
+    /// Isosurfaces of the `values` of a grid, where `values[[i, j, k]]` is the value at
+    /// `(x[i], y[j], z[k])`. This function requires the `plotly_ndarray` feature.
+    ///
+    /// # Panics
+    /// When the shape of `values` is not the lengths of `x`, `y` and `z`.
+    pub fn from_array(
+        x: Array<X, Ix1>,
+        y: Array<Y, Ix1>,
+        z: Array<Z, Ix1>,
+        values: Array<V, Ix3>,
+    ) -> Self {
+        let (x, y, z, value) = crate::private::grid_vectors_from(x, y, z, values);
+        Isosurface::new(x, y, z, value)
+    }
+}
+
+impl<X, Y, Z, V> Trace for Isosurface<X, Y, Z, V>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use crate::volume::Cap;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_isosurface() {
+        let isosurface = Isosurface::new(
+            vec![0, 0, 1, 1],
+            vec![0, 1, 0, 1],
+            vec![0, 0, 0, 0],
+            vec![1, 2, 3, 4],
+        )
+        .iso_min(2.)
+        .iso_max(3.)
+        .surface(IsoSurfaces::new().count(3).fill(0.8))
+        .caps(
+            Caps::new()
+                .x(Cap::new().show(false))
+                .y(Cap::new().fill(0.5)),
+        )
+        .flat_shading(false);
+        let expected = json!({
+            "x": [0, 0, 1, 1],
+            "y": [0, 1, 0, 1],
+            "z": [0, 0, 0, 0],
+            "value": [1, 2, 3, 4],
+            "type": "isosurface",
+            "isomin": 2.,
+            "isomax": 3.,
+            "surface": {"count": 3, "fill": 0.8},
+            "caps": {"x": {"show": false}, "y": {"fill": 0.5}},
+            "flatshading": false,
+        });
+        assert_trace_json(&isosurface, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    fn test_isosurface_from_array() {
+        let grid = Array::from(vec![0., 1.]);
+        let values = Array::from_shape_fn((2, 2, 2), |(i, j, k)| (4 * i + 2 * j + k) as f64);
+        let isosurface = Isosurface::from_array(grid.clone(), grid.clone(), grid, values);
+        let value = serde_json::to_value(&isosurface).unwrap();
+        assert_eq!(value["x"], json!([0., 0., 0., 0., 1., 1., 1., 1.]));
+        assert_eq!(value["z"], json!([0., 1., 0., 1., 0., 1., 0., 1.]));
+        assert_eq!(value["value"], json!([0., 1., 2., 3., 4., 5., 6., 7.]));
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    #[should_panic(
+        expected = "the shape of the values must be the lengths of the x, y and z coordinates"
+    )]
+    fn test_isosurface_from_array_shape_mismatch() {
+        let grid = Array::from(vec![0., 1.]);
+        let _ = Isosurface::from_array(
+            grid.clone(),
+            grid.clone(),
+            grid,
+            Array::<f64, _>::zeros((2, 2, 3)),
+        );
+    }
+}
@@ -1,12 +1,16 @@
-use crate::common::color::{Color, ColorWrapper};
+use crate::common::color::ColorWrapper;
 use crate::common::{
-    Anchor, Calendar, ColorBar, ColorScale, DashType, Font, Label, Orientation, Side,
-    TickFormatStop, TickMode, Title,
+    Anchor, Calendar, ColorBar, ColorScale, DashType, Domain, ExponentFormat, FlagNames, Flags,
+    Font, Label, Orientation, RotationDirection, Side, TickFormatStop, TickMode, Title,
 };
 use crate::plot::Trace;
-use crate::private;
-use crate::private::{to_num_or_string_wrapper, NumOrString, NumOrStringWrapper, TruthyEnum};
-use serde::Serialize;
+use crate::private::{NumOrString, NumOrStringWrapper, TruthyEnum};
+use crate::template::Template;
+use plotly_derive::FieldSetter;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
 
 #[derive(Serialize, Debug)]
 pub enum AxisType {
@@ -58,12 +62,18 @@ pub enum RangeMode {
     NonNegative,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Debug)]
 pub enum TicksDirection {
     #[serde(rename = "outside")]
     Outside,
     #[serde(rename = "inside")]
     Inside,
+    /// No ticks are drawn.
+    #[serde(rename = "")]
+    None,
+    /// Any other value, passed to plotly.js as is.
+    #[serde(untagged)]
+    Raw(String),
 }
 
 #[derive(Serialize, Debug)]
@@ -74,7 +84,7 @@ pub enum TicksPosition {
     Boundaries,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Debug)]
 pub enum ArrayShow {
     #[serde(rename = "all")]
     All,
@@ -84,6 +94,9 @@ pub enum ArrayShow {
     Last,
     #[serde(rename = "none")]
     None,
+    /// Any other value, passed to plotly.js as is.
+    #[serde(untagged)]
+    Raw(String),
 }
 
 #[derive(Serialize, Debug)]
@@ -132,7 +145,7 @@ pub enum WaterfallMode {
     Overlay,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Legend {
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<ColorWrapper>,
@@ -172,86 +185,6 @@ impl Legend {
     pub fn new() -> Legend {
         Default::default()
     }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> Legend {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn border_color<C: Color>(mut self, border_color: C) -> Legend {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
-
-    pub fn border_width(mut self, border_width: usize) -> Legend {
-        self.border_width = Some(border_width);
-        self
-    }
-
-    pub fn font(mut self, font: Font) -> Legend {
-        self.font = Some(font);
-        self
-    }
-
-    pub fn orientation(mut self, orientation: Orientation) -> Legend {
-        self.orientation = Some(orientation);
-        self
-    }
-
-    pub fn trace_order(mut self, trace_order: &str) -> Legend {
-        self.trace_order = Some(trace_order.to_owned());
-        self
-    }
-
-    pub fn trace_group_gap(mut self, trace_group_gap: usize) -> Legend {
-        self.trace_group_gap = Some(trace_group_gap);
-        self
-    }
-
-    pub fn item_sizing(mut self, item_sizing: &str) -> Legend {
-        self.item_sizing = Some(item_sizing.to_owned());
-        self
-    }
-
-    pub fn item_click(mut self, item_click: &str) -> Legend {
-        self.item_click = Some(item_click.to_owned());
-        self
-    }
-
-    pub fn item_double_click(mut self, item_double_click: &str) -> Legend {
-        self.item_double_click = Some(item_double_click.to_owned());
-        self
-    }
-
-    pub fn x(mut self, x: f64) -> Legend {
-        self.x = Some(x);
-        self
-    }
-
-    pub fn x_anchor(mut self, x_anchor: Anchor) -> Legend {
-        self.x_anchor = Some(x_anchor);
-        self
-    }
-
-    pub fn y(mut self, y: f64) -> Legend {
-        self.y = Some(y);
-        self
-    }
-
-    pub fn y_anchor(mut self, y_anchor: Anchor) -> Legend {
-        self.y_anchor = Some(y_anchor);
-        self
-    }
-
-    pub fn valign(mut self, valign: VAlign) -> Legend {
-        self.valign = Some(valign);
-        self
-    }
-
-    pub fn title(mut self, title: Title) -> Legend {
-        self.title = Some(title);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -274,14 +207,18 @@ pub enum HAlign {
     Right,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Margin {
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     l: Option<usize>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     r: Option<usize>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     t: Option<usize>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     b: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -314,19 +251,9 @@ impl Margin {
         self.b = Some(bottom);
         self
     }
-
-    pub fn pad(mut self, pad: usize) -> Margin {
-        self.pad = Some(pad);
-        self
-    }
-
-    pub fn auto_expand(mut self, auto_expand: bool) -> Margin {
-        self.auto_expand = Some(auto_expand);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct LayoutColorScale {
     #[serde(skip_serializing_if = "Option::is_none")]
     sequential: Option<ColorScale>,
@@ -340,21 +267,6 @@ impl LayoutColorScale {
     pub fn new() -> LayoutColorScale {
         Default::default()
     }
-
-    pub fn sequential(mut self, sequential: ColorScale) -> LayoutColorScale {
-        self.sequential = Some(sequential);
-        self
-    }
-
-    pub fn sequential_minus(mut self, sequential_minus: ColorScale) -> LayoutColorScale {
-        self.sequential_minus = Some(sequential_minus);
-        self
-    }
-
-    pub fn diverging(mut self, diverging: ColorScale) -> LayoutColorScale {
-        self.diverging = Some(diverging);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -367,7 +279,7 @@ pub enum SliderRangeMode {
     Match,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct RangeSliderYAxis {
     #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
     range_mode: Option<SliderRangeMode>,
@@ -379,20 +291,9 @@ impl RangeSliderYAxis {
     pub fn new() -> RangeSliderYAxis {
         Default::default()
     }
-
-    pub fn range_mode(mut self, range_mode: SliderRangeMode) -> RangeSliderYAxis {
-        self.range_mode = Some(range_mode);
-        self
-    }
-
-    pub fn range<C: NumOrString>(mut self, range: Vec<C>) -> RangeSliderYAxis {
-        let wrapped = to_num_or_string_wrapper(range);
-        self.range = Some(wrapped);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct RangeSlider {
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<ColorWrapper>,
@@ -416,47 +317,6 @@ impl RangeSlider {
     pub fn new() -> RangeSlider {
         Default::default()
     }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> RangeSlider {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn border_color<C: Color>(mut self, border_color: C) -> RangeSlider {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
-
-    pub fn border_width(mut self, border_width: u64) -> RangeSlider {
-        self.border_width = Some(border_width);
-        self
-    }
-
-    pub fn auto_range(mut self, auto_range: bool) -> RangeSlider {
-        self.auto_range = Some(auto_range);
-        self
-    }
-
-    pub fn range<C: NumOrString>(mut self, range: Vec<C>) -> RangeSlider {
-        let wrapped = to_num_or_string_wrapper(range);
-        self.range = Some(wrapped);
-        self
-    }
-
-    pub fn thickness(mut self, thickness: f64) -> RangeSlider {
-        self.thickness = Some(thickness);
-        self
-    }
-
-    pub fn visible(mut self, visible: bool) -> RangeSlider {
-        self.visible = Some(visible);
-        self
-    }
-
-    pub fn y_axis(mut self, axis: RangeSliderYAxis) -> RangeSlider {
-        self.y_axis = Some(axis);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -485,7 +345,7 @@ pub enum StepMode {
     ToDate,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct SelectorButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
@@ -507,44 +367,9 @@ impl SelectorButton {
     pub fn new() -> SelectorButton {
         Default::default()
     }
-
-    pub fn visible(mut self, visible: bool) -> SelectorButton {
-        self.visible = Some(visible);
-        self
-    }
-
-    pub fn step(mut self, step: SelectorStep) -> SelectorButton {
-        self.step = Some(step);
-        self
-    }
-
-    pub fn step_mode(mut self, step_mode: StepMode) -> SelectorButton {
-        self.step_mode = Some(step_mode);
-        self
-    }
-
-    pub fn count(mut self, count: usize) -> SelectorButton {
-        self.count = Some(count);
-        self
-    }
-
-    pub fn label(mut self, label: &str) -> SelectorButton {
-        self.label = Some(label.to_owned());
-        self
-    }
-
-    pub fn name(mut self, name: &str) -> SelectorButton {
-        self.name = Some(name.to_owned());
-        self
-    }
-
-    pub fn template_item_name(mut self, template_item_name: &str) -> SelectorButton {
-        self.template_item_name = Some(template_item_name.to_owned());
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct RangeSelector {
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
@@ -574,64 +399,9 @@ impl RangeSelector {
     pub fn new() -> RangeSelector {
         Default::default()
     }
-
-    pub fn visible(mut self, visible: bool) -> RangeSelector {
-        self.visible = Some(visible);
-        self
-    }
-
-    pub fn buttons(mut self, buttons: Vec<SelectorButton>) -> RangeSelector {
-        self.buttons = Some(buttons);
-        self
-    }
-
-    pub fn x(mut self, x: f64) -> RangeSelector {
-        self.x = Some(x);
-        self
-    }
-
-    pub fn x_anchor(mut self, x_anchor: Anchor) -> RangeSelector {
-        self.x_anchor = Some(x_anchor);
-        self
-    }
-
-    pub fn y(mut self, y: f64) -> RangeSelector {
-        self.y = Some(y);
-        self
-    }
-
-    pub fn y_anchor(mut self, y_anchor: Anchor) -> RangeSelector {
-        self.y_anchor = Some(y_anchor);
-        self
-    }
-
-    pub fn font(mut self, font: Font) -> RangeSelector {
-        self.font = Some(font);
-        self
-    }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> RangeSelector {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn active_color<C: Color>(mut self, active_color: C) -> RangeSelector {
-        self.background_color = Some(active_color.to_color());
-        self
-    }
-
-    pub fn border_color<C: Color>(mut self, border_color: C) -> RangeSelector {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
-
-    pub fn border_width(mut self, border_width: usize) -> RangeSelector {
-        self.border_width = Some(border_width);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct ColorAxis {
     #[serde(skip_serializing_if = "Option::is_none")]
     cauto: Option<bool>,
@@ -657,54 +427,9 @@ impl ColorAxis {
     pub fn new() -> ColorAxis {
         Default::default()
     }
-
-    pub fn cauto(mut self, cauto: bool) -> ColorAxis {
-        self.cauto = Some(cauto);
-        self
-    }
-
-    pub fn cmin(mut self, cmin: f64) -> ColorAxis {
-        self.cmin = Some(cmin);
-        self
-    }
-
-    pub fn cmax(mut self, cmax: f64) -> ColorAxis {
-        self.cmax = Some(cmax);
-        self
-    }
-
-    pub fn cmid(mut self, cmid: f64) -> ColorAxis {
-        self.cmid = Some(cmid);
-        self
-    }
-
-    pub fn color_scale(mut self, color_scale: ColorScale) -> ColorAxis {
-        self.color_scale = Some(color_scale);
-        self
-    }
-
-    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> ColorAxis {
-        self.auto_color_scale = Some(auto_color_scale);
-        self
-    }
-
-    pub fn reverse_scale(mut self, reverse_scale: bool) -> ColorAxis {
-        self.reverse_scale = Some(reverse_scale);
-        self
-    }
-
-    pub fn show_scale(mut self, show_scale: bool) -> ColorAxis {
-        self.show_scale = Some(show_scale);
-        self
-    }
-
-    pub fn color_bar(mut self, color_bar: ColorBar) -> ColorAxis {
-        self.color_bar = Some(color_bar);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Axis {
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
@@ -714,7 +439,7 @@ pub struct Axis {
     title: Option<Title>,
     #[serde(skip_serializing_if = "Option::is_none")]
     r#type: Option<AxisType>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "auto_range")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
     auto_range: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
     range_mode: Option<RangeMode>,
@@ -738,7 +463,7 @@ pub struct Axis {
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
     tick_values: Option<Vec<f64>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "tick_text")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
     tick_text: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     ticks: Option<TicksDirection>,
@@ -765,7 +490,7 @@ pub struct Axis {
     #[serde(skip_serializing_if = "Option::is_none", rename = "spikedash")]
     spike_dash: Option<DashType>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "spikemode")]
-    spike_mode: Option<String>,
+    spike_mode: Option<SpikeMode>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "spikesnap")]
     spike_snap: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
@@ -816,12 +541,21 @@ pub struct Axis {
     divider_color: Option<ColorWrapper>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "dividerwidth")]
     divider_width: Option<usize>,
+    /// Anchors this axis to an axis of the other letter, the position of this axis then follows
+    /// the domain of that axis. With `AxisRef::Free` the axis is placed with `position`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    anchor: Option<String>,
+    anchor: Option<AxisRef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     side: Option<Side>,
+    /// Draws this axis on top of the plotting area of another axis of the same letter, e.g. a
+    /// secondary y axis overlaying `AxisRef::y(1)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overlaying: Option<AxisRef>,
+    /// Makes the range of this axis match the range of another axis of the same letter. Zooming or
+    /// panning one of the axes updates both.
     #[serde(skip_serializing_if = "Option::is_none")]
-    overlaying: Option<String>,
+    matches: Option<AxisId>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     domain: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -839,315 +573,337 @@ impl Axis {
         Default::default()
     }
 
-    pub fn visible(mut self, visible: bool) -> Axis {
-        self.visible = Some(visible);
+    pub fn domain(mut self, domain: &[f64]) -> Axis {
+        self.domain = Some(domain.to_vec());
         self
     }
+}
 
-    pub fn color<C: Color>(mut self, color: C) -> Axis {
-        self.color = Some(color.to_color());
-        self
-    }
+/// Flags of `Axis::spike_mode`, combined with `|`, e.g. `SpikeMode::TO_AXIS | SpikeMode::MARKER`.
+pub type SpikeMode = Flags<SpikeModeNames>;
 
-    pub fn title(mut self, title: Title) -> Axis {
-        self.title = Some(title);
-        self
-    }
+/// The flag names of `SpikeMode`.
+pub enum SpikeModeNames {}
 
-    pub fn type_(mut self, t: AxisType) -> Axis {
-        self.r#type = Some(t);
-        self
-    }
+impl FlagNames for SpikeModeNames {
+    const NAMES: &'static [&'static str] = &["toaxis", "across", "marker"];
+    const EMPTY: &'static str = "";
+}
 
-    pub fn auto_range(mut self, auto_range: bool) -> Axis {
-        self.auto_range = Some(auto_range);
-        self
-    }
+impl SpikeMode {
+    /// Draws the spike line from the data point to the axis.
+    pub const TO_AXIS: SpikeMode = Flags::flag(0);
+    /// Draws the spike line across the whole plotting area.
+    pub const ACROSS: SpikeMode = Flags::flag(1);
+    /// Draws a marker where the spike line meets the axis.
+    pub const MARKER: SpikeMode = Flags::flag(2);
+}
 
-    pub fn range_mode(mut self, range_mode: RangeMode) -> Axis {
-        self.range_mode = Some(range_mode);
-        self
-    }
+/// Identifies a cartesian axis of the `Layout`. Indices start at 1: `AxisId::x(1)` is the `xaxis`
+/// of the layout, which traces reference as "x", `AxisId::x(2)` is `xaxis2`, referenced as "x2",
+/// and so on. Serializes as the reference, which is how traces select the axes they are drawn on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AxisId {
+    X(NonZeroUsize),
+    Y(NonZeroUsize),
+}
 
-    pub fn range<C: NumOrString>(mut self, range: Vec<C>) -> Axis {
-        let wrapped = to_num_or_string_wrapper(range);
-        self.range = Some(wrapped);
-        self
+impl AxisId {
+    /// The x axis with the given index. Panics if `index` is 0.
+    pub fn x(index: usize) -> AxisId {
+        AxisId::X(axis_index(index))
     }
 
-    pub fn fixed_range(mut self, fixed_range: bool) -> Axis {
-        self.fixed_range = Some(fixed_range);
-        self
+    /// The y axis with the given index. Panics if `index` is 0.
+    pub fn y(index: usize) -> AxisId {
+        AxisId::Y(axis_index(index))
     }
 
-    pub fn constrain(mut self, constrain: AxisConstrain) -> Axis {
-        self.constrain = Some(constrain);
-        self
+    pub fn index(&self) -> usize {
+        match *self {
+            AxisId::X(index) | AxisId::Y(index) => index.get(),
+        }
     }
 
-    pub fn constrain_toward(mut self, constrain_toward: ConstrainDirection) -> Axis {
-        self.constrain_toward = Some(constrain_toward);
-        self
+    /// The name of the axis in the layout, e.g. "xaxis" or "yaxis2".
+    pub fn layout_name(&self) -> String {
+        format!("{}axis{}", self.letter(), self.suffix())
     }
 
-    pub fn tick_mode(mut self, tick_mode: TickMode) -> Axis {
-        self.tick_mode = Some(tick_mode);
-        self
+    /// The name traces, shapes and annotations use to reference the axis, e.g. "x" or "y2".
+    pub fn reference(&self) -> String {
+        format!("{}{}", self.letter(), self.suffix())
     }
 
-    pub fn n_ticks(mut self, n_ticks: usize) -> Axis {
-        self.n_ticks = Some(n_ticks);
-        self
+    fn letter(&self) -> &'static str {
+        match self {
+            AxisId::X(_) => "x",
+            AxisId::Y(_) => "y",
+        }
     }
 
-    pub fn tick0(mut self, tick0: f64) -> Axis {
-        self.tick0 = Some(tick0);
-        self
+    fn suffix(&self) -> String {
+        match self.index() {
+            1 => String::new(),
+            index => index.to_string(),
+        }
     }
+}
 
-    pub fn dtick(mut self, dtick: f64) -> Axis {
-        self.dtick = Some(dtick);
-        self
-    }
+fn axis_index(index: usize) -> NonZeroUsize {
+    NonZeroUsize::new(index).expect("axis indices start at 1")
+}
 
-    pub fn tick_values(mut self, tick_values: Vec<f64>) -> Axis {
-        self.tick_values = Some(tick_values);
-        self
+// Axes are ordered by index first, so that the layout lists "xaxis", "yaxis", "xaxis2", ...
+impl Ord for AxisId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |id: &AxisId| (id.index(), matches!(id, AxisId::Y(_)));
+        key(self).cmp(&key(other))
     }
+}
 
-    pub fn tick_text(mut self, tick_text: Vec<String>) -> Axis {
-        self.tick_text = Some(tick_text);
-        self
+impl PartialOrd for AxisId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    pub fn ticks(mut self, ticks: TicksDirection) -> Axis {
-        self.ticks = Some(ticks);
-        self
+impl Serialize for AxisId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.reference())
     }
+}
 
-    pub fn ticks_on(mut self, ticks_on: TicksPosition) -> Axis {
-        self.ticks_on = Some(ticks_on);
-        self
-    }
+/// An x axis of the `Layout`, the axis a trace draws its x coordinates on. Serializes as "x" for
+/// index 1 and as "x<index>" otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct XAxisId(NonZeroUsize);
 
-    pub fn mirror(mut self, mirror: bool) -> Axis {
-        self.mirror = Some(mirror);
-        self
+impl XAxisId {
+    /// The x axis with the given index. Panics if `index` is 0.
+    pub fn new(index: usize) -> XAxisId {
+        XAxisId(axis_index(index))
     }
 
-    pub fn tick_length(mut self, tick_length: usize) -> Axis {
-        self.tick_length = Some(tick_length);
-        self
+    pub fn index(&self) -> usize {
+        self.0.get()
     }
+}
 
-    pub fn tick_width(mut self, tick_width: usize) -> Axis {
-        self.tick_width = Some(tick_width);
-        self
+impl From<XAxisId> for AxisId {
+    fn from(id: XAxisId) -> Self {
+        AxisId::X(id.0)
     }
+}
 
-    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Axis {
-        self.tick_color = Some(tick_color.to_color());
-        self
+impl Serialize for XAxisId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        AxisId::from(*self).serialize(serializer)
     }
+}
 
-    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Axis {
-        self.show_tick_labels = Some(show_tick_labels);
-        self
-    }
+/// A y axis of the `Layout`, the axis a trace draws its y coordinates on. Serializes as "y" for
+/// index 1 and as "y<index>" otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct YAxisId(NonZeroUsize);
 
-    pub fn auto_margin(mut self, auto_margin: bool) -> Axis {
-        self.auto_margin = Some(auto_margin);
-        self
+impl YAxisId {
+    /// The y axis with the given index. Panics if `index` is 0.
+    pub fn new(index: usize) -> YAxisId {
+        YAxisId(axis_index(index))
     }
 
-    pub fn show_spikes(mut self, show_spikes: bool) -> Axis {
-        self.show_spikes = Some(show_spikes);
-        self
+    pub fn index(&self) -> usize {
+        self.0.get()
     }
+}
 
-    pub fn spike_color<C: Color>(mut self, spike_color: C) -> Axis {
-        self.spike_color = Some(spike_color.to_color());
-        self
+impl From<YAxisId> for AxisId {
+    fn from(id: YAxisId) -> Self {
+        AxisId::Y(id.0)
     }
+}
 
-    pub fn spike_thickness(mut self, spike_thickness: usize) -> Axis {
-        self.spike_thickness = Some(spike_thickness);
-        self
+impl Serialize for YAxisId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        AxisId::from(*self).serialize(serializer)
     }
+}
 
-    pub fn spike_dash(mut self, spike_dash: DashType) -> Axis {
-        self.spike_dash = Some(spike_dash);
-        self
-    }
+/// The axis an `Axis` is anchored to or overlays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AxisRef {
+    X(NonZeroUsize),
+    Y(NonZeroUsize),
+    /// Not anchored to, or overlaying, any axis. A free axis is placed with `Axis::position`.
+    Free,
+}
 
-    pub fn spike_mode(mut self, spike_mode: &str) -> Axis {
-        self.spike_mode = Some(spike_mode.to_owned());
-        self
+impl AxisRef {
+    /// The x axis with the given index. Panics if `index` is 0.
+    pub fn x(index: usize) -> AxisRef {
+        AxisRef::X(axis_index(index))
     }
 
-    pub fn spike_snap(mut self, spike_snap: &str) -> Axis {
-        self.spike_snap = Some(spike_snap.to_owned());
-        self
+    /// The y axis with the given index. Panics if `index` is 0.
+    pub fn y(index: usize) -> AxisRef {
+        AxisRef::Y(axis_index(index))
     }
+}
 
-    pub fn tick_font(mut self, tick_font: Font) -> Axis {
-        self.tick_font = Some(tick_font);
-        self
+impl From<AxisId> for AxisRef {
+    fn from(id: AxisId) -> Self {
+        match id {
+            AxisId::X(index) => AxisRef::X(index),
+            AxisId::Y(index) => AxisRef::Y(index),
+        }
     }
+}
 
-    pub fn tick_angle(mut self, tick_angle: f64) -> Axis {
-        self.tick_angle = Some(tick_angle);
-        self
+impl From<XAxisId> for AxisRef {
+    fn from(id: XAxisId) -> Self {
+        AxisRef::X(id.0)
     }
+}
 
-    pub fn tick_prefix(mut self, tick_prefix: &str) -> Axis {
-        self.tick_prefix = Some(tick_prefix.to_owned());
-        self
+impl From<YAxisId> for AxisRef {
+    fn from(id: YAxisId) -> Self {
+        AxisRef::Y(id.0)
     }
+}
 
-    pub fn show_tick_prefix(mut self, show_tick_prefix: ArrayShow) -> Axis {
-        self.show_tick_prefix = Some(show_tick_prefix);
-        self
+impl Serialize for AxisRef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            AxisRef::X(index) => AxisId::X(index).serialize(serializer),
+            AxisRef::Y(index) => AxisId::Y(index).serialize(serializer),
+            AxisRef::Free => serializer.serialize_str("free"),
+        }
     }
+}
 
-    pub fn tick_suffix(mut self, tick_suffix: &str) -> Axis {
-        self.tick_suffix = Some(tick_suffix.to_owned());
-        self
-    }
+/// The coordinate system of an x position of a `Shape` or an `Annotation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XRef {
+    /// The data coordinates of an x axis, "x" for index 1 and "x<index>" otherwise.
+    X(NonZeroUsize),
+    /// Normalized coordinates of the plotting area, 0 being the left side and 1 the right side.
+    Paper,
+}
 
-    pub fn show_tick_suffix(mut self, show_tick_suffix: ArrayShow) -> Axis {
-        self.show_tick_suffix = Some(show_tick_suffix);
-        self
+impl XRef {
+    /// The data coordinates of the x axis with the given index. Panics if `index` is 0.
+    pub fn x(index: usize) -> XRef {
+        XRef::X(axis_index(index))
     }
+}
 
-    pub fn show_exponent(mut self, show_exponent: ArrayShow) -> Axis {
-        self.show_exponent = Some(show_exponent);
-        self
-    }
-
-    pub fn exponent_format(mut self, exponent_format: &str) -> Axis {
-        self.exponent_format = Some(exponent_format.to_owned());
-        self
-    }
-
-    pub fn separate_thousands(mut self, separate_thousands: bool) -> Axis {
-        self.separate_thousands = Some(separate_thousands);
-        self
-    }
-
-    pub fn tick_format(mut self, tick_format: &str) -> Axis {
-        self.tick_format = Some(tick_format.to_owned());
-        self
-    }
-
-    pub fn tick_format_stops(mut self, tick_format_stops: Vec<TickFormatStop>) -> Axis {
-        self.tick_format_stops = Some(tick_format_stops);
-        self
-    }
-
-    pub fn hover_format(mut self, hover_format: &str) -> Axis {
-        self.hover_format = Some(hover_format.to_owned());
-        self
-    }
-
-    pub fn show_line(mut self, show_line: bool) -> Axis {
-        self.show_line = Some(show_line);
-        self
-    }
-
-    pub fn line_color<C: Color>(mut self, line_color: C) -> Axis {
-        self.line_color = Some(line_color.to_color());
-        self
-    }
-
-    pub fn line_width(mut self, line_width: usize) -> Axis {
-        self.line_width = Some(line_width);
-        self
-    }
-
-    pub fn show_grid(mut self, show_grid: bool) -> Axis {
-        self.show_grid = Some(show_grid);
-        self
-    }
-
-    pub fn grid_color<C: Color>(mut self, grid_color: C) -> Axis {
-        self.grid_color = Some(grid_color.to_color());
-        self
-    }
-
-    pub fn grid_width(mut self, grid_width: usize) -> Axis {
-        self.grid_width = Some(grid_width);
-        self
-    }
-
-    pub fn zero_line(mut self, zero_line: bool) -> Axis {
-        self.zero_line = Some(zero_line);
-        self
-    }
-
-    pub fn zero_line_color<C: Color>(mut self, zero_line_color: C) -> Axis {
-        self.zero_line_color = Some(zero_line_color.to_color());
-        self
-    }
-
-    pub fn zero_line_width(mut self, zero_line_width: usize) -> Axis {
-        self.zero_line_width = Some(zero_line_width);
-        self
-    }
-
-    pub fn show_dividers(mut self, show_dividers: bool) -> Axis {
-        self.show_dividers = Some(show_dividers);
-        self
+impl Serialize for XRef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            XRef::X(index) => AxisId::X(index).serialize(serializer),
+            XRef::Paper => serializer.serialize_str("paper"),
+        }
     }
+}
 
-    pub fn divider_color<C: Color>(mut self, divider_color: C) -> Axis {
-        self.divider_color = Some(divider_color.to_color());
-        self
-    }
+/// The coordinate system of a y position of a `Shape` or an `Annotation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YRef {
+    /// The data coordinates of a y axis, "y" for index 1 and "y<index>" otherwise.
+    Y(NonZeroUsize),
+    /// Normalized coordinates of the plotting area, 0 being the bottom side and 1 the top side.
+    Paper,
+}
 
-    pub fn divider_width(mut self, divider_width: usize) -> Axis {
-        self.divider_width = Some(divider_width);
-        self
+impl YRef {
+    /// The data coordinates of the y axis with the given index. Panics if `index` is 0.
+    pub fn y(index: usize) -> YRef {
+        YRef::Y(axis_index(index))
     }
+}
 
-    pub fn anchor(mut self, anchor: &str) -> Axis {
-        self.anchor = Some(anchor.to_owned());
-        self
+impl Serialize for YRef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            YRef::Y(index) => AxisId::Y(index).serialize(serializer),
+            YRef::Paper => serializer.serialize_str("paper"),
+        }
     }
+}
 
-    pub fn side(mut self, side: Side) -> Axis {
-        self.side = Some(side);
-        self
-    }
+/// The coordinate system of the tail of an `Annotation` arrow, `ax` or `ay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TailRef {
+    /// The data coordinates of an axis, an x axis for `ax_ref` and a y axis for `ay_ref`.
+    Axis(NonZeroUsize),
+    /// An offset in pixels from the head of the arrow.
+    Pixel,
+}
 
-    pub fn overlaying(mut self, overlaying: &str) -> Axis {
-        self.overlaying = Some(overlaying.to_owned());
-        self
+impl TailRef {
+    /// The data coordinates of the axis with the given index. Panics if `index` is 0.
+    pub fn axis(index: usize) -> TailRef {
+        TailRef::Axis(axis_index(index))
     }
+}
 
-    pub fn domain(mut self, domain: &[f64]) -> Axis {
-        self.domain = Some(domain.to_vec());
-        self
+fn serialize_tail_ref<S>(
+    tail_ref: &Option<TailRef>,
+    axis: fn(NonZeroUsize) -> AxisId,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match *tail_ref {
+        Some(TailRef::Axis(index)) => axis(index).serialize(serializer),
+        Some(TailRef::Pixel) => serializer.serialize_str("pixel"),
+        None => serializer.serialize_none(),
     }
+}
 
-    pub fn position(mut self, position: f64) -> Axis {
-        self.position = Some(position);
-        self
-    }
+fn serialize_x_tail_ref<S>(tail_ref: &Option<TailRef>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_tail_ref(tail_ref, AxisId::X, serializer)
+}
 
-    pub fn range_slider(mut self, slider: RangeSlider) -> Axis {
-        self.range_slider = Some(slider);
-        self
-    }
+fn serialize_y_tail_ref<S>(tail_ref: &Option<TailRef>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_tail_ref(tail_ref, AxisId::Y, serializer)
+}
 
-    pub fn range_selector(mut self, range_selector: RangeSelector) -> Axis {
-        self.range_selector = Some(range_selector);
-        self
-    }
+#[derive(Debug, Default)]
+struct Axes(BTreeMap<AxisId, Axis>);
 
-    pub fn calendar(mut self, calendar: Calendar) -> Axis {
-        self.calendar = Some(calendar);
-        self
+impl Serialize for Axes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.0.iter().map(|(id, axis)| (id.layout_name(), axis)))
     }
 }
 
@@ -1191,7 +947,7 @@ pub enum GridYSide {
     Right,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct GridDomain {
     #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<Vec<f64>>,
@@ -1203,19 +959,9 @@ impl GridDomain {
     pub fn new() -> GridDomain {
         Default::default()
     }
-
-    pub fn x(mut self, x: Vec<f64>) -> GridDomain {
-        self.x = Some(x);
-        self
-    }
-
-    pub fn y(mut self, y: Vec<f64>) -> GridDomain {
-        self.y = Some(y);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct LayoutGrid {
     #[serde(skip_serializing_if = "Option::is_none")]
     rows: Option<usize>,
@@ -1247,65 +993,6 @@ impl LayoutGrid {
     pub fn new() -> LayoutGrid {
         Default::default()
     }
-
-    pub fn rows(mut self, rows: usize) -> LayoutGrid {
-        self.rows = Some(rows);
-        self
-    }
-
-    pub fn row_order(mut self, row_order: RowOrder) -> LayoutGrid {
-        self.row_order = Some(row_order);
-        self
-    }
-
-    pub fn columns(mut self, columns: usize) -> LayoutGrid {
-        self.columns = Some(columns);
-        self
-    }
-
-    pub fn sub_plots(mut self, sub_plots: Vec<String>) -> LayoutGrid {
-        self.sub_plots = Some(sub_plots);
-        self
-    }
-
-    pub fn x_axes(mut self, x_axes: Vec<String>) -> LayoutGrid {
-        self.x_axes = Some(x_axes);
-        self
-    }
-
-    pub fn y_axes(mut self, y_axes: Vec<String>) -> LayoutGrid {
-        self.y_axes = Some(y_axes);
-        self
-    }
-
-    pub fn pattern(mut self, pattern: GridPattern) -> LayoutGrid {
-        self.pattern = Some(pattern);
-        self
-    }
-
-    pub fn x_gap(mut self, x_gap: f64) -> LayoutGrid {
-        self.x_gap = Some(x_gap);
-        self
-    }
-
-    pub fn y_gap(mut self, y_gap: f64) -> LayoutGrid {
-        self.y_gap = Some(y_gap);
-        self
-    }
-
-    pub fn domain(mut self, domain: GridDomain) -> LayoutGrid {
-        self.domain = Some(domain);
-        self
-    }
-
-    pub fn x_side(mut self, x_side: GridXSide) -> LayoutGrid {
-        self.x_side = Some(x_side);
-        self
-    }
-    pub fn y_side(mut self, y_side: GridYSide) -> LayoutGrid {
-        self.y_side = Some(y_side);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -1318,11 +1005,11 @@ pub enum UniformTextMode {
     Show,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct UniformText {
     #[serde(skip_serializing_if = "Option::is_none")]
     mode: Option<TruthyEnum<UniformTextMode>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "minsize")]
     min_size: Option<usize>,
 }
 
@@ -1330,16 +1017,6 @@ impl UniformText {
     pub fn new() -> UniformText {
         Default::default()
     }
-
-    pub fn mode(mut self, mode: UniformTextMode) -> UniformText {
-        self.mode = Some(TruthyEnum { e: mode });
-        self
-    }
-
-    pub fn min_size(mut self, min_size: usize) -> UniformText {
-        self.min_size = Some(min_size);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -1358,7 +1035,73 @@ pub enum HoverMode {
     YUnified,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug)]
+pub enum DragMode {
+    #[serde(rename = "zoom")]
+    Zoom,
+    #[serde(rename = "pan")]
+    Pan,
+    #[serde(rename = "select")]
+    Select,
+    #[serde(rename = "lasso")]
+    Lasso,
+    #[serde(rename = "drawclosedpath")]
+    DrawClosedPath,
+    #[serde(rename = "drawopenpath")]
+    DrawOpenPath,
+    #[serde(rename = "drawline")]
+    DrawLine,
+    #[serde(rename = "drawrect")]
+    DrawRect,
+    #[serde(rename = "drawcircle")]
+    DrawCircle,
+    #[serde(rename = "orbit")]
+    Orbit,
+    #[serde(rename = "turntable")]
+    TurnTable,
+    #[serde(rename = "false")]
+    False,
+    /// Any other value, passed to plotly.js as is.
+    #[serde(untagged)]
+    Raw(String),
+}
+
+#[derive(Serialize, Debug)]
+pub enum SelectDirection {
+    #[serde(rename = "h")]
+    Horizontal,
+    #[serde(rename = "v")]
+    Vertical,
+    #[serde(rename = "d")]
+    Diagonal,
+    #[serde(rename = "any")]
+    Any,
+    /// Any other value, passed to plotly.js as is.
+    #[serde(untagged)]
+    Raw(String),
+}
+
+/// Flags of `Layout::click_mode`, combined with `|`, e.g. `ClickMode::EVENT | ClickMode::SELECT`.
+pub type ClickMode = Flags<ClickModeNames>;
+
+/// The flag names of `ClickMode`.
+pub enum ClickModeNames {}
+
+impl FlagNames for ClickModeNames {
+    const NAMES: &'static [&'static str] = &["event", "select"];
+    const EMPTY: &'static str = "none";
+}
+
+impl ClickMode {
+    /// Clicks are ignored.
+    pub const NONE: ClickMode = Flags::empty();
+    /// Clicks emit `plotly_click` events.
+    pub const EVENT: ClickMode = Flags::flag(0);
+    /// Clicks select data points.
+    pub const SELECT: ClickMode = Flags::flag(1);
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct ModeBar {
     #[serde(skip_serializing_if = "Option::is_none")]
     orientation: Option<Orientation>,
@@ -1374,26 +1117,6 @@ impl ModeBar {
     pub fn new() -> ModeBar {
         Default::default()
     }
-
-    pub fn orientation<C: Color>(mut self, orientation: Orientation) -> ModeBar {
-        self.orientation = Some(orientation);
-        self
-    }
-
-    pub fn background_color<C: Color>(mut self, background_color: C) -> ModeBar {
-        self.background_color = Some(background_color.to_color());
-        self
-    }
-
-    pub fn color<C: Color>(mut self, color: C) -> ModeBar {
-        self.color = Some(color.to_color());
-        self
-    }
-
-    pub fn active_color<C: Color>(mut self, active_color: C) -> ModeBar {
-        self.active_color = Some(active_color.to_color());
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -1432,12 +1155,16 @@ pub enum FillRule {
     NonZero,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct ShapeLine {
+    /// Sets the line color.
     #[serde(skip_serializing_if = "Option::is_none")]
     color: Option<ColorWrapper>,
+    /// Sets the line width (in px).
     #[serde(skip_serializing_if = "Option::is_none")]
     width: Option<f64>,
+    /// Sets the dash style of lines. Set to a dash type string ("solid", "dot", "dash", "longdash",
+    /// "dashdot", or "longdashdot") or a dash length list in px (eg "5px,10px,2px,2px").
     #[serde(skip_serializing_if = "Option::is_none")]
     dash: Option<String>,
 }
@@ -1446,69 +1173,244 @@ impl ShapeLine {
     pub fn new() -> ShapeLine {
         Default::default()
     }
+}
 
-    /// Sets the line color.
-    pub fn color<C: Color>(mut self, color: C) -> ShapeLine {
-        self.color = Some(color.to_color());
-        self
+/// The path of a `Shape` of type `ShapeType::Path`, built from absolute SVG path commands.
+/// Coordinates are numbers or, on date and category axes, strings. The space of a date-time is
+/// written as `_`, as plotly.js splits paths on whitespace; coordinates with a comma panic.
+///
+/// ```
+/// use plotly::layout::ShapePath;
+///
+/// let triangle = ShapePath::new().move_to(1, 1).line_to(1, 3).line_to(4, 1).close();
+/// assert_eq!(serde_json::to_string(&triangle).unwrap(), r#""M 1,1 L 1,3 L 4,1 Z""#);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapePath {
+    commands: Vec<String>,
+}
+
+impl ShapePath {
+    pub fn new() -> ShapePath {
+        Default::default()
     }
 
-    /// Sets the line width (in px).
-    pub fn width(mut self, width: f64) -> ShapeLine {
-        self.width = Some(width);
+    /// Any path, passed to plotly.js as is.
+    pub fn raw(path: &str) -> ShapePath {
+        ShapePath {
+            commands: vec![path.to_owned()],
+        }
+    }
+
+    /// Starts a new subpath at (`x`, `y`).
+    pub fn move_to<X: NumOrString, Y: NumOrString>(self, x: X, y: Y) -> ShapePath {
+        self.command("M", &[point(x, y)])
+    }
+
+    /// Draws a line to (`x`, `y`).
+    pub fn line_to<X: NumOrString, Y: NumOrString>(self, x: X, y: Y) -> ShapePath {
+        self.command("L", &[point(x, y)])
+    }
+
+    /// Draws a horizontal line to `x`.
+    pub fn horizontal_to<X: NumOrString>(self, x: X) -> ShapePath {
+        self.command("H", &[coordinate(x)])
+    }
+
+    /// Draws a vertical line to `y`.
+    pub fn vertical_to<Y: NumOrString>(self, y: Y) -> ShapePath {
+        self.command("V", &[coordinate(y)])
+    }
+
+    /// Draws a quadratic Bézier curve to (`x`, `y`) with the control point (`cx`, `cy`).
+    pub fn quadratic_to<X: NumOrString, Y: NumOrString>(
+        self,
+        cx: X,
+        cy: Y,
+        x: X,
+        y: Y,
+    ) -> ShapePath {
+        self.command("Q", &[point(cx, cy), point(x, y)])
+    }
+
+    /// Draws a quadratic Bézier curve to (`x`, `y`), reflecting the control point of the previous
+    /// curve.
+    pub fn smooth_quadratic_to<X: NumOrString, Y: NumOrString>(self, x: X, y: Y) -> ShapePath {
+        self.command("T", &[point(x, y)])
+    }
+
+    /// Draws a cubic Bézier curve to (`x`, `y`) with the control points (`c1x`, `c1y`) and
+    /// (`c2x`, `c2y`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn cubic_to<X: NumOrString, Y: NumOrString>(
+        self,
+        c1x: X,
+        c1y: Y,
+        c2x: X,
+        c2y: Y,
+        x: X,
+        y: Y,
+    ) -> ShapePath {
+        self.command("C", &[point(c1x, c1y), point(c2x, c2y), point(x, y)])
+    }
+
+    /// Draws a cubic Bézier curve to (`x`, `y`) with the second control point (`c2x`, `c2y`),
+    /// reflecting the second control point of the previous curve.
+    pub fn smooth_cubic_to<X: NumOrString, Y: NumOrString>(
+        self,
+        c2x: X,
+        c2y: Y,
+        x: X,
+        y: Y,
+    ) -> ShapePath {
+        self.command("S", &[point(c2x, c2y), point(x, y)])
+    }
+
+    /// Closes the current subpath with a line to its start.
+    pub fn close(mut self) -> ShapePath {
+        self.commands.push("Z".to_owned());
         self
     }
 
-    /// Sets the dash style of lines. Set to a dash type string ("solid", "dot", "dash", "longdash",
-    /// "dashdot", or "longdashdot") or a dash length list in px (eg "5px,10px,2px,2px").
-    pub fn dash(mut self, dash: &str) -> ShapeLine {
-        self.dash = Some(dash.to_owned());
+    fn command(mut self, command: &str, points: &[String]) -> ShapePath {
+        self.commands
+            .push(format!("{} {}", command, points.join(" ")));
         self
     }
 }
 
-#[derive(Serialize, Debug, Default)]
+fn coordinate<V: NumOrString>(value: V) -> String {
+    match serde_json::to_value(value.to_num_or_string()).unwrap() {
+        serde_json::Value::String(s) => {
+            assert!(!s.contains(','), "path coordinate {:?} contains a comma", s);
+            s.replace(' ', "_")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn point<X: NumOrString, Y: NumOrString>(x: X, y: Y) -> String {
+    format!("{},{}", coordinate(x), coordinate(y))
+}
+
+impl Serialize for ShapePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.commands.join(" "))
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Shape {
+    /// Determines whether or not this shape is visible.
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
     r#type: Option<ShapeType>,
+    /// Specifies whether shapes are drawn below or above traces.
     #[serde(skip_serializing_if = "Option::is_none")]
     layer: Option<ShapeLayer>,
+    /// Sets the shape's x coordinate axis. If set to an x axis (e.g. `XRef::x(1)` or `XRef::x(2)`),
+    /// the `x` position refers to an x coordinate. If set to `XRef::Paper`, the `x` position refers
+    /// to the distance from the left side of the plotting area in normalized coordinates where "0"
+    /// ("1") corresponds to the left (right) side. If the axis `type` is "log", then you must take
+    /// the log of your desired range. If the axis `type` is "date", then you must convert the date
+    /// to unix time in milliseconds.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
-    x_ref: Option<String>,
+    x_ref: Option<XRef>,
+    /// Sets the shapes's sizing mode along the x axis. If set to "scaled", `x0`, `x1` and x
+    /// coordinates within `path` refer to data values on the x axis or a fraction of the plot
+    /// area's width (`xref` set to "paper"). If set to "pixel", `xanchor` specifies the x position
+    /// in terms of data or plot fraction but `x0`, `x1` and x coordinates within `path` are pixels
+    /// relative to `xanchor`. This way, the shape can have a fixed width while maintaining a
+    /// position relative to data or plot fraction.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xsizemode")]
     x_size_mode: Option<ShapeSizeMode>,
+    /// Only relevant in conjunction with `xsizemode` set to "pixel". Specifies the anchor point on
+    /// the x axis to which `x0`, `x1` and x coordinates within `path` are relative to. E.g. useful
+    /// to attach a pixel sized shape to a certain data value. No effect when `xsizemode` not set
+    /// to "pixel".
     #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
     x_anchor: Option<NumOrStringWrapper>,
+    /// Sets the shape's starting x position. See `type` and `xsizemode` for more info.
     #[serde(skip_serializing_if = "Option::is_none")]
     x0: Option<NumOrStringWrapper>,
+    /// Sets the shape's end x position. See `type` and `xsizemode` for more info.
     #[serde(skip_serializing_if = "Option::is_none")]
     x1: Option<NumOrStringWrapper>,
+    /// Sets the shape's y coordinate axis. If set to a y axis (e.g. `YRef::y(1)` or `YRef::y(2)`),
+    /// the `y` position refers to a y coordinate. If set to `YRef::Paper`, the `y` position refers
+    /// to the distance from the bottom of the plotting area in normalized coordinates where "0"
+    /// ("1") corresponds to the bottom (top).
     #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
-    y_ref: Option<String>,
+    y_ref: Option<YRef>,
+    /// Sets the shapes's sizing mode along the y axis. If set to "scaled", `y0`, `y1` and y
+    /// coordinates within `path` refer to data values on the y axis or a fraction of the plot
+    /// area's height (`yref` set to "paper"). If set to "pixel", `yanchor` specifies the y position
+    /// in terms of data or plot fraction but `y0`, `y1` and y coordinates within `path` are pixels
+    /// relative to `yanchor`. This way, the shape can have a fixed height while maintaining a
+    /// position relative to data or plot fraction.
     #[serde(skip_serializing_if = "Option::is_none", rename = "ysizemode")]
     y_size_mode: Option<ShapeSizeMode>,
+    /// Only relevant in conjunction with `ysizemode` set to "pixel". Specifies the anchor point on
+    /// the y axis to which `y0`, `y1` and y coordinates within `path` are relative to. E.g. useful
+    /// to attach a pixel sized shape to a certain data value. No effect when `ysizemode` not set
+    /// to "pixel".
     #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
     y_anchor: Option<NumOrStringWrapper>,
+    /// Sets the shape's starting y position. See `type` and `ysizemode` for more info.
     #[serde(skip_serializing_if = "Option::is_none")]
     y0: Option<NumOrStringWrapper>,
+    /// Sets the shape's end y position. See `type` and `ysizemode` for more info.
     #[serde(skip_serializing_if = "Option::is_none")]
     y1: Option<NumOrStringWrapper>,
+    /// For `type` "path" - a valid SVG path with the pixel values replaced by data values in
+    /// `xsizemode`/`ysizemode` being "scaled" and taken unmodified as pixels relative to
+    /// `xanchor` and `yanchor` in case of "pixel" size mode. There are a few restrictions / quirks
+    /// only absolute instructions, not relative. So the allowed segments
+    /// are: M, L, H, V, Q, C, T, S, and Z arcs (A) are not allowed because radius rx and ry are
+    /// relative. In the future we could consider supporting relative commands, but we would have
+    /// to decide on how to handle date and log axes. Note that even as is, Q and C Bezier paths
+    /// that are smooth on linear axes may not be smooth on log, and vice versa. no chained
+    /// "polybezier" commands - specify the segment type for each one. On category axes, values are
+    /// numbers scaled to the serial numbers of categories because using the categories themselves
+    /// there would be no way to describe fractional positions On data axes: because space and T are
+    /// both normal components of path strings, we can't use either to separate date from time parts.
+    /// Therefore we'll use underscore for this purpose: 2015-02-21_13:45:56.789
     #[serde(skip_serializing_if = "Option::is_none")]
-    path: Option<String>,
+    path: Option<ShapePath>,
+    /// Sets the opacity of the shape. Number between or equal to 0 and 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
+    /// Sets the shape line properties (`color`, `width`, `dash`).
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<ShapeLine>,
+    /// Sets the color filling the shape's interior. Only applies to closed shapes.
     #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
     fill_color: Option<ColorWrapper>,
+    /// Determines which regions of complex paths constitute the interior. For more info please
+    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
     #[serde(skip_serializing_if = "Option::is_none", rename = "fillrule")]
     fill_rule: Option<FillRule>,
+    /// Determines whether the shape could be activated for edit or not. Has no effect when the
+    /// older editable shapes mode is enabled via `config.editable` or `config.edits.shapePosition`.
     #[serde(skip_serializing_if = "Option::is_none")]
     editable: Option<bool>,
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output figure
+    /// by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
     #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
     template_item_name: Option<String>,
 }
@@ -1518,12 +1420,6 @@ impl Shape {
         Default::default()
     }
 
-    /// Determines whether or not this shape is visible.
-    pub fn visible(mut self, visible: bool) -> Shape {
-        self.visible = Some(visible);
-        self
-    }
-
     /// Specifies the shape type to be drawn. If "line", a line is drawn from (`x0`,`y0`) to
     /// (`x1`,`y1`) with respect to the axes' sizing mode. If "circle", a circle is drawn from
     /// ((`x0`+`x1`)/2, (`y0`+`y1`)/2)) with radius (|(`x0`+`x1`)/2 - `x0`|, |(`y0`+`y1`)/2 -`y0`)|)
@@ -1535,167 +1431,7 @@ impl Shape {
         self.r#type = Some(shape_type);
         self
     }
-
-    /// Specifies whether shapes are drawn below or above traces.
-    pub fn layer(mut self, layer: ShapeLayer) -> Shape {
-        self.layer = Some(layer);
-        self
-    }
-
-    /// Sets the shape's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
-    /// position refers to an x coordinate. If set to "paper", the `x` position refers to the
-    /// distance from the left side of the plotting area in normalized coordinates where "0" ("1")
-    /// corresponds to the left (right) side. If the axis `type` is "log", then you must take the
-    /// log of your desired range. If the axis `type` is "date", then you must convert the date to
-    /// unix time in milliseconds.
-    pub fn x_ref(mut self, x_ref: &str) -> Shape {
-        self.x_ref = Some(x_ref.to_owned());
-        self
-    }
-
-    /// Sets the shapes's sizing mode along the x axis. If set to "scaled", `x0`, `x1` and x
-    /// coordinates within `path` refer to data values on the x axis or a fraction of the plot
-    /// area's width (`xref` set to "paper"). If set to "pixel", `xanchor` specifies the x position
-    /// in terms of data or plot fraction but `x0`, `x1` and x coordinates within `path` are pixels
-    /// relative to `xanchor`. This way, the shape can have a fixed width while maintaining a
-    /// position relative to data or plot fraction.
-    pub fn x_size_mode(mut self, x_size_mode: ShapeSizeMode) -> Shape {
-        self.x_size_mode = Some(x_size_mode);
-        self
-    }
-
-    /// Only relevant in conjunction with `xsizemode` set to "pixel". Specifies the anchor point on
-    /// the x axis to which `x0`, `x1` and x coordinates within `path` are relative to. E.g. useful
-    /// to attach a pixel sized shape to a certain data value. No effect when `xsizemode` not set
-    /// to "pixel".
-    pub fn x_anchor<C: NumOrString>(mut self, x_anchor: C) -> Shape {
-        self.x_anchor = Some(x_anchor.to_num_or_string());
-        self
-    }
-
-    /// Sets the shape's starting x position. See `type` and `xsizemode` for more info.
-    pub fn x0<C: NumOrString>(mut self, x0: C) -> Shape {
-        self.x0 = Some(x0.to_num_or_string());
-        self
-    }
-
-    /// Sets the shape's end x position. See `type` and `xsizemode` for more info.
-    pub fn x1<C: NumOrString>(mut self, x1: C) -> Shape {
-        self.x1 = Some(x1.to_num_or_string());
-        self
-    }
-
-    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"),
-    /// the `y` position refers to an y coordinate If set to "paper", the `y` position refers to
-    /// the distance from the bottom of the plotting area in normalized coordinates where "0" ("1")
-    /// corresponds to the bottom (top).
-    pub fn y_ref(mut self, y_ref: &str) -> Shape {
-        self.y_ref = Some(y_ref.to_owned());
-        self
-    }
-
-    /// Sets the shapes's sizing mode along the y axis. If set to "scaled", `y0`, `y1` and y
-    /// coordinates within `path` refer to data values on the y axis or a fraction of the plot
-    /// area's height (`yref` set to "paper"). If set to "pixel", `yanchor` specifies the y position
-    /// in terms of data or plot fraction but `y0`, `y1` and y coordinates within `path` are pixels
-    /// relative to `yanchor`. This way, the shape can have a fixed height while maintaining a
-    /// position relative to data or plot fraction.
-    pub fn y_size_mode(mut self, y_size_mode: ShapeSizeMode) -> Shape {
-        self.y_size_mode = Some(y_size_mode);
-        self
-    }
-
-    /// Only relevant in conjunction with `ysizemode` set to "pixel". Specifies the anchor point on
-    /// the y axis to which `y0`, `y1` and y coordinates within `path` are relative to. E.g. useful
-    /// to attach a pixel sized shape to a certain data value. No effect when `ysizemode` not set
-    /// to "pixel".
-    pub fn y_anchor<C: NumOrString>(mut self, y_anchor: C) -> Shape {
-        self.y_anchor = Some(y_anchor.to_num_or_string());
-        self
-    }
-
-    /// Sets the shape's starting y position. See `type` and `ysizemode` for more info.
-    pub fn y0<C: NumOrString>(mut self, y0: C) -> Shape {
-        self.y0 = Some(y0.to_num_or_string());
-        self
-    }
-
-    /// Sets the shape's end y position. See `type` and `ysizemode` for more info.
-    pub fn y1<C: NumOrString>(mut self, y1: C) -> Shape {
-        self.y1 = Some(y1.to_num_or_string());
-        self
-    }
-
-    /// For `type` "path" - a valid SVG path with the pixel values replaced by data values in
-    /// `xsizemode`/`ysizemode` being "scaled" and taken unmodified as pixels relative to
-    /// `xanchor` and `yanchor` in case of "pixel" size mode. There are a few restrictions / quirks
-    /// only absolute instructions, not relative. So the allowed segments
-    /// are: M, L, H, V, Q, C, T, S, and Z arcs (A) are not allowed because radius rx and ry are
-    /// relative. In the future we could consider supporting relative commands, but we would have
-    /// to decide on how to handle date and log axes. Note that even as is, Q and C Bezier paths
-    /// that are smooth on linear axes may not be smooth on log, and vice versa. no chained
-    /// "polybezier" commands - specify the segment type for each one. On category axes, values are
-    /// numbers scaled to the serial numbers of categories because using the categories themselves
-    /// there would be no way to describe fractional positions On data axes: because space and T are
-    /// both normal components of path strings, we can't use either to separate date from time parts.
-    /// Therefore we'll use underscore for this purpose: 2015-02-21_13:45:56.789
-    pub fn path(mut self, path: &str) -> Shape {
-        self.path = Some(path.to_owned());
-        self
-    }
-
-    /// Sets the opacity of the shape. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> Shape {
-        self.opacity = Some(opacity);
-        self
-    }
-
-    /// Sets the shape line properties (`color`, `width`, `dash`).
-    pub fn line(mut self, line: ShapeLine) -> Shape {
-        self.line = Some(line);
-        self
-    }
-
-    /// Sets the color filling the shape's interior. Only applies to closed shapes.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Shape {
-        self.fill_color = Some(fill_color.to_color());
-        self
-    }
-
-    /// Determines which regions of complex paths constitute the interior. For more info please
-    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
-    pub fn fill_rule(mut self, fill_rule: FillRule) -> Shape {
-        self.fill_rule = Some(fill_rule);
-        self
-    }
-
-    /// Determines whether the shape could be activated for edit or not. Has no effect when the
-    /// older editable shapes mode is enabled via `config.editable` or `config.edits.shapePosition`.
-    pub fn editable(mut self, editable: bool) -> Shape {
-        self.editable = Some(editable);
-        self
-    }
-
-    /// When used in a template, named items are created in the output figure in addition to any
-    /// items the figure already has in this array. You can modify these items in the output figure
-    /// by making your own item with `templateitemname` matching this `name` alongside your
-    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
-    /// outside of a template.
-    pub fn name(mut self, name: &str) -> Shape {
-        self.name = Some(name.to_owned());
-        self
-    }
-
-    /// Used to refer to a named item in this array in the template. Named items from the template
-    /// will be created even without a matching item in the input figure, but you can modify one
-    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
-    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
-    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
-    pub fn template_item_name(mut self, template_item_name: &str) -> Shape {
-        self.template_item_name = Some(template_item_name.to_owned());
-        self
-    }
-}
+}
 
 #[derive(Serialize, Debug)]
 pub enum DrawDirection {
@@ -1709,18 +1445,30 @@ pub enum DrawDirection {
     Diagonal,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct NewShape {
+    /// Sets the shape line properties (`color`, `width`, `dash`).
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<ShapeLine>,
+    /// Sets the color filling new shapes' interior. Please note that if using a fillcolor with
+    /// alpha greater than half, drag inside the active shape starts moving the shape underneath,
+    /// otherwise a new shape could be started over.
     #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
     fill_color: Option<ColorWrapper>,
+    /// Determines the path's interior. For more info please
+    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
     #[serde(skip_serializing_if = "Option::is_none", rename = "fillrule")]
     fill_rule: Option<FillRule>,
+    /// Sets the opacity of new shapes. Number between or equal to 0 and 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
+    /// Specifies whether new shapes are drawn below or above traces.
     #[serde(skip_serializing_if = "Option::is_none")]
     layer: Option<ShapeLayer>,
+    /// When `dragmode` is set to "drawrect", "drawline" or "drawcircle" this limits the drag to be
+    /// horizontal, vertical or diagonal. Using "diagonal" there is no limit e.g. in drawing lines
+    /// in any direction. "ortho" limits the draw to be either horizontal or vertical. "horizontal"
+    /// allows horizontal extend. "vertical" allows vertical extend.
     #[serde(skip_serializing_if = "Option::is_none", rename = "drawdirection")]
     draw_direction: Option<DrawDirection>,
 }
@@ -1729,54 +1477,14 @@ impl NewShape {
     pub fn new() -> NewShape {
         Default::default()
     }
-
-    /// Sets the shape line properties (`color`, `width`, `dash`).
-    pub fn line(mut self, line: ShapeLine) -> NewShape {
-        self.line = Some(line);
-        self
-    }
-
-    /// Sets the color filling new shapes' interior. Please note that if using a fillcolor with
-    /// alpha greater than half, drag inside the active shape starts moving the shape underneath,
-    /// otherwise a new shape could be started over.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> NewShape {
-        self.fill_color = Some(fill_color.to_color());
-        self
-    }
-
-    /// Determines the path's interior. For more info please
-    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
-    pub fn fill_rule(mut self, fill_rule: FillRule) -> NewShape {
-        self.fill_rule = Some(fill_rule);
-        self
-    }
-
-    /// Sets the opacity of new shapes. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> NewShape {
-        self.opacity = Some(opacity);
-        self
-    }
-
-    /// Specifies whether new shapes are drawn below or above traces.
-    pub fn layer(mut self, layer: ShapeLayer) -> NewShape {
-        self.layer = Some(layer);
-        self
-    }
-
-    /// When `dragmode` is set to "drawrect", "drawline" or "drawcircle" this limits the drag to be
-    /// horizontal, vertical or diagonal. Using "diagonal" there is no limit e.g. in drawing lines
-    /// in any direction. "ortho" limits the draw to be either horizontal or vertical. "horizontal"
-    /// allows horizontal extend. "vertical" allows vertical extend.
-    pub fn draw_direction(mut self, draw_direction: DrawDirection) -> NewShape {
-        self.draw_direction = Some(draw_direction);
-        self
-    }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct ActiveShape {
+    /// Sets the color filling the active shape' interior.
     #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
     fill_color: Option<ColorWrapper>,
+    /// Sets the opacity of the active shape. Number between or equal to 0 and 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
 }
@@ -1785,18 +1493,6 @@ impl ActiveShape {
     pub fn new() -> ActiveShape {
         Default::default()
     }
-
-    /// Sets the color filling the active shape' interior.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> ActiveShape {
-        self.fill_color = Some(fill_color.to_color());
-        self
-    }
-
-    /// Sets the opacity of the active shape. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> ActiveShape {
-        self.opacity = Some(opacity);
-        self
-    }
 }
 
 #[derive(Serialize, Debug)]
@@ -1821,94 +1517,215 @@ pub enum ClickToShow {
     OnOut,
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Annotation {
+    /// Determines whether or not this annotation is visible.
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
+    /// Sets the text associated with this annotation. Plotly uses a subset of HTML tags to do
+    /// things like newline (<br>), bold (<b></b>), italics (<i></i>), hyperlinks
+    /// (<a href='...'></a>). Tags <em>, <sup>, <sub> <span> are also supported.
     #[serde(skip_serializing_if = "Option::is_none")]
     text: Option<String>,
+    /// Sets the angle at which the `text` is drawn with respect to the horizontal.
     #[serde(skip_serializing_if = "Option::is_none", rename = "textangle")]
     text_angle: Option<f64>,
+    /// Sets the annotation text font.
     #[serde(skip_serializing_if = "Option::is_none")]
     font: Option<Font>,
+    /// Sets an explicit width for the text box. null (default) lets the text set the box width.
+    /// Wider text will be clipped. There is no automatic wrapping; use <br> to start a new line.
     #[serde(skip_serializing_if = "Option::is_none")]
     width: Option<f64>,
+    /// Sets an explicit height for the text box. null (default) lets the text set the box height.
+    /// Taller text will be clipped.
     #[serde(skip_serializing_if = "Option::is_none")]
     height: Option<f64>,
+    /// Sets the opacity of the annotation (text + arrow).
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
+    /// Sets the horizontal alignment of the `text` within the box. Has an effect only if `text`
+    /// spans two or more lines (i.e. `text` contains one or more <br> HTML tags) or if an explicit
+    /// width is set to override the text width.
     #[serde(skip_serializing_if = "Option::is_none")]
     align: Option<HAlign>,
+    /// Sets the vertical alignment of the `text` within the box. Has an effect only if an explicit
+    /// height is set to override the text height.
     #[serde(skip_serializing_if = "Option::is_none")]
     valign: Option<VAlign>,
+    /// Sets the background color of the annotation.
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<ColorWrapper>,
+    /// Sets the color of the border enclosing the annotation `text`.
     #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
     border_color: Option<ColorWrapper>,
+    /// Sets the padding (in px) between the `text` and the enclosing border.
     #[serde(skip_serializing_if = "Option::is_none", rename = "borderpad")]
     border_pad: Option<f64>,
+    /// Sets the width (in px) of the border enclosing the annotation `text`.
     #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
     border_width: Option<f64>,
+    /// Determines whether or not the annotation is drawn with an arrow. If "True", `text` is
+    /// placed near the arrow's tail. If "False", `text` lines up with the `x` and `y` provided.
     #[serde(skip_serializing_if = "Option::is_none", rename = "showarrow")]
     show_arrow: Option<bool>,
+    /// Sets the color of the annotation arrow.
     #[serde(skip_serializing_if = "Option::is_none", rename = "arrowcolor")]
     arrow_color: Option<ColorWrapper>,
+    /// Sets the end annotation arrow head style. Integer between or equal to 0 and 8.
     #[serde(skip_serializing_if = "Option::is_none", rename = "arrowhead")]
     arrow_head: Option<u8>,
+    /// Sets the start annotation arrow head style. Integer between or equal to 0 and 8.
     #[serde(skip_serializing_if = "Option::is_none", rename = "startarrowhead")]
     start_arrow_head: Option<u8>,
+    /// Sets the annotation arrow head position.
     #[serde(skip_serializing_if = "Option::is_none", rename = "arrowside")]
     arrow_side: Option<ArrowSide>,
+    /// Sets the size of the end annotation arrow head, relative to `arrowwidth`. A value of 1
+    /// (default) gives a head about 3x as wide as the line.
     #[serde(skip_serializing_if = "Option::is_none", rename = "arrowsize")]
     arrow_size: Option<f64>,
+    /// Sets the size of the start annotation arrow head, relative to `arrowwidth`. A value of 1
+    /// (default) gives a head about 3x as wide as the line.
     #[serde(skip_serializing_if = "Option::is_none", rename = "startarrowsize")]
     start_arrow_size: Option<f64>,
+    /// Sets the width (in px) of annotation arrow line.
     #[serde(skip_serializing_if = "Option::is_none", rename = "arrowwidth")]
     arrow_width: Option<f64>,
+    /// Sets a distance, in pixels, to move the end arrowhead away from the position it is pointing
+    /// at, for example to point at the edge of a marker independent of zoom. Note that this
+    /// shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift` which
+    /// moves everything by this amount.
     #[serde(skip_serializing_if = "Option::is_none", rename = "standoff")]
     stand_off: Option<f64>,
+    /// Sets a distance, in pixels, to move the start arrowhead away from the position it is
+    /// pointing at, for example to point at the edge of a marker independent of zoom. Note that
+    /// this shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift`
+    /// which moves everything by this amount.
     #[serde(skip_serializing_if = "Option::is_none", rename = "startstandoff")]
     start_stand_off: Option<f64>,
+    /// Sets the x component of the arrow tail about the arrow head. If `axref` is `pixel`, a
+    /// positive (negative) component corresponds to an arrow pointing from right to left (left
+    /// to right). If `axref` is an axis, this is an absolute value on that axis, like `x`, NOT a
+    /// relative value.
     #[serde(skip_serializing_if = "Option::is_none")]
     ax: Option<NumOrStringWrapper>,
+    /// Sets the y component of the arrow tail about the arrow head. If `ayref` is `pixel`, a
+    /// positive (negative) component corresponds to an arrow pointing from bottom to top (top to
+    /// bottom). If `ayref` is an axis, this is an absolute value on that axis, like `y`, NOT a
+    /// relative value.
     #[serde(skip_serializing_if = "Option::is_none")]
     ay: Option<NumOrStringWrapper>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "axref")]
-    ax_ref: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "ayref")]
-    ay_ref: Option<String>,
+    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If
+    /// `TailRef::Pixel`, `ax` is a relative offset in pixels from `x`. If set to an x axis (e.g.
+    /// `TailRef::axis(2)` for "x2"), `ax` is specified in the same terms as that axis. This is
+    /// useful for trendline annotations which should continue to indicate the correct trend when
+    /// zoomed.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "axref",
+        serialize_with = "serialize_x_tail_ref"
+    )]
+    ax_ref: Option<TailRef>,
+    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If
+    /// `TailRef::Pixel`, `ay` is a relative offset in pixels from `y`. If set to a y axis (e.g.
+    /// `TailRef::axis(2)` for "y2"), `ay` is specified in the same terms as that axis. This is
+    /// useful for trendline annotations which should continue to indicate the correct trend when
+    /// zoomed.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "ayref",
+        serialize_with = "serialize_y_tail_ref"
+    )]
+    ay_ref: Option<TailRef>,
+    /// Sets the annotation's x coordinate axis. If set to an x axis (e.g. `XRef::x(1)` or
+    /// `XRef::x(2)`), the `x` position refers to an x coordinate. If set to `XRef::Paper`, the `x`
+    /// position refers to the distance from the left side of the plotting area in normalized
+    /// coordinates where 0 (1) corresponds to the left (right) side.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
-    x_ref: Option<String>,
+    x_ref: Option<XRef>,
+    /// Sets the annotation's x position. If the axis `type` is "log", then you must take the log
+    /// of your desired range. If the axis `type` is "date", it should be date strings, like date
+    /// data, though Date objects and unix milliseconds will be accepted and converted to strings.
+    /// If the axis `type` is "category", it should be numbers, using the scale where each category
+    /// is assigned a serial number from zero in the order it appears.
     #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<NumOrStringWrapper>,
+    /// Sets the text box's horizontal position anchor This anchor binds the `x` position to the
+    /// "left", "center" or "right" of the annotation. For example, if `x` is set to 1, `xref` to
+    /// "paper" and `xanchor` to "right" then the right-most portion of the annotation lines up with
+    /// the right-most edge of the plotting area. If "auto", the anchor is equivalent to "center"
+    /// for data-referenced annotations or if there is an arrow, whereas for paper-referenced with
+    /// no arrow, the anchor picked corresponds to the closest side.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
     x_anchor: Option<Anchor>,
+    /// Shifts the position of the whole annotation and arrow to the right (positive) or left
+    /// (negative) by this many pixels.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xshift")]
     x_shift: Option<f64>,
+    /// Sets the annotation's y coordinate axis. If set to a y axis (e.g. `YRef::y(1)` or
+    /// `YRef::y(2)`), the `y` position refers to a y coordinate. If set to `YRef::Paper`, the `y`
+    /// position refers to the distance from the bottom of the plotting area in normalized
+    /// coordinates where 0 (1) corresponds to the bottom (top).
     #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
-    y_ref: Option<String>,
+    y_ref: Option<YRef>,
+    /// Sets the annotation's y position. If the axis `type` is "log", then you must take the log of
+    /// your desired range. If the axis `type` is "date", it should be date strings, like date data,
+    /// though Date objects and unix milliseconds will be accepted and converted to strings. If the
+    /// axis `type` is "category", it should be numbers, using the scale where each category is
+    /// assigned a serial number from zero in the order it appears.
     #[serde(skip_serializing_if = "Option::is_none")]
     y: Option<NumOrStringWrapper>,
+    /// Sets the text box's vertical position anchor This anchor binds the `y` position to the
+    /// "top", "middle" or "bottom" of the annotation. For example, if `y` is set to 1, `yref` to
+    /// "paper" and `yanchor` to "top" then the top-most portion of the annotation lines up with the
+    /// top-most edge of the plotting area. If "auto", the anchor is equivalent to "middle" for
+    /// data-referenced annotations or if there is an arrow, whereas for paper-referenced with no
+    /// arrow, the anchor picked corresponds to the closest side.
     #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
     y_anchor: Option<Anchor>,
+    /// Shifts the position of the whole annotation and arrow up (positive) or down (negative) by
+    /// this many pixels.
     #[serde(skip_serializing_if = "Option::is_none", rename = "yshift")]
     y_shift: Option<f64>,
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none", rename = "clicktoshow")]
     click_to_show: Option<TruthyEnum<ClickToShow>>,
+    /// Toggle this annotation when clicking a data point whose `x` value is `xclick` rather than
+    /// the annotation's `x` value.
     #[serde(skip_serializing_if = "Option::is_none", rename = "xclick")]
     x_click: Option<NumOrStringWrapper>,
+    /// Toggle this annotation when clicking a data point whose `y` value is `yclick` rather than
+    /// the annotation's `y` value.
     #[serde(skip_serializing_if = "Option::is_none", rename = "yclick")]
     y_click: Option<NumOrStringWrapper>,
+    /// Sets text to appear when hovering over this annotation. If omitted or blank, no hover label
+    /// will appear.
     #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
     hover_text: Option<String>,
+    /// Label displayed on mouse hover.
     #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
     hover_label: Option<Label>,
+    /// Determines whether the annotation text box captures mouse move and click events, or allows
+    /// those events to pass through to data points in the plot that may be behind the annotation.
+    /// By default `captureevents` is "false" unless `hovertext` is provided. If you use the event
+    /// `plotly_clickannotation` without `hovertext` you must explicitly enable `captureevents`.
     #[serde(skip_serializing_if = "Option::is_none", rename = "captureevents")]
     capture_events: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
-    template_item_name: Option<String>,
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output figure
+    /// by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one by
+    /// making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
 }
 
 impl Annotation {
@@ -1916,341 +1733,567 @@ impl Annotation {
         Default::default()
     }
 
-    /// Determines whether or not this annotation is visible.
-    pub fn visible(mut self, visible: bool) -> Annotation {
-        self.visible = Some(visible);
-        self
-    }
-
-    /// Sets the text associated with this annotation. Plotly uses a subset of HTML tags to do
-    /// things like newline (<br>), bold (<b></b>), italics (<i></i>), hyperlinks
-    /// (<a href='...'></a>). Tags <em>, <sup>, <sub> <span> are also supported.
-    pub fn text(mut self, text: &str) -> Annotation {
-        self.text = Some(text.to_owned());
-        self
-    }
-
-    /// Sets the angle at which the `text` is drawn with respect to the horizontal.
-    pub fn text_angle(mut self, text_angle: f64) -> Annotation {
-        self.text_angle = Some(text_angle);
-        self
-    }
-
-    /// Sets the annotation text font.
-    pub fn font(mut self, font: Font) -> Annotation {
-        self.font = Some(font);
-        self
-    }
-
-    /// Sets an explicit width for the text box. null (default) lets the text set the box width.
-    /// Wider text will be clipped. There is no automatic wrapping; use <br> to start a new line.
-    pub fn width(mut self, width: f64) -> Annotation {
-        self.width = Some(width);
+    /// Makes this annotation respond to clicks on the plot. If you click a data point that exactly
+    /// matches the `x` and `y` values of this annotation, and it is hidden (visible: false), it
+    /// will appear. In "onoff" mode, you must click the same point again to make it disappear, so
+    /// if you click multiple points, you can show multiple annotations. In "onout" mode, a click
+    /// anywhere else in the plot (on another data point or not) will hide this annotation. If you
+    /// need to show/hide this annotation in response to different `x` or `y` values, you can set
+    /// `xclick` and/or `yclick`. This is useful for example to label the side of a bar. To label
+    /// markers though, `standoff` is preferred over `xclick` and `yclick`.
+    pub fn click_to_show(mut self, click_to_show: TruthyEnum<ClickToShow>) -> Annotation {
+        self.click_to_show = Some(click_to_show);
         self
     }
+}
 
-    /// Sets an explicit height for the text box. null (default) lets the text set the box height.
-    /// Taller text will be clipped.
-    pub fn height(mut self, height: f64) -> Annotation {
-        self.height = Some(height);
-        self
-    }
+/// Identifies a 3D scene of the `Layout`. Indices start at 1: `SceneId::new(1)` is the `scene` of
+/// the layout, `SceneId::new(2)` is `scene2`, and so on. Serializes as the name of the scene, which
+/// is how traces select the scene they are drawn in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SceneId(NonZeroUsize);
 
-    /// Sets the opacity of the annotation (text + arrow).
-    pub fn opacity(mut self, opacity: f64) -> Annotation {
-        self.opacity = Some(opacity);
-        self
+impl SceneId {
+    /// The scene with the given index. Panics if `index` is 0.
+    pub fn new(index: usize) -> SceneId {
+        SceneId(NonZeroUsize::new(index).expect("scene indices start at 1"))
     }
 
-    /// Sets the horizontal alignment of the `text` within the box. Has an effect only if `text`
-    /// spans two or more lines (i.e. `text` contains one or more <br> HTML tags) or if an explicit
-    /// width is set to override the text width.
-    pub fn align(mut self, align: HAlign) -> Annotation {
-        self.align = Some(align);
-        self
+    pub fn index(&self) -> usize {
+        self.0.get()
     }
 
-    /// Sets the vertical alignment of the `text` within the box. Has an effect only if an explicit
-    /// height is set to override the text height.
-    pub fn valign(mut self, valign: VAlign) -> Annotation {
-        self.valign = Some(valign);
-        self
+    /// The name of the scene in the layout, e.g. "scene" or "scene2".
+    pub fn layout_name(&self) -> String {
+        match self.index() {
+            1 => "scene".to_owned(),
+            index => format!("scene{}", index),
+        }
     }
+}
 
-    /// Sets the background color of the annotation.
-    pub fn background_color<C: Color>(mut self, background_color: C) -> Annotation {
-        self.background_color = Some(background_color.to_color());
-        self
+impl Serialize for SceneId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.layout_name())
     }
+}
 
-    /// Sets the color of the border enclosing the annotation `text`.
-    pub fn border_color<C: Color>(mut self, border_color: C) -> Annotation {
-        self.border_color = Some(border_color.to_color());
-        self
-    }
+#[derive(Debug, Default)]
+struct Scenes(BTreeMap<SceneId, LayoutScene>);
 
-    /// Sets the padding (in px) between the `text` and the enclosing border.
-    pub fn border_pad(mut self, border_pad: f64) -> Annotation {
-        self.border_pad = Some(border_pad);
-        self
+impl Serialize for Scenes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.0.iter().map(|(id, scene)| (id.layout_name(), scene)))
     }
+}
 
-    /// Sets the width (in px) of the border enclosing the annotation `text`.
-    pub fn border_width(mut self, border_width: f64) -> Annotation {
-        self.border_width = Some(border_width);
-        self
-    }
+/// A point or direction in the normalized coordinates of a scene.
+#[derive(Serialize, Clone, Debug)]
+pub struct CameraPosition {
+    x: f64,
+    y: f64,
+    z: f64,
+}
 
-    /// Determines whether or not the annotation is drawn with an arrow. If "True", `text` is
-    /// placed near the arrow's tail. If "False", `text` lines up with the `x` and `y` provided.
-    pub fn show_arrow(mut self, show_arrow: bool) -> Annotation {
-        self.show_arrow = Some(show_arrow);
-        self
+impl CameraPosition {
+    pub fn new(x: f64, y: f64, z: f64) -> CameraPosition {
+        CameraPosition { x, y, z }
     }
+}
 
-    /// Sets the color of the annotation arrow.
-    pub fn arrow_color<C: Color>(mut self, arrow_color: C) -> Annotation {
-        self.arrow_color = Some(arrow_color.to_color());
-        self
-    }
+#[derive(Serialize, Clone, Debug)]
+pub enum ProjectionType {
+    #[serde(rename = "perspective")]
+    Perspective,
+    #[serde(rename = "orthographic")]
+    Orthographic,
+}
 
-    /// Sets the end annotation arrow head style. Integer between or equal to 0 and 8.
-    pub fn arrow_head(mut self, arrow_head: u8) -> Annotation {
-        self.arrow_head = Some(arrow_head);
-        self
-    }
+#[derive(Serialize, Clone, Debug)]
+struct CameraProjection {
+    r#type: ProjectionType,
+}
 
-    /// Sets the start annotation arrow head style. Integer between or equal to 0 and 8.
-    pub fn start_arrow_head(mut self, start_arrow_head: u8) -> Annotation {
-        self.start_arrow_head = Some(start_arrow_head);
-        self
-    }
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Camera {
+    /// Direction pointing up on the screen, the z axis by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    up: Option<CameraPosition>,
+    /// Point the camera looks at, the center of the scene by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    center: Option<CameraPosition>,
+    /// Position of the camera.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eye: Option<CameraPosition>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projection: Option<CameraProjection>,
+}
 
-    /// Sets the annotation arrow head position.
-    pub fn arrow_side(mut self, arrow_side: ArrowSide) -> Annotation {
-        self.arrow_side = Some(arrow_side);
-        self
+impl Camera {
+    pub fn new() -> Camera {
+        Default::default()
     }
 
-    /// Sets the size of the end annotation arrow head, relative to `arrowwidth`. A value of 1
-    /// (default) gives a head about 3x as wide as the line.
-    pub fn arrow_size(mut self, arrow_size: f64) -> Annotation {
-        self.arrow_size = Some(arrow_size);
+    pub fn projection(mut self, projection: ProjectionType) -> Camera {
+        self.projection = Some(CameraProjection { r#type: projection });
         self
     }
+}
 
-    /// Sets the size of the start annotation arrow head, relative to `arrowwidth`. A value of 1
-    /// (default) gives a head about 3x as wide as the line.
-    pub fn start_arrow_size(mut self, start_arrow_size: f64) -> Annotation {
-        self.start_arrow_size = Some(start_arrow_size);
-        self
-    }
+#[derive(Serialize, Clone, Debug)]
+pub enum AspectMode {
+    /// `AspectMode::Data` unless one axis is more than four times longer than the others, then
+    /// `AspectMode::Cube`.
+    #[serde(rename = "auto")]
+    Auto,
+    /// The axes are drawn as a cube.
+    #[serde(rename = "cube")]
+    Cube,
+    /// The axes are drawn in proportion to their ranges.
+    #[serde(rename = "data")]
+    Data,
+    /// The axes are drawn with the `LayoutScene::aspect_ratio`.
+    #[serde(rename = "manual")]
+    Manual,
+}
 
-    /// Sets the width (in px) of annotation arrow line.
-    pub fn arrow_width(mut self, arrow_width: f64) -> Annotation {
-        self.arrow_width = Some(arrow_width);
-        self
-    }
+#[derive(Serialize, Clone, Debug)]
+pub struct AspectRatio {
+    x: f64,
+    y: f64,
+    z: f64,
+}
 
-    /// Sets a distance, in pixels, to move the end arrowhead away from the position it is pointing
-    /// at, for example to point at the edge of a marker independent of zoom. Note that this
-    /// shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift` which
-    /// moves everything by this amount.
-    pub fn stand_off(mut self, stand_off: f64) -> Annotation {
-        self.stand_off = Some(stand_off);
-        self
+impl AspectRatio {
+    pub fn new(x: f64, y: f64, z: f64) -> AspectRatio {
+        AspectRatio { x, y, z }
     }
+}
 
-    /// Sets a distance, in pixels, to move the start arrowhead away from the position it is
-    /// pointing at, for example to point at the edge of a marker independent of zoom. Note that
-    /// this shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift`
-    /// which moves everything by this amount.
-    pub fn start_stand_off(mut self, start_stand_off: f64) -> Annotation {
-        self.start_stand_off = Some(start_stand_off);
-        self
-    }
+/// An axis of a `LayoutScene`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct SceneAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Title>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<AxisType>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
+    auto_range: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
+    range_mode: Option<RangeMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Vec<NumOrStringWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nticks")]
+    n_ticks: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tick0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtick: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ticks: Option<TicksDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mirror: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showexponent")]
+    show_exponent: Option<ArrayShow>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "separatethousands")]
+    separate_thousands: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformatstops")]
+    tick_format_stops: Option<Vec<TickFormatStop>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linecolor")]
+    line_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridcolor")]
+    grid_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridwidth")]
+    grid_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zeroline")]
+    zero_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zerolinecolor")]
+    zero_line_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zerolinewidth")]
+    zero_line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showspikes")]
+    show_spikes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spikecolor")]
+    spike_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spikethickness")]
+    spike_thickness: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showbackground")]
+    show_background: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "backgroundcolor")]
+    background_color: Option<ColorWrapper>,
+    /// Whether spikes are extended to the sides of the scene.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spikesides")]
+    spike_sides: Option<bool>,
+    /// Whether the axis labels are drawn next to the spikes.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showaxeslabels")]
+    show_axes_labels: Option<bool>,
+}
 
-    /// Sets the x component of the arrow tail about the arrow head. If `axref` is `pixel`, a
-    /// positive (negative) component corresponds to an arrow pointing from right to left (left
-    /// to right). If `axref` is an axis, this is an absolute value on that axis, like `x`, NOT a
-    /// relative value.
-    pub fn ax<C: NumOrString>(mut self, ax: C) -> Annotation {
-        self.ax = Some(ax.to_num_or_string());
-        self
+impl SceneAxis {
+    pub fn new() -> SceneAxis {
+        Default::default()
     }
+}
 
-    /// Sets the y component of the arrow tail about the arrow head. If `ayref` is `pixel`, a
-    /// positive (negative) component corresponds to an arrow pointing from bottom to top (top to
-    /// bottom). If `ayref` is an axis, this is an absolute value on that axis, like `y`, NOT a
-    /// relative value.
-    pub fn ay<C: NumOrString>(mut self, ay: C) -> Annotation {
-        self.ay = Some(ay.to_num_or_string());
-        self
-    }
+/// A 3D scene, set on the layout with `Layout::scene` or `Layout::scene_n`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct LayoutScene {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    camera: Option<Camera>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "aspectmode")]
+    aspect_mode: Option<AspectMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "aspectratio")]
+    aspect_ratio: Option<AspectRatio>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<SceneAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<SceneAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zaxis")]
+    z_axis: Option<SceneAxis>,
+    /// `DragMode::Orbit`, `DragMode::TurnTable`, `DragMode::Zoom`, `DragMode::Pan` or
+    /// `DragMode::False`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dragmode")]
+    drag_mode: Option<TruthyEnum<DragMode>>,
+    /// `HoverMode::Closest` or `HoverMode::False`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovermode")]
+    hover_mode: Option<TruthyEnum<HoverMode>>,
+}
 
-    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ax`
-    /// is a relative offset in pixels from `x`. If set to an x axis id (e.g. "x" or "x2"), `ax` is
-    /// specified in the same terms as that axis. This is useful for trendline annotations which
-    /// should continue to indicate the correct trend when zoomed.
-    pub fn ax_ref(mut self, ax_ref: &str) -> Annotation {
-        self.ax_ref = Some(ax_ref.to_owned());
-        self
+impl LayoutScene {
+    pub fn new() -> LayoutScene {
+        Default::default()
     }
+}
 
-    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ay`
-    /// is a relative offset in pixels from `y`. If set to a y axis id (e.g. "y" or "y2"), `ay` is
-    /// specified in the same terms as that axis. This is useful for trendline annotations which
-    /// should continue to indicate the correct trend when zoomed.
-    pub fn ay_ref(mut self, ay_ref: &str) -> Annotation {
-        self.ay_ref = Some(ay_ref.to_owned());
-        self
-    }
+/// Identifies a polar subplot of the `Layout`. Indices start at 1: `PolarId::new(1)` is the
+/// `polar` subplot of the layout, `PolarId::new(2)` is `polar2`, and so on. Serializes as the name
+/// of the subplot, which is how polar traces select the subplot they are drawn in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PolarId(NonZeroUsize);
 
-    /// Sets the annotation's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
-    /// position refers to an x coordinate If set to "paper", the `x` position refers to the
-    /// distance from the left side of the plotting area in normalized coordinates where 0 (1)
-    /// corresponds to the left (right) side.
-    pub fn x_ref(mut self, x_ref: &str) -> Annotation {
-        self.x_ref = Some(x_ref.to_owned());
-        self
+impl PolarId {
+    /// The polar subplot with the given index. Panics if `index` is 0.
+    pub fn new(index: usize) -> PolarId {
+        PolarId(NonZeroUsize::new(index).expect("polar subplot indices start at 1"))
     }
 
-    /// Sets the annotation's x position. If the axis `type` is "log", then you must take the log
-    /// of your desired range. If the axis `type` is "date", it should be date strings, like date
-    /// data, though Date objects and unix milliseconds will be accepted and converted to strings.
-    /// If the axis `type` is "category", it should be numbers, using the scale where each category
-    /// is assigned a serial number from zero in the order it appears.
-    pub fn x<C: NumOrString>(mut self, x: C) -> Annotation {
-        self.x = Some(x.to_num_or_string());
-        self
+    pub fn index(&self) -> usize {
+        self.0.get()
     }
 
-    /// Sets the text box's horizontal position anchor This anchor binds the `x` position to the
-    /// "left", "center" or "right" of the annotation. For example, if `x` is set to 1, `xref` to
-    /// "paper" and `xanchor` to "right" then the right-most portion of the annotation lines up with
-    /// the right-most edge of the plotting area. If "auto", the anchor is equivalent to "center"
-    /// for data-referenced annotations or if there is an arrow, whereas for paper-referenced with
-    /// no arrow, the anchor picked corresponds to the closest side.
-    pub fn x_anchor(mut self, x_anchor: Anchor) -> Annotation {
-        self.x_anchor = Some(x_anchor);
-        self
+    /// The name of the polar subplot in the layout, e.g. "polar" or "polar2".
+    pub fn layout_name(&self) -> String {
+        match self.index() {
+            1 => "polar".to_owned(),
+            index => format!("polar{}", index),
+        }
     }
+}
 
-    /// Shifts the position of the whole annotation and arrow to the right (positive) or left
-    /// (negative) by this many pixels.
-    pub fn x_shift(mut self, x_shift: f64) -> Annotation {
-        self.x_shift = Some(x_shift);
-        self
+impl Serialize for PolarId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.layout_name())
     }
+}
 
-    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"), the `y`
-    /// position refers to an y coordinate If set to "paper", the `y` position refers to the
-    /// distance from the bottom of the plotting area in normalized coordinates where 0 (1)
-    /// corresponds to the bottom (top).
-    pub fn y_ref(mut self, y_ref: &str) -> Annotation {
-        self.y_ref = Some(y_ref.to_owned());
-        self
-    }
+#[derive(Debug, Default)]
+struct Polars(BTreeMap<PolarId, LayoutPolar>);
 
-    /// Sets the annotation's y position. If the axis `type` is "log", then you must take the log of
-    /// your desired range. If the axis `type` is "date", it should be date strings, like date data,
-    /// though Date objects and unix milliseconds will be accepted and converted to strings. If the
-    /// axis `type` is "category", it should be numbers, using the scale where each category is
-    /// assigned a serial number from zero in the order it appears.
-    pub fn y<C: NumOrString>(mut self, y: C) -> Annotation {
-        self.y = Some(y.to_num_or_string());
-        self
+impl Serialize for Polars {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.0.iter().map(|(id, polar)| (id.layout_name(), polar)))
     }
+}
 
-    /// Sets the text box's vertical position anchor This anchor binds the `y` position to the
-    /// "top", "middle" or "bottom" of the annotation. For example, if `y` is set to 1, `yref` to
-    /// "paper" and `yanchor` to "top" then the top-most portion of the annotation lines up with the
-    /// top-most edge of the plotting area. If "auto", the anchor is equivalent to "middle" for
-    /// data-referenced annotations or if there is an arrow, whereas for paper-referenced with no
-    /// arrow, the anchor picked corresponds to the closest side.
-    pub fn y_anchor(mut self, y_anchor: Anchor) -> Annotation {
-        self.y_anchor = Some(y_anchor);
-        self
-    }
+/// Unit of the angular coordinates.
+#[derive(Serialize, Clone, Debug)]
+pub enum ThetaUnit {
+    #[serde(rename = "radians")]
+    Radians,
+    #[serde(rename = "degrees")]
+    Degrees,
+    /// Only valid for the `theta_unit` of polar traces, not of an `AngularAxis`.
+    #[serde(rename = "gradians")]
+    Gradians,
+}
 
-    /// Shifts the position of the whole annotation and arrow up (positive) or down (negative) by
-    /// this many pixels.
-    pub fn y_shift(mut self, y_shift: f64) -> Annotation {
-        self.y_shift = Some(y_shift);
-        self
-    }
+/// Whether the axis lines and tick labels are drawn above or below the traces.
+#[derive(Serialize, Clone, Debug)]
+pub enum AxisLayer {
+    #[serde(rename = "above traces")]
+    AboveTraces,
+    #[serde(rename = "below traces")]
+    BelowTraces,
+}
 
-    /// Makes this annotation respond to clicks on the plot. If you click a data point that exactly
-    /// matches the `x` and `y` values of this annotation, and it is hidden (visible: false), it
-    /// will appear. In "onoff" mode, you must click the same point again to make it disappear, so
-    /// if you click multiple points, you can show multiple annotations. In "onout" mode, a click
-    /// anywhere else in the plot (on another data point or not) will hide this annotation. If you
-    /// need to show/hide this annotation in response to different `x` or `y` values, you can set
-    /// `xclick` and/or `yclick`. This is useful for example to label the side of a bar. To label
-    /// markers though, `standoff` is preferred over `xclick` and `yclick`.
-    pub fn click_to_show(mut self, click_to_show: TruthyEnum<ClickToShow>) -> Annotation {
-        self.click_to_show = Some(click_to_show);
-        self
-    }
+/// Shape of the grid of a polar subplot.
+#[derive(Serialize, Clone, Debug)]
+pub enum GridShape {
+    #[serde(rename = "circular")]
+    Circular,
+    /// Polygons with vertices at the angular ticks, as in radar charts.
+    #[serde(rename = "linear")]
+    Linear,
+}
 
-    /// Toggle this annotation when clicking a data point whose `x` value is `xclick` rather than
-    /// the annotation's `x` value.
-    pub fn x_click<C: NumOrString>(mut self, x_click: C) -> Annotation {
-        self.x_click = Some(x_click.to_num_or_string());
-        self
-    }
+/// How the bars of `BarPolar` traces at the same location are drawn.
+#[derive(Serialize, Clone, Debug)]
+pub enum PolarBarMode {
+    #[serde(rename = "stack")]
+    Stack,
+    #[serde(rename = "overlay")]
+    Overlay,
+}
 
-    /// Toggle this annotation when clicking a data point whose `y` value is `yclick` rather than
-    /// the annotation's `y` value.
-    pub fn y_click<C: NumOrString>(mut self, y_click: C) -> Annotation {
-        self.y_click = Some(y_click.to_num_or_string());
-        self
-    }
+/// The radial axis of a `LayoutPolar`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct RadialAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Title>,
+    /// `AxisType::Default`, `AxisType::Linear`, `AxisType::Log`, `AxisType::Date` or
+    /// `AxisType::Category`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<AxisType>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
+    auto_range: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
+    range_mode: Option<RangeMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Vec<NumOrStringWrapper>>,
+    /// Angle in degrees at which the radial axis is drawn, the start of the sector by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    angle: Option<f64>,
+    /// Side of the radial axis the tick labels are drawn on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<RotationDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nticks")]
+    n_ticks: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tick0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtick: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ticks: Option<TicksDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linecolor")]
+    line_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridcolor")]
+    grid_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridwidth")]
+    grid_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layer: Option<AxisLayer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    calendar: Option<Calendar>,
+}
 
-    /// Sets text to appear when hovering over this annotation. If omitted or blank, no hover label
-    /// will appear.
-    pub fn hover_text(mut self, hover_text: &str) -> Annotation {
-        self.hover_text = Some(hover_text.to_owned());
-        self
+impl RadialAxis {
+    pub fn new() -> RadialAxis {
+        Default::default()
     }
+}
 
-    /// Label displayed on mouse hover.
-    pub fn hover_label(mut self, hover_label: Label) -> Annotation {
-        self.hover_label = Some(hover_label);
-        self
-    }
+/// The angular axis of a `LayoutPolar`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct AngularAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    /// `AxisType::Default`, `AxisType::Linear` or `AxisType::Category`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<AxisType>,
+    /// `ThetaUnit::Radians` or `ThetaUnit::Degrees`, the unit of the tick labels of a linear axis.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    /// Number of categories spanning a full turn of a category axis, the number of categories by
+    /// default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<usize>,
+    /// Direction in which the angles increase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<RotationDirection>,
+    /// Angle in degrees at which the angular axis starts, 0 (east) by default, or 90 (north) with
+    /// `RotationDirection::Clockwise`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nticks")]
+    n_ticks: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tick0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtick: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ticks: Option<TicksDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linecolor")]
+    line_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridcolor")]
+    grid_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridwidth")]
+    grid_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layer: Option<AxisLayer>,
+}
 
-    /// Determines whether the annotation text box captures mouse move and click events, or allows
-    /// those events to pass through to data points in the plot that may be behind the annotation.
-    /// By default `captureevents` is "false" unless `hovertext` is provided. If you use the event
-    /// `plotly_clickannotation` without `hovertext` you must explicitly enable `captureevents`.
-    pub fn capture_events(mut self, capture_events: bool) -> Annotation {
-        self.capture_events = Some(capture_events);
-        self
+impl AngularAxis {
+    pub fn new() -> AngularAxis {
+        Default::default()
     }
+}
 
-    /// When used in a template, named items are created in the output figure in addition to any
-    /// items the figure already has in this array. You can modify these items in the output figure
-    /// by making your own item with `templateitemname` matching this `name` alongside your
-    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
-    /// outside of a template.
-    pub fn name(mut self, name: &str) -> Annotation {
-        self.name = Some(name.to_owned());
-        self
-    }
+/// A polar subplot, set on the layout with `Layout::polar` or `Layout::polar_n`.
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct LayoutPolar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    /// Start and end angles in degrees of the sector drawn, `[0., 360.]` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sector: Option<Vec<f64>>,
+    /// Fraction of the radius cut out of the center of the subplot, in `[0, 1]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hole: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "radialaxis")]
+    radial_axis: Option<RadialAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "angularaxis")]
+    angular_axis: Option<AngularAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridshape")]
+    grid_shape: Option<GridShape>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "barmode")]
+    bar_mode: Option<PolarBarMode>,
+    /// Gap between the bars of adjacent locations, as a fraction of the angular width of a bar.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bargap")]
+    bar_gap: Option<f64>,
+}
 
-    /// Used to refer to a named item in this array in the template. Named items from the template
-    /// will be created even without a matching item in the input figure, but you can modify one by
-    /// making an item with `templateitemname` matching its `name`, alongside your modifications
-    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
-    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
-    pub fn template_item_name(mut self, template_item_name: &str) -> Annotation {
-        self.template_item_name = Some(template_item_name.to_owned());
-        self
+impl LayoutPolar {
+    pub fn new() -> LayoutPolar {
+        Default::default()
     }
 }
 
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, FieldSetter)]
 pub struct Layout {
     #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<Title>,
@@ -2268,7 +2311,7 @@ pub struct Layout {
     height: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     font: Option<Font>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "uniformtext")]
     uniform_text: Option<UniformText>,
     #[serde(skip_serializing_if = "Option::is_none")]
     separators: Option<String>,
@@ -2284,14 +2327,30 @@ pub struct Layout {
     color_axis: Option<ColorAxis>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "modebar")]
     mode_bar: Option<ModeBar>,
+    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
+    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
+    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
+    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
+    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
+    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
+    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
+    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
+    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
+    /// else the default value is "closest".
     #[serde(skip_serializing_if = "Option::is_none", rename = "hovermode")]
     hover_mode: Option<TruthyEnum<HoverMode>>,
+    /// Determines the mode of single click interactions: "event" emits `plotly_click` events,
+    /// "select" selects the clicked points. Set with the flags of `ClickMode`.
     #[serde(skip_serializing_if = "Option::is_none", rename = "clickmode")]
-    click_mode: Option<String>,
+    click_mode: Option<ClickMode>,
+    /// Determines the mode of drag interactions. "select" and "lasso" apply only to scatter traces
+    /// with markers or text. "orbit" and "turntable" apply only to 3D scenes.
     #[serde(skip_serializing_if = "Option::is_none", rename = "dragmode")]
-    drag_mode: Option<String>,
+    drag_mode: Option<TruthyEnum<DragMode>>,
+    /// When `dragmode` is set to "select", this limits the selection of the drag to horizontal,
+    /// vertical or diagonal.
     #[serde(skip_serializing_if = "Option::is_none", rename = "selectdirection")]
-    select_direction: Option<String>,
+    select_direction: Option<SelectDirection>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "hoverdistance")]
     hover_distance: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "spikedistance")]
@@ -2299,50 +2358,25 @@ pub struct Layout {
     #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
     hover_label: Option<Label>,
 
+    #[field_setter(skip)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    template: Option<String>,
+    template: Option<Template>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     grid: Option<LayoutGrid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     calendar: Option<Calendar>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
-    x_axis: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
-    y_axis: Option<Axis>,
-
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis2")]
-    x_axis2: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis2")]
-    y_axis2: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis3")]
-    x_axis3: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis3")]
-    y_axis3: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis4")]
-    x_axis4: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis4")]
-    y_axis4: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis5")]
-    x_axis5: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis5")]
-    y_axis5: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis6")]
-    x_axis6: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis6")]
-    y_axis6: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis7")]
-    x_axis7: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis7")]
-    y_axis7: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis8")]
-    x_axis8: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis8")]
-    y_axis8: Option<Axis>,
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    axes: Axes,
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    scenes: Scenes,
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    polars: Polars,
 
     // ternary: Option<LayoutTernary>,
-    // scene: Option<LayoutScene>,
-    // polar: Option<LayoutPolar>,
     #[serde(skip_serializing_if = "Option::is_none")]
     annotations: Option<Vec<Annotation>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2391,7 +2425,7 @@ pub struct Layout {
     sunburst_colorway: Option<Vec<ColorWrapper>>,
     #[serde(
         skip_serializing_if = "Option::is_none",
-        rename = "extendsuburstcolors"
+        rename = "extendsunburstcolors"
     )]
     extend_sunburst_colors: Option<bool>,
 }
@@ -2401,225 +2435,120 @@ impl Layout {
         Default::default()
     }
 
-    pub fn title(mut self, title: Title) -> Layout {
-        self.title = Some(title);
-        self
-    }
-
-    pub fn show_legend(mut self, show_legend: bool) -> Layout {
-        self.show_legend = Some(show_legend);
-        self
-    }
-
-    pub fn legend(mut self, legend: Legend) -> Layout {
-        self.legend = Some(legend);
-        self
-    }
-
-    pub fn margin(mut self, margin: Margin) -> Layout {
-        self.margin = Some(margin);
-        self
-    }
-
-    pub fn auto_size(mut self, auto_size: bool) -> Layout {
-        self.auto_size = Some(auto_size);
-        self
-    }
-
-    pub fn width(mut self, width: usize) -> Layout {
-        self.width = Some(width);
-        self
-    }
-
-    pub fn height(mut self, height: usize) -> Layout {
-        self.height = Some(height);
-        self
-    }
-
-    pub fn font(mut self, font: Font) -> Layout {
-        self.font = Some(font);
-        self
-    }
-
-    pub fn uniform_text(mut self, uniform_text: UniformText) -> Layout {
-        self.uniform_text = Some(uniform_text);
-        self
-    }
-
-    pub fn separators(mut self, separators: &str) -> Layout {
-        self.separators = Some(separators.to_owned());
-        self
-    }
-
-    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Layout {
-        self.paper_background_color = Some(paper_background_color.to_color());
-        self
-    }
-
-    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Layout {
-        self.plot_background_color = Some(plot_background_color.to_color());
-        self
-    }
-
-    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Layout {
-        self.color_scale = Some(color_scale);
-        self
-    }
-
-    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Layout {
-        let colorway = private::to_color_array(colorway);
-        self.colorway = Some(colorway);
-        self
-    }
-
-    pub fn color_axis(mut self, color_axis: ColorAxis) -> Layout {
-        self.color_axis = Some(color_axis);
-        self
-    }
-
-    pub fn mode_bar(mut self, mode_bar: ModeBar) -> Layout {
-        self.mode_bar = Some(mode_bar);
-        self
-    }
-
-    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
-    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
-    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
-    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
-    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
-    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
-    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
-    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
-    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
-    /// else the default value is "closest".
-    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Layout {
-        self.hover_mode = Some(TruthyEnum { e: hover_mode });
-        self
-    }
-
-    pub fn click_mode(mut self, click_mode: &str) -> Layout {
-        self.click_mode = Some(click_mode.to_owned());
-        self
-    }
-
-    pub fn drag_mode(mut self, drag_mode: &str) -> Layout {
-        self.drag_mode = Some(drag_mode.to_owned());
-        self
-    }
-
-    pub fn select_direction(mut self, select_direction: &str) -> Layout {
-        self.select_direction = Some(select_direction.to_owned());
-        self
-    }
-
-    pub fn hover_distance(mut self, hover_distance: i32) -> Layout {
-        self.hover_distance = Some(hover_distance);
-        self
-    }
-
-    pub fn spike_distance(mut self, spike_distance: i32) -> Layout {
-        self.spike_distance = Some(spike_distance);
-        self
-    }
-
-    pub fn hover_label(mut self, hover_label: Label) -> Layout {
-        self.hover_label = Some(hover_label);
-        self
-    }
-
-    pub fn grid(mut self, grid: LayoutGrid) -> Layout {
-        self.grid = Some(grid);
-        self
-    }
-
-    pub fn calendar(mut self, calendar: Calendar) -> Layout {
-        self.calendar = Some(calendar);
-        self
-    }
-
-    pub fn x_axis(mut self, xaxis: Axis) -> Layout {
-        self.x_axis = Some(xaxis);
-        self
-    }
-
-    pub fn y_axis(mut self, yaxis: Axis) -> Layout {
-        self.y_axis = Some(yaxis);
-        self
-    }
-
-    pub fn x_axis2(mut self, xaxis: Axis) -> Layout {
-        self.x_axis2 = Some(xaxis);
+    /// Sets the default attributes of the traces and of the layout, either a `Template` or one of
+    /// the built-in templates of `plotly::template`. Overrides the default template set with
+    /// `template::set_default_template`.
+    pub fn template<T: Into<Template>>(mut self, template: T) -> Layout {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// Sets the axis identified by `id`, replacing the axis previously set for it. Panics if
+    /// `axis` is anchored to an axis of its own letter, or overlays or matches an axis of the other
+    /// letter.
+    pub fn axis<I: Into<AxisId>>(mut self, id: I, axis: Axis) -> Layout {
+        let id = id.into();
+        let letter = |axis_ref: Option<AxisRef>| match axis_ref {
+            Some(AxisRef::X(_)) => Some("x"),
+            Some(AxisRef::Y(_)) => Some("y"),
+            Some(AxisRef::Free) | None => None,
+        };
+        assert!(
+            letter(axis.anchor) != Some(id.letter()),
+            "{} is anchored to an axis of its own letter",
+            id.layout_name()
+        );
+        for other in &[
+            letter(axis.overlaying),
+            letter(axis.matches.map(AxisRef::from)),
+        ] {
+            if let Some(other) = *other {
+                assert!(
+                    other == id.letter(),
+                    "{} overlays or matches an axis of the other letter",
+                    id.layout_name()
+                );
+            }
+        }
+        self.axes.0.insert(id, axis);
         self
     }
 
-    pub fn y_axis2(mut self, yaxis: Axis) -> Layout {
-        self.y_axis2 = Some(yaxis);
-        self
+    /// Sets the first x axis, same as `x_axis_n(1, axis)`.
+    pub fn x_axis(self, axis: Axis) -> Layout {
+        self.axis(AxisId::x(1), axis)
     }
 
-    pub fn x_axis3(mut self, xaxis: Axis) -> Layout {
-        self.x_axis3 = Some(xaxis);
-        self
+    /// Sets the first y axis, same as `y_axis_n(1, axis)`.
+    pub fn y_axis(self, axis: Axis) -> Layout {
+        self.axis(AxisId::y(1), axis)
     }
 
-    pub fn y_axis3(mut self, yaxis: Axis) -> Layout {
-        self.y_axis3 = Some(yaxis);
-        self
+    /// Sets the x axis with the given index, serialized as "xaxis" for index 1 and as
+    /// "xaxis<index>" otherwise. Panics if `index` is 0.
+    pub fn x_axis_n(self, index: usize, axis: Axis) -> Layout {
+        self.axis(AxisId::x(index), axis)
     }
 
-    pub fn x_axis4(mut self, xaxis: Axis) -> Layout {
-        self.x_axis4 = Some(xaxis);
-        self
+    /// Sets the y axis with the given index, serialized as "yaxis" for index 1 and as
+    /// "yaxis<index>" otherwise. Panics if `index` is 0.
+    pub fn y_axis_n(self, index: usize, axis: Axis) -> Layout {
+        self.axis(AxisId::y(index), axis)
     }
 
-    pub fn y_axis4(mut self, yaxis: Axis) -> Layout {
-        self.y_axis4 = Some(yaxis);
-        self
+    /// The axis identified by `id`, if it is set.
+    pub fn get_axis<I: Into<AxisId>>(&self, id: I) -> Option<&Axis> {
+        self.axes.0.get(&id.into())
     }
 
-    pub fn x_axis5(mut self, xaxis: Axis) -> Layout {
-        self.x_axis5 = Some(xaxis);
-        self
+    /// Iterates over all axes set on the layout in serialization order: "xaxis", "yaxis",
+    /// "xaxis2", ...
+    pub fn axes(&self) -> impl Iterator<Item = (AxisId, &Axis)> {
+        self.axes.0.iter().map(|(id, axis)| (*id, axis))
     }
 
-    pub fn y_axis5(mut self, yaxis: Axis) -> Layout {
-        self.y_axis5 = Some(yaxis);
-        self
+    /// Iterates over the x axes set on the layout in order of their index.
+    pub fn x_axes(&self) -> impl Iterator<Item = (AxisId, &Axis)> {
+        self.axes().filter(|(id, _)| matches!(id, AxisId::X(_)))
     }
 
-    pub fn x_axis6(mut self, xaxis: Axis) -> Layout {
-        self.x_axis6 = Some(xaxis);
-        self
+    /// Iterates over the y axes set on the layout in order of their index.
+    pub fn y_axes(&self) -> impl Iterator<Item = (AxisId, &Axis)> {
+        self.axes().filter(|(id, _)| matches!(id, AxisId::Y(_)))
     }
 
-    pub fn y_axis6(mut self, yaxis: Axis) -> Layout {
-        self.y_axis6 = Some(yaxis);
-        self
+    /// Sets the first 3D scene, same as `scene_n(1, scene)`.
+    pub fn scene(self, scene: LayoutScene) -> Layout {
+        self.scene_n(1, scene)
     }
 
-    pub fn x_axis7(mut self, xaxis: Axis) -> Layout {
-        self.x_axis7 = Some(xaxis);
+    /// Sets the 3D scene with the given index, serialized as "scene" for index 1 and as
+    /// "scene<index>" otherwise. Panics if `index` is 0.
+    pub fn scene_n(mut self, index: usize, scene: LayoutScene) -> Layout {
+        self.scenes.0.insert(SceneId::new(index), scene);
         self
     }
 
-    pub fn y_axis7(mut self, yaxis: Axis) -> Layout {
-        self.y_axis7 = Some(yaxis);
-        self
+    /// The scene identified by `id`, if it is set.
+    pub fn get_scene(&self, id: SceneId) -> Option<&LayoutScene> {
+        self.scenes.0.get(&id)
     }
 
-    pub fn x_axis8(mut self, xaxis: Axis) -> Layout {
-        self.x_axis8 = Some(xaxis);
-        self
+    /// Sets the first polar subplot, same as `polar_n(1, polar)`.
+    pub fn polar(self, polar: LayoutPolar) -> Layout {
+        self.polar_n(1, polar)
     }
 
-    pub fn y_axis8(mut self, yaxis: Axis) -> Layout {
-        self.y_axis8 = Some(yaxis);
+    /// Sets the polar subplot with the given index, serialized as "polar" for index 1 and as
+    /// "polar<index>" otherwise. Panics if `index` is 0.
+    pub fn polar_n(mut self, index: usize, polar: LayoutPolar) -> Layout {
+        self.polars.0.insert(PolarId::new(index), polar);
         self
     }
 
-    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Layout {
-        self.annotations = Some(annotations);
-        self
+    /// The polar subplot identified by `id`, if it is set.
+    pub fn get_polar(&self, id: PolarId) -> Option<&LayoutPolar> {
+        self.polars.0.get(&id)
     }
 
     pub fn add_annotation(&mut self, annotation: Annotation) {
@@ -2629,119 +2558,12 @@ impl Layout {
         self.annotations.as_mut().unwrap().push(annotation);
     }
 
-    pub fn shapes(mut self, shapes: Vec<Shape>) -> Layout {
-        self.shapes = Some(shapes);
-        self
-    }
-
     pub fn add_shape(&mut self, shape: Shape) {
         if self.shapes.is_none() {
             self.shapes = Some(Vec::new());
         }
         self.shapes.as_mut().unwrap().push(shape);
     }
-
-    pub fn new_shape(mut self, new_shape: NewShape) -> Layout {
-        self.new_shape = Some(new_shape);
-        self
-    }
-
-    pub fn active_shape(mut self, active_shape: ActiveShape) -> Layout {
-        self.active_shape = Some(active_shape);
-        self
-    }
-
-    pub fn template(mut self, template: &str) -> Layout {
-        self.template = Some(template.to_owned());
-        self
-    }
-
-    pub fn box_mode(mut self, box_mode: BoxMode) -> Layout {
-        self.box_mode = Some(box_mode);
-        self
-    }
-
-    pub fn box_gap(mut self, box_gap: f64) -> Layout {
-        self.box_gap = Some(box_gap);
-        self
-    }
-
-    pub fn box_group_gap(mut self, box_group_gap: f64) -> Layout {
-        self.box_group_gap = Some(box_group_gap);
-        self
-    }
-
-    pub fn bar_mode(mut self, bar_mode: BarMode) -> Layout {
-        self.bar_mode = Some(bar_mode);
-        self
-    }
-
-    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Layout {
-        self.bar_norm = Some(bar_norm);
-        self
-    }
-
-    pub fn bar_gap(mut self, bar_gap: f64) -> Layout {
-        self.bar_gap = Some(bar_gap);
-        self
-    }
-
-    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Layout {
-        self.bar_group_gap = Some(bar_group_gap);
-        self
-    }
-
-    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Layout {
-        self.violin_mode = Some(violin_mode);
-        self
-    }
-
-    pub fn violin_gap(mut self, violin_gap: f64) -> Layout {
-        self.violin_gap = Some(violin_gap);
-        self
-    }
-
-    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Layout {
-        self.violin_group_gap = Some(violin_group_gap);
-        self
-    }
-
-    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Layout {
-        self.waterfall_mode = Some(waterfall_mode);
-        self
-    }
-
-    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Layout {
-        self.waterfall_gap = Some(waterfall_gap);
-        self
-    }
-
-    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Layout {
-        self.waterfall_group_gap = Some(waterfall_group_gap);
-        self
-    }
-
-    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Layout {
-        let pie_colorway = private::to_color_array(pie_colorway);
-        self.pie_colorway = Some(pie_colorway);
-        self
-    }
-
-    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Layout {
-        self.extend_pie_colors = Some(extend_pie_colors);
-        self
-    }
-
-    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Layout {
-        let sunburst_colorway = private::to_color_array(sunburst_colorway);
-        self.sunburst_colorway = Some(sunburst_colorway);
-        self
-    }
-
-    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Layout {
-        self.extend_sunburst_colors = Some(extend_sunburst_colors);
-        self
-    }
 }
 
 impl Trace for Layout {
@@ -2749,3 +2571,182 @@ impl Trace for Layout {
         serde_json::to_string(&self).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_indexed_axes() {
+        let mut layout = Layout::new()
+            .y_axis_n(2, Axis::new().title("y2".into()))
+            .x_axis(Axis::new().title("x".into()));
+        for index in 1..=9 {
+            layout = layout.x_axis_n(index, Axis::new().anchor(AxisRef::y(index)));
+        }
+        layout = layout.axis(AxisId::y(10), Axis::new().overlaying(AxisRef::y(1)));
+
+        let json: Value = serde_json::from_str(&Trace::serialize(&layout)).unwrap();
+        assert_eq!(json["xaxis"], json!({"anchor": "y"}));
+        assert_eq!(json["xaxis9"], json!({"anchor": "y9"}));
+        assert_eq!(json["yaxis2"], json!({"title": {"text": "y2"}}));
+        assert_eq!(json["yaxis10"], json!({"overlaying": "y"}));
+        assert!(json.get("xaxis1").is_none());
+
+        let names: Vec<String> = layout.axes().map(|(id, _)| id.layout_name()).collect();
+        assert_eq!(&names[..4], &["xaxis", "xaxis2", "yaxis2", "xaxis3"]);
+        assert_eq!(names.len(), 11);
+        assert_eq!(layout.x_axes().count(), 9);
+        assert_eq!(
+            layout.y_axes().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![AxisId::y(2), AxisId::y(10)]
+        );
+        assert!(layout.get_axis(AxisId::x(9)).is_some());
+        assert!(layout.get_axis(AxisId::y(1)).is_none());
+        assert_eq!(AxisId::y(1).reference(), "y");
+        assert_eq!(AxisId::x(12).reference(), "x12");
+    }
+
+    #[test]
+    fn test_refs() {
+        let axis = Axis::new()
+            .anchor(AxisRef::Free)
+            .overlaying(AxisId::y(1).into())
+            .matches(AxisId::y(3));
+        let json = serde_json::to_value(axis).unwrap();
+        assert_eq!(
+            json,
+            json!({"anchor": "free", "overlaying": "y", "matches": "y3"})
+        );
+
+        let refs = (
+            [XRef::x(1), XRef::x(3), XRef::Paper],
+            [YRef::y(2), YRef::Paper],
+            AxisRef::from(AxisId::x(2)),
+            [XAxisId::new(1), XAxisId::new(4)],
+            YAxisId::new(2),
+        );
+        assert_eq!(
+            serde_json::to_value(refs).unwrap(),
+            json!([
+                ["x", "x3", "paper"],
+                ["y2", "paper"],
+                "x2",
+                ["x", "x4"],
+                "y2"
+            ])
+        );
+        assert_eq!(AxisId::from(YAxisId::new(3)), AxisId::y(3));
+        assert_eq!(AxisRef::from(XAxisId::new(3)), AxisRef::x(3));
+
+        let annotation = Annotation::new()
+            .x_ref(XRef::Paper)
+            .y_ref(YRef::y(2))
+            .ax_ref(TailRef::Pixel)
+            .ay_ref(TailRef::axis(2));
+        let json = serde_json::to_value(annotation).unwrap();
+        assert_eq!(
+            json,
+            json!({"xref": "paper", "yref": "y2", "axref": "pixel", "ayref": "y2"})
+        );
+        let json = serde_json::to_value(Annotation::new().ax_ref(TailRef::axis(1))).unwrap();
+        assert_eq!(json, json!({"axref": "x"}));
+    }
+
+    #[test]
+    #[should_panic(expected = "xaxis2 is anchored to an axis of its own letter")]
+    fn test_axis_anchored_to_own_letter() {
+        Layout::new().x_axis_n(2, Axis::new().anchor(AxisRef::x(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "yaxis2 overlays or matches an axis of the other letter")]
+    fn test_axis_overlaying_other_letter() {
+        Layout::new().y_axis_n(2, Axis::new().overlaying(AxisRef::x(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "yaxis overlays or matches an axis of the other letter")]
+    fn test_axis_matching_other_letter() {
+        Layout::new().y_axis(Axis::new().matches(AxisId::x(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "axis indices start at 1")]
+    fn test_axis_id_index_zero() {
+        let _ = AxisId::x(0);
+    }
+
+    #[test]
+    fn test_typed_modes() {
+        let layout = Layout::new()
+            .drag_mode(DragMode::False)
+            .click_mode(ClickMode::EVENT | ClickMode::SELECT)
+            .select_direction(SelectDirection::Horizontal);
+        let json = serde_json::to_value(layout).unwrap();
+        assert_eq!(
+            json,
+            json!({"clickmode": "event+select", "dragmode": false, "selectdirection": "h"})
+        );
+
+        let modes = vec![
+            ClickMode::NONE,
+            ClickMode::SELECT,
+            ClickMode::raw("event+all"),
+        ];
+        assert_eq!(
+            serde_json::to_value(modes).unwrap(),
+            json!(["none", "select", "event+all"])
+        );
+        let json = serde_json::to_value(Layout::new().drag_mode(DragMode::Raw("zoon".to_owned())))
+            .unwrap();
+        assert_eq!(json["dragmode"], json!("zoon"));
+
+        let axis = Axis::new()
+            .ticks(TicksDirection::None)
+            .spike_mode(SpikeMode::TO_AXIS | SpikeMode::ACROSS | SpikeMode::MARKER)
+            .show_exponent(ArrayShow::Raw("every".to_owned()));
+        let json = serde_json::to_value(axis).unwrap();
+        assert_eq!(
+            json,
+            json!({"ticks": "", "spikemode": "toaxis+across+marker", "showexponent": "every"})
+        );
+    }
+
+    #[test]
+    fn test_shape_path() {
+        let path = ShapePath::new()
+            .move_to("2020-01-01", 1.5)
+            .horizontal_to("2020-02-01")
+            .vertical_to(3)
+            .smooth_cubic_to(1, 2, 3, 4)
+            .smooth_quadratic_to(5, 6)
+            .close();
+        assert_eq!(
+            serde_json::to_value(path).unwrap(),
+            json!("M 2020-01-01,1.5 H 2020-02-01 V 3 S 1,2 3,4 T 5,6 Z")
+        );
+        let raw = ShapePath::raw("M 0,0 L 1,1");
+        assert_eq!(serde_json::to_value(raw).unwrap(), json!("M 0,0 L 1,1"));
+        let date_times = ShapePath::new()
+            .move_to("2015-02-21 13:45:56", 1)
+            .line_to("2015-02-22 08:00:00.5", 2);
+        assert_eq!(
+            serde_json::to_value(date_times).unwrap(),
+            json!("M 2015-02-21_13:45:56,1 L 2015-02-22_08:00:00.5,2")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "contains a comma")]
+    fn test_shape_path_comma() {
+        ShapePath::new().move_to("a,b", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis indices start at 1")]
+    fn test_axis_index_zero() {
+        Layout::new().x_axis_n(0, Axis::new());
+    }
+}
@@ -10,39 +10,82 @@ extern crate serde;
 
 pub mod ndarray;
 
+pub mod hierarchy;
 pub mod layout;
 pub mod plot;
+pub mod subplots;
+pub mod template;
+pub mod validation;
 
 pub mod bar;
+pub mod bar_polar;
 pub mod box_plot;
 pub mod candlestick;
 pub mod common;
+pub mod cone;
 pub mod contour;
 pub mod heat_map;
 pub mod histogram;
+pub mod histogram2d;
+pub mod histogram2d_contour;
+pub mod isosurface;
+pub mod mesh3d;
 pub mod ohlc;
+pub mod pie;
 pub mod scatter;
+pub mod scatter3d;
+pub mod scatter_polar;
+pub mod scatter_polar_gl;
+pub mod streamtube;
+pub mod sunburst;
 pub mod surface;
+pub mod treemap;
+pub mod violin;
+pub mod volume;
+pub mod waterfall;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use crate::layout::Layout;
+pub use crate::plot::IdMode;
 pub use crate::plot::ImageFormat;
 pub use crate::plot::Plot;
+pub use crate::subplots::Subplots;
+pub use crate::template::Template;
 
 pub use crate::bar::Bar;
+pub use crate::bar_polar::BarPolar;
 pub use crate::box_plot::BoxPlot;
 pub use crate::candlestick::Candlestick;
+pub use crate::cone::Cone;
 pub use crate::contour::Contour;
 pub use crate::heat_map::HeatMap;
 pub use crate::histogram::Histogram;
+pub use crate::histogram2d::Histogram2d;
+pub use crate::histogram2d_contour::Histogram2dContour;
+pub use crate::isosurface::Isosurface;
+pub use crate::mesh3d::Mesh3D;
 pub use crate::ohlc::Ohlc;
+pub use crate::pie::Pie;
 pub use crate::scatter::Scatter;
+pub use crate::scatter3d::Scatter3D;
+pub use crate::scatter_polar::ScatterPolar;
+pub use crate::scatter_polar_gl::ScatterPolarGL;
+pub use crate::streamtube::Streamtube;
+pub use crate::sunburst::Sunburst;
 pub use crate::surface::Surface;
+pub use crate::treemap::Treemap;
+pub use crate::violin::Violin;
+pub use crate::volume::Volume;
+pub use crate::waterfall::Waterfall;
 
 pub use crate::common::color::NamedColor;
 pub use crate::common::color::Rgb;
 pub use crate::common::color::Rgba;
 
 pub use crate::plot::Trace;
+pub use crate::validation::Diagnostic;
 
 #[cfg(feature = "plotly_ndarray")]
 pub use crate::ndarray::ArrayTraces;
@@ -0,0 +1,460 @@
+//! 3D mesh
+
+use crate::common::color::ColorWrapper;
+use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType};
+use crate::layout::SceneId;
+use crate::surface::{Lighting, Position};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+/// Axis along which the Delaunay triangulation of the points is computed when no faces are given.
+#[derive(Serialize, Clone, Debug)]
+pub enum DelaunayAxis {
+    #[serde(rename = "x")]
+    X,
+    #[serde(rename = "y")]
+    Y,
+    #[serde(rename = "z")]
+    Z,
+}
+
+/// Whether the `intensity` values are given per vertex or per face.
+#[derive(Serialize, Clone, Debug)]
+pub enum IntensityMode {
+    #[serde(rename = "vertex")]
+    Vertex,
+    #[serde(rename = "cell")]
+    Cell,
+}
+
+/// The edges of the faces.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Contour {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+}
+
+impl Contour {
+    pub fn new() -> Contour {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Mesh3D<X, Y, Z> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    z: Vec<Z>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    /// Indices of the first vertex of every face. Without `i`, `j` and `k` the faces are computed
+    /// with the `alpha_hull` or `delaunay_axis`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    i: Option<Vec<usize>>,
+    /// Indices of the second vertex of every face.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    j: Option<Vec<usize>>,
+    /// Indices of the third vertex of every face.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    k: Option<Vec<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// The scene the mesh is drawn in, `SceneId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<SceneId>,
+    /// With -1 the faces are the Delaunay triangulation along the `delaunay_axis`, with 0 the
+    /// convex hull of the points, and with a positive value the alpha shape of that alpha.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "alphahull")]
+    alpha_hull: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "delaunayaxis")]
+    delaunay_axis: Option<DelaunayAxis>,
+    /// Values mapped to colors with the `color_scale`, per vertex or per face depending on the
+    /// `intensity_mode`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    intensity: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "intensitymode")]
+    intensity_mode: Option<IntensityMode>,
+    /// Color of the whole mesh.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    /// Color of every vertex, interpolated over the faces. Takes precedence over `face_color`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "vertexcolor")]
+    vertex_color: Option<Vec<ColorWrapper>>,
+    /// Color of every face.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "facecolor")]
+    face_color: Option<Vec<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contour: Option<Contour>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lighting: Option<Lighting>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lightposition")]
+    light_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zcalendar")]
+    z_calendar: Option<Calendar>,
+}
+
+impl<X, Y, Z> Mesh3D<X, Y, Z> {
+    /// A mesh over the given vertices. The faces are set with `i`, `j` and `k`, or computed by
+    /// plotly.js otherwise.
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+        Z: Serialize + Default,
+    {
+        Mesh3D {
+            x,
+            y,
+            z,
+            r#type: PlotType::Mesh3D,
+            ..Default::default()
+        }
+    }
+}
+
+impl Mesh3D<f64, f64, f64> {
+    /// Reads an ASCII or binary STL file. Vertices shared by several facets are merged.
+    pub fn from_stl<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Mesh3D::from_stl_bytes(&fs::read(path)?)
+    }
+
+    /// Parses the content of an ASCII or binary STL file.
+    pub fn from_stl_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut builder = MeshBuilder::default();
+        if is_binary_stl(bytes) {
+            for facet in bytes[84..].chunks_exact(50) {
+                let mut triangle = [0; 3];
+                for (n, vertex) in triangle.iter_mut().enumerate() {
+                    let mut point = [0.; 3];
+                    for (c, coordinate) in point.iter_mut().enumerate() {
+                        let offset = 12 + 12 * n + 4 * c;
+                        let mut le_bytes = [0; 4];
+                        le_bytes.copy_from_slice(&facet[offset..offset + 4]);
+                        *coordinate = f32::from_le_bytes(le_bytes) as f64;
+                    }
+                    *vertex = builder.merged_vertex(point);
+                }
+                builder.faces.push(triangle);
+            }
+        } else {
+            let text = std::str::from_utf8(bytes)
+                .map_err(|_| invalid_data("neither a binary nor an ASCII STL file".to_owned()))?;
+            if !text.trim_start().starts_with("solid") {
+                return Err(invalid_data(
+                    "neither a binary nor an ASCII STL file".to_owned(),
+                ));
+            }
+            let mut triangle = Vec::with_capacity(3);
+            for (number, line) in text.lines().enumerate() {
+                let mut tokens = line.split_whitespace();
+                match tokens.next() {
+                    Some("vertex") => {
+                        let point = parse_point(tokens, number)?;
+                        triangle.push(builder.merged_vertex(point));
+                    }
+                    Some("endfacet") => {
+                        if triangle.len() != 3 {
+                            return Err(invalid_data(format!(
+                                "line {}: facet with {} vertices",
+                                number + 1,
+                                triangle.len()
+                            )));
+                        }
+                        builder.faces.push([triangle[0], triangle[1], triangle[2]]);
+                        triangle.clear();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(builder.build())
+    }
+
+    /// Reads a Wavefront OBJ file. Only the vertices and faces are used, polygons are split into
+    /// triangles fanning out from their first vertex.
+    pub fn from_obj<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Mesh3D::from_obj_str(&fs::read_to_string(path)?)
+    }
+
+    /// Parses the content of a Wavefront OBJ file.
+    pub fn from_obj_str(obj: &str) -> Result<Self> {
+        let mut builder = MeshBuilder::default();
+        for (number, line) in obj.lines().enumerate() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let point = parse_point(tokens, number)?;
+                    builder.vertices.push(point);
+                }
+                Some("f") => {
+                    let polygon = tokens
+                        .map(|token| parse_obj_index(token, builder.vertices.len(), number))
+                        .collect::<Result<Vec<usize>>>()?;
+                    if polygon.len() < 3 {
+                        return Err(invalid_data(format!(
+                            "line {}: face with {} vertices",
+                            number + 1,
+                            polygon.len()
+                        )));
+                    }
+                    for n in 1..polygon.len() - 1 {
+                        builder.faces.push([polygon[0], polygon[n], polygon[n + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(builder.build())
+    }
+}
+
+impl<X, Y, Z> Trace for Mesh3D<X, Y, Z>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[derive(Default)]
+struct MeshBuilder {
+    vertices: Vec<[f64; 3]>,
+    faces: Vec<[usize; 3]>,
+    merged: HashMap<[u64; 3], usize>,
+}
+
+impl MeshBuilder {
+    fn merged_vertex(&mut self, point: [f64; 3]) -> usize {
+        let key = [point[0].to_bits(), point[1].to_bits(), point[2].to_bits()];
+        let vertices = &mut self.vertices;
+        *self.merged.entry(key).or_insert_with(|| {
+            vertices.push(point);
+            vertices.len() - 1
+        })
+    }
+
+    fn build(self) -> Mesh3D<f64, f64, f64> {
+        let x = self.vertices.iter().map(|v| v[0]).collect();
+        let y = self.vertices.iter().map(|v| v[1]).collect();
+        let z = self.vertices.iter().map(|v| v[2]).collect();
+        Mesh3D::new(x, y, z)
+            .i(self.faces.iter().map(|f| f[0]).collect())
+            .j(self.faces.iter().map(|f| f[1]).collect())
+            .k(self.faces.iter().map(|f| f[2]).collect())
+    }
+}
+
+/// A binary STL file has an 80 bytes header, the number of facets and 50 bytes per facet. ASCII
+/// files start with `solid`, but so do the headers of some binary files.
+fn is_binary_stl(bytes: &[u8]) -> bool {
+    if bytes.len() < 84 {
+        return false;
+    }
+    let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+    bytes.len() == 84 + 50 * count
+}
+
+fn parse_point<'a, I: Iterator<Item = &'a str>>(tokens: I, number: usize) -> Result<[f64; 3]> {
+    let coordinates = tokens
+        .take(3)
+        .map(|token| token.parse::<f64>())
+        .collect::<std::result::Result<Vec<f64>, _>>()
+        .map_err(|e| invalid_data(format!("line {}: {}", number + 1, e)))?;
+    if coordinates.len() != 3 {
+        return Err(invalid_data(format!(
+            "line {}: expected 3 coordinates",
+            number + 1
+        )));
+    }
+    Ok([coordinates[0], coordinates[1], coordinates[2]])
+}
+
+/// OBJ indices start at 1, negative ones count back from the last vertex read so far. Texture and
+/// normal indices, as in `1/2/3`, are ignored.
+fn parse_obj_index(token: &str, vertex_count: usize, number: usize) -> Result<usize> {
+    let index: i64 = token
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .parse()
+        .map_err(|e| invalid_data(format!("line {}: {}", number + 1, e)))?;
+    let resolved = if index < 0 {
+        vertex_count as i64 + index
+    } else {
+        index - 1
+    };
+    if resolved < 0 || resolved >= vertex_count as i64 {
+        return Err(invalid_data(format!(
+            "line {}: vertex index {} out of range",
+            number + 1,
+            index
+        )));
+    }
+    Ok(resolved as usize)
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_mesh3d() {
+        let mesh = Mesh3D::new(vec![0, 1, 0, 0], vec![0, 0, 1, 0], vec![0, 0, 0, 1])
+            .i(vec![0, 0, 0, 1])
+            .j(vec![1, 2, 3, 2])
+            .k(vec![2, 3, 1, 3])
+            .intensity(vec![0., 1., 2., 3.])
+            .intensity_mode(IntensityMode::Vertex)
+            .flat_shading(true)
+            .lighting(Lighting::new().ambient(0.5))
+            .light_position(Position::new(10, 10, 0))
+            .contour(Contour::new().show(true).width(2.))
+            .scene(SceneId::new(1));
+        let expected = json!({
+            "x": [0, 1, 0, 0],
+            "y": [0, 0, 1, 0],
+            "z": [0, 0, 0, 1],
+            "type": "mesh3d",
+            "i": [0, 0, 0, 1],
+            "j": [1, 2, 3, 2],
+            "k": [2, 3, 1, 3],
+            "scene": "scene",
+            "intensity": [0., 1., 2., 3.],
+            "intensitymode": "vertex",
+            "flatshading": true,
+            "contour": {"show": true, "width": 2.},
+            "lighting": {"ambient": 0.5},
+            "lightposition": {"x": 10, "y": 10, "z": 0},
+        });
+        assert_trace_json(&mesh, expected);
+    }
+
+    #[test]
+    fn test_load_meshes() {
+        let ascii = "solid square
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 1 1 0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 1 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid square
+";
+        let expected = json!({
+            "x": [0., 1., 1., 0.],
+            "y": [0., 0., 1., 1.],
+            "z": [0., 0., 0., 0.],
+            "type": "mesh3d",
+            "i": [0, 0],
+            "j": [1, 2],
+            "k": [2, 3],
+        });
+        let mesh = Mesh3D::from_stl_bytes(ascii.as_bytes()).unwrap();
+        assert_eq!(to_value(&mesh).unwrap(), expected);
+
+        let mut binary = b"solid header of a binary file".to_vec();
+        binary.resize(80, 0);
+        binary.extend_from_slice(&2u32.to_le_bytes());
+        let triangles = [
+            [0., 0., 0., 1., 0., 0., 1., 1., 0.],
+            [0., 0., 0., 1., 1., 0., 0., 1., 0.],
+        ];
+        for triangle in triangles.iter() {
+            binary.extend_from_slice(&[0; 12]);
+            for coordinate in triangle.iter() {
+                binary.extend_from_slice(&(*coordinate as f32).to_le_bytes());
+            }
+            binary.extend_from_slice(&[0; 2]);
+        }
+        let mesh = Mesh3D::from_stl_bytes(&binary).unwrap();
+        assert_eq!(to_value(&mesh).unwrap(), expected);
+
+        let obj = "# square
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+f 1/1/1 2/2/1 3/3/1 -1/4/1
+";
+        let mesh = Mesh3D::from_obj_str(obj).unwrap();
+        assert_eq!(to_value(&mesh).unwrap(), expected);
+
+        let error = Mesh3D::from_obj_str("v 0 0 0\nf 1 2 3\n").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "line 2: vertex index 2 out of range");
+        assert!(Mesh3D::from_stl_bytes(b"not a mesh").is_err());
+    }
+}
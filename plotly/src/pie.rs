@@ -0,0 +1,206 @@
+//! Pie chart
+
+use crate::common::color::ColorWrapper;
+use crate::common::{
+    Dim, Domain, FlagNames, Flags, Font, InsideTextOrientation, Label, PlotType, TextPosition,
+};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+pub use crate::common::RotationDirection as Direction;
+
+/// Flags of `Pie::text_info`, combined with `|`, e.g. `TextInfo::LABEL | TextInfo::PERCENT`.
+pub type TextInfo = Flags<TextInfoNames>;
+
+/// The flag names of `TextInfo`.
+pub enum TextInfoNames {}
+
+impl FlagNames for TextInfoNames {
+    const NAMES: &'static [&'static str] = &["label", "text", "value", "percent"];
+    const EMPTY: &'static str = "none";
+}
+
+impl TextInfo {
+    /// No text on the sectors.
+    pub const NONE: TextInfo = Flags::empty();
+    pub const LABEL: TextInfo = Flags::flag(0);
+    pub const TEXT: TextInfo = Flags::flag(1);
+    pub const VALUE: TextInfo = Flags::flag(2);
+    pub const PERCENT: TextInfo = Flags::flag(3);
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct MarkerLine {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Dim<f64>>,
+}
+
+impl MarkerLine {
+    pub fn new() -> MarkerLine {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Marker {
+    /// Colors of the sectors, in the order of the labels. Sectors without a color take the next
+    /// color of `Layout::pie_colorway`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<Vec<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<MarkerLine>,
+}
+
+impl Marker {
+    pub fn new() -> Marker {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Pie<L, V> {
+    #[field_setter(skip)]
+    labels: Vec<L>,
+    #[field_setter(skip)]
+    values: Vec<V>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    /// Fraction of the radius cut out of the pie, between 0 and 1. A hole makes a donut chart.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hole: Option<f64>,
+    /// Fraction of the radius by which sectors are pulled out of the pie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pull: Option<Dim<f64>>,
+    /// Whether the sectors are sorted from largest to smallest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<Direction>,
+    /// Angle in degrees at which the first sector starts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    /// Pies with the same scale group are sized by their total value.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalegroup")]
+    scale_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "insidetextorientation"
+    )]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "automargin")]
+    auto_margin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<L, V> Pie<L, V> {
+    pub fn new(labels: Vec<L>, values: Vec<V>) -> Self
+    where
+        L: Serialize + Default,
+        V: Serialize + Default,
+    {
+        Pie {
+            labels,
+            values,
+            r#type: PlotType::Pie,
+            ..Default::default()
+        }
+    }
+}
+
+impl<L, V> Trace for Pie<L, V>
+where
+    L: Serialize,
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_pie() {
+        let pie = Pie::new(vec!["a", "b", "c"], vec![3, 2, 1])
+            .hole(0.4)
+            .pull_array(vec![0., 0.1, 0.])
+            .sort(false)
+            .direction(Direction::Clockwise)
+            .rotation(90.)
+            .text_info(TextInfo::LABEL | TextInfo::PERCENT)
+            .inside_text_orientation(InsideTextOrientation::Radial)
+            .domain(Domain::new().row(0).column(1))
+            .marker(
+                Marker::new()
+                    .colors(vec!["red", "green", "blue"])
+                    .line(MarkerLine::new().color("white").width(2.)),
+            );
+        let expected = json!({
+            "labels": ["a", "b", "c"],
+            "values": [3, 2, 1],
+            "type": "pie",
+            "domain": {"row": 0, "column": 1},
+            "hole": 0.4,
+            "pull": [0., 0.1, 0.],
+            "sort": false,
+            "direction": "clockwise",
+            "rotation": 90.,
+            "marker": {"colors": ["red", "green", "blue"], "line": {"color": "white", "width": 2.}},
+            "textinfo": "label+percent",
+            "insidetextorientation": "radial",
+        });
+        assert_trace_json(&pie, expected);
+
+        let pie = Pie::new(vec!["a"], vec![1]).text_info(TextInfo::NONE);
+        assert_eq!(to_value(&pie).unwrap()["textinfo"], json!("none"));
+    }
+
+    #[test]
+    fn test_new_pie() {
+        let pie = Pie::new(vec!["a", "b"], vec![1., 2.]);
+        let expected = json!({"labels": ["a", "b"], "values": [1., 2.], "type": "pie"});
+        assert_eq!(to_value(&pie).unwrap(), expected);
+    }
+}
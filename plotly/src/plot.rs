@@ -9,6 +9,8 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::template;
+use crate::validation::{self, Diagnostic};
 use crate::Layout;
 use rand_distr::Alphanumeric;
 
@@ -54,19 +56,69 @@ pub enum ImageFormat {
     EPS,
 }
 
+impl ImageFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::PNG => "png",
+            ImageFormat::JPEG => "jpeg",
+            ImageFormat::SVG => "svg",
+            ImageFormat::PDF => "pdf",
+            ImageFormat::EPS => "eps",
+            ImageFormat::WEBP => "webp",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::PNG => "image/png",
+            ImageFormat::JPEG => "image/jpeg",
+            ImageFormat::SVG => "image/svg+xml",
+            ImageFormat::PDF => "application/pdf",
+            ImageFormat::EPS => "application/postscript",
+            ImageFormat::WEBP => "image/webp",
+        }
+    }
+}
+
+/// Determines how the ids of the plot `div` element and the names of temporary html files are
+/// generated when rendering a `Plot`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdMode {
+    /// A new random id is generated on every render. This is the default.
+    Random,
+    /// The id is derived from a hash of the serialized `Trace`s and `Layout`, hence rendering the
+    /// same plot always produces the same output.
+    ContentHash,
+    /// The id is derived from the supplied seed and a hash of the serialized `Trace`s and
+    /// `Layout`, hence the output is reproducible and plots that share a seed get different ids
+    /// unless their content is identical.
+    Seed(u64),
+}
+
+impl Default for IdMode {
+    fn default() -> Self {
+        IdMode::Random
+    }
+}
 
 /// A struct that implements `Trace` can be serialized to json format that is understood by Plotly.js.
 pub trait Trace {
     fn serialize(&self) -> String;
 }
 
+impl<T: Trace + ?Sized> Trace for Box<T> {
+    fn serialize(&self) -> String {
+        (**self).serialize()
+    }
+}
+
 /// Plot is a container for structs that implement the `Trace` trait. Optionally a `Layout` can
 /// also be specified. Its function is to serialize `Trace`s and the `Layout` in html format and
 /// display and/or persist the resulting plot.
 ///
 /// # Examples
 ///
-/// ```
+/// ```no_run
 /// extern crate plotly;
 /// use plotly::common::Mode;
 /// use plotly::{Plot, Scatter};
@@ -97,6 +149,8 @@ pub struct Plot {
     traces: Vec<Box<dyn Trace>>,
     layout: Option<Layout>,
     remote_plotly_js: bool,
+    id_mode: IdMode,
+    color_scheme_templates: Option<(template::Template, template::Template)>,
 }
 
 const DEFAULT_HTML_APP_NOT_FOUND: &str = r#"Could not find default application for HTML files.
@@ -120,7 +174,6 @@ plot.save("filename", ImageFormat::PNG, width, height, scale);
 See https://igiagkiozis.github.io/plotly/content/getting_started.html for further details.
 "#;
 
-
 impl Plot {
     /// Create a new `Plot`.
     pub fn new() -> Plot {
@@ -137,16 +190,43 @@ impl Plot {
         self.remote_plotly_js = false;
     }
 
-    /// Add a `Trace` to the `Plot`.
-    pub fn add_trace(&mut self, trace: Box<dyn Trace>) {
-        self.traces.push(trace);
+    /// Sets how div ids and temporary file names are generated. Use `IdMode::ContentHash` or
+    /// `IdMode::Seed` to make the html output of the `Plot` reproducible.
+    pub fn set_id_mode(&mut self, id_mode: IdMode) {
+        self.id_mode = id_mode;
+    }
+
+    /// Sets the templates used when the browser prefers a light and a dark color scheme. The html
+    /// output picks one of them through the `prefers-color-scheme` media query and switches to the
+    /// other one when the preference changes. The templates take precedence over the template of
+    /// the `Layout`; `Plot::to_json` and static images use the light template.
+    ///
+    /// ```
+    /// use plotly::template::{PLOTLY_DARK, PLOTLY_WHITE};
+    /// use plotly::{Plot, Scatter};
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_trace(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]));
+    /// plot.set_color_scheme_templates(PLOTLY_WHITE, PLOTLY_DARK);
+    /// assert!(plot.to_inline_html("plot").contains("prefers-color-scheme: dark"));
+    /// ```
+    pub fn set_color_scheme_templates<L, D>(&mut self, light: L, dark: D)
+    where
+        L: Into<template::Template>,
+        D: Into<template::Template>,
+    {
+        self.color_scheme_templates = Some((light.into(), dark.into()));
+    }
+
+    /// Add a `Trace` to the `Plot`. Both traces and boxed traces, e.g. the output of
+    /// `Scatter::to_traces`, are accepted.
+    pub fn add_trace<T: Trace + 'static>(&mut self, trace: T) {
+        self.traces.push(Box::new(trace));
     }
 
     /// Add multiple `Trace`s to the `Plot`.
     pub fn add_traces(&mut self, traces: Vec<Box<dyn Trace>>) {
-        for trace in traces {
-            self.add_trace(trace);
-        }
+        self.traces.extend(traces);
     }
 
     /// Set the `Layout` to be used by `Plot`.
@@ -164,10 +244,7 @@ impl Plot {
         let rendered = rendered.as_bytes();
         let mut temp = env::temp_dir();
 
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
+        let mut plot_name = self.generate_id(22);
         plot_name.push_str(".html");
         plot_name = format!("plotly_{}", plot_name);
 
@@ -191,10 +268,7 @@ impl Plot {
         let rendered = rendered.as_bytes();
         let mut temp = env::temp_dir();
 
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
+        let mut plot_name = self.generate_id(22);
         plot_name.push_str(".html");
 
         temp.push(plot_name);
@@ -217,10 +291,7 @@ impl Plot {
         let rendered = rendered.as_bytes();
         let mut temp = env::temp_dir();
 
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
+        let mut plot_name = self.generate_id(22);
         plot_name.push_str(".html");
 
         temp.push(plot_name);
@@ -253,22 +324,22 @@ impl Plot {
     /// html page that this element is embedded. If that assumption is violated then the plot will
     /// not be displayed.
     ///
-    /// If `plot_div_id` is `None` the plot div id will be randomly generated, otherwise the user
-    /// supplied div id is used.
+    /// If `plot_div_id` is `None` the plot div id will be generated according to the `IdMode` of
+    /// the `Plot` (random by default), otherwise the user supplied div id is used.
     pub fn to_inline_html<T: Into<Option<&'static str>>>(&self, plot_div_id: T) -> String {
         let plot_div_id = plot_div_id.into();
         match plot_div_id {
             Some(id) => self.render_inline(id.as_ref()),
             None => {
-                let rand_id: String = thread_rng().sample_iter(&Alphanumeric).take(20).collect();
-                self.render_inline(rand_id.as_str())
+                let plot_div_id = self.generate_id(20);
+                self.render_inline(plot_div_id.as_str())
             }
         }
     }
 
     fn to_jupyter_notebook_html(&self) -> String {
-        let plot_div_id: String = thread_rng().sample_iter(&Alphanumeric).take(20).collect();
-        let plot_data = self.render_plot_data();
+        let plot_div_id = self.generate_id(20);
+        let plot_data = self.render_plot_data(plot_div_id.as_str());
 
         let tmpl = JupyterNotebookPlotTemplate {
             plot_data: plot_data.as_str(),
@@ -280,7 +351,10 @@ impl Plot {
     /// Display plot in Jupyter Notebook.
     pub fn notebook_display(&self) {
         let plot_data = self.to_jupyter_notebook_html();
-        println!("EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT", plot_data);
+        println!(
+            "EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT",
+            plot_data
+        );
     }
 
     /// Display plot in Jupyter Lab.
@@ -292,10 +366,61 @@ impl Plot {
         );
     }
 
-    /// Displays the plot in Jupyter Lab; if running a Jupyter Notebook then use the
-    /// `notebook_display()` method instead.
+    /// Displays the plot in any Jupyter frontend running the EvCxR kernel.
+    ///
+    /// A single MIME bundle is emitted containing both the plotly.js json (rendered by Jupyter Lab
+    /// and VS Code) and the html representation (rendered by the classic Jupyter Notebook), so the
+    /// frontend picks the richest representation it supports. Use `evcxr_display_with_image` to
+    /// also include a static image for viewers that don't execute javascript, e.g. nbviewer.
     pub fn evcxr_display(&self) {
-        self.lab_display();
+        println!("{}", self.to_evcxr_bundle(None));
+    }
+
+    /// Same as `evcxr_display` with the addition of a static image, rendered with Kaleido, in the
+    /// emitted MIME bundle. If the image cannot be rendered the interactive representations are
+    /// still displayed.
+    #[cfg(feature = "kaleido")]
+    pub fn evcxr_display_with_image(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) {
+        let kaleido = plotly_kaleido::Kaleido::new();
+        let image_data = kaleido
+            .image_data(
+                self.to_json().as_str(),
+                format.extension(),
+                width,
+                height,
+                scale,
+            )
+            .ok()
+            .flatten();
+        let image = image_data
+            .as_ref()
+            .map(|data| (format.mime_type(), data.as_str()));
+        println!("{}", self.to_evcxr_bundle(image));
+    }
+
+    fn to_evcxr_bundle(&self, image: Option<(&str, &str)>) -> String {
+        let mut bundle = String::new();
+        let mut push_content = |mime_type: &str, content: &str| {
+            bundle.push_str(
+                format!(
+                    "EVCXR_BEGIN_CONTENT {}\n{}\nEVCXR_END_CONTENT\n",
+                    mime_type, content
+                )
+                .as_str(),
+            );
+        };
+        push_content("application/vnd.plotly.v1+json", self.to_json().as_str());
+        push_content("text/html", self.to_jupyter_notebook_html().as_str());
+        if let Some((mime_type, data)) = image {
+            push_content(mime_type, data);
+        }
+        bundle
     }
 
     /// Saves the `Plot` to the selected image format.
@@ -310,14 +435,7 @@ impl Plot {
     ) {
         let kaleido = plotly_kaleido::Kaleido::new();
         let plot_data = self.to_json();
-        let image_format = match format {
-            ImageFormat::PNG => "png",
-            ImageFormat::JPEG => "jpeg",
-            ImageFormat::SVG => "svg",
-            ImageFormat::PDF => "pdf",
-            ImageFormat::EPS => "eps",
-            ImageFormat::WEBP => "webp",
-        };
+        let image_format = format.extension();
         kaleido
             .save(
                 filename.as_ref(),
@@ -330,13 +448,79 @@ impl Plot {
             .unwrap_or_else(|_| panic!("failed to export plot to {:?}", filename.as_ref()));
     }
 
+    fn generate_id(&self, length: usize) -> String {
+        let seed = match &self.id_mode {
+            IdMode::Random => {
+                return thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(length)
+                    .collect();
+            }
+            IdMode::ContentHash => fnv1a_hash(self.to_json().as_bytes()),
+            IdMode::Seed(seed) => {
+                let mut bytes = seed.to_le_bytes().to_vec();
+                bytes.extend_from_slice(self.to_json().as_bytes());
+                fnv1a_hash(&bytes)
+            }
+        };
+        deterministic_id(seed, length)
+    }
+
     fn plotly_js_path() -> PathBuf {
         let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
         let templates = root.join("templates");
         templates.join(PLOTLY_JS)
     }
 
-    fn render_plot_data(&self) -> String {
+    fn serialize_layout(&self) -> String {
+        let layout = match &self.layout {
+            Some(layout) => Trace::serialize(layout),
+            None => "{}".to_owned(),
+        };
+        match &self.color_scheme_templates {
+            Some((light, _)) => template::replace_template(layout, Some(light)),
+            None => template::apply_default_template(layout),
+        }
+    }
+
+    /// Declares the layout, with the template matching the preferred color scheme of the browser
+    /// when color scheme templates are set, and relayouts the plot when the preference changes.
+    fn render_layout(&self, plot_div_id: &str) -> String {
+        let (light, dark) = match &self.color_scheme_templates {
+            Some(templates) => templates,
+            None => return format!("var layout = {};", self.serialize_layout()),
+        };
+        let layout = match &self.layout {
+            Some(layout) => template::replace_template(Trace::serialize(layout), None),
+            None => "{}".to_owned(),
+        };
+        format!(
+            r#"var layout = {layout};
+(function(light, dark) {{
+    var darkScheme = window.matchMedia ? window.matchMedia("(prefers-color-scheme: dark)") : null;
+    var schemeTemplate = function() {{
+        return darkScheme && darkScheme.matches ? dark : light;
+    }};
+    layout.template = schemeTemplate();
+    if (darkScheme) {{
+        var relayout = function() {{
+            Plotly.relayout("{id}", {{template: schemeTemplate()}});
+        }};
+        if (darkScheme.addEventListener) {{
+            darkScheme.addEventListener("change", relayout);
+        }} else {{
+            darkScheme.addListener(relayout);
+        }}
+    }}
+}})({light}, {dark});"#,
+            layout = layout,
+            id = plot_div_id,
+            light = light.to_json(),
+            dark = dark.to_json(),
+        )
+    }
+
+    fn render_plot_data(&self, plot_div_id: &str) -> String {
         let mut plot_data = String::new();
         for (idx, trace) in self.traces.iter().enumerate() {
             let s = trace.serialize();
@@ -352,15 +536,7 @@ impl Plot {
             }
         }
         plot_data.push_str("];\n");
-        let layout_data = match &self.layout {
-            Some(layout) => format!("var layout = {};", Trace::serialize(layout)),
-            None => {
-                let mut s = String::from("var layout = {");
-                s.push_str("};");
-                s
-            }
-        };
-        plot_data.push_str(layout_data.as_str());
+        plot_data.push_str(self.render_layout(plot_div_id).as_str());
         plot_data
     }
 
@@ -371,7 +547,7 @@ impl Plot {
         image_width: usize,
         image_height: usize,
     ) -> String {
-        let plot_data = self.render_plot_data();
+        let plot_data = self.render_plot_data("plotly-html-element");
         let plotly_js = PlotlyJs {}.render().unwrap();
         let tmpl = PlotTemplate {
             plot_data: plot_data.as_str(),
@@ -386,7 +562,7 @@ impl Plot {
     }
 
     fn render_inline(&self, plot_div_id: &str) -> String {
-        let plot_data = self.render_plot_data();
+        let plot_data = self.render_plot_data(plot_div_id);
 
         let tmpl = InlinePlotTemplate {
             plot_data: plot_data.as_str(),
@@ -401,28 +577,63 @@ impl Plot {
             let s = trace.serialize();
             plot_data.push(s);
         }
-        let layout_data = match &self.layout {
-            Some(layout) => Trace::serialize(layout),
-            None => "{}".to_owned(),
-        };
+        let layout_data = self.serialize_layout();
 
         let mut json_data = String::new();
         json_data.push_str(r#"{"data": ["#);
-
-        for (index, data) in plot_data.iter().enumerate() {
-            if index < plot_data.len() - 1 {
-                json_data.push_str(data);
-                json_data.push_str(r#","#);
-            } else {
-                json_data.push_str(data);
-                json_data.push_str("]");
-            }
-        }
+        json_data.push_str(plot_data.join(",").as_str());
+        json_data.push_str("]");
         json_data.push_str(format!(r#", "layout": {}"#, layout_data).as_str());
         json_data.push_str("}");
         json_data
     }
 
+    /// Checks every serialized trace and layout attribute against the plotly.js schema bundled
+    /// with the crate and reports unknown attributes, values of the wrong type, invalid enumerated
+    /// values and numbers out of range. Validation works offline; an empty result means plotly.js
+    /// will accept all attributes of the `Plot` as given.
+    ///
+    /// ```
+    /// use plotly::layout::DragMode;
+    /// use plotly::{Layout, Plot};
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.set_layout(Layout::new().drag_mode(DragMode::Raw("zoon".to_owned())));
+    /// let diagnostics = plot.validate();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].attribute, "dragmode");
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let figure: serde_json::Value =
+            serde_json::from_str(self.to_json().as_str()).expect("plot serialized to invalid json");
+        validation::validate_figure(&figure)
+    }
+
+    /// Reports inconsistencies between attributes that the plotly.js schema cannot catch and that
+    /// plotly.js renders silently as broken plots: traces or axes referring to axes that are not
+    /// defined in the `Layout`, x and y data of different lengths, `Marker` size arrays shorter
+    /// than the data and precomputed `BoxPlot` statistics of different lengths.
+    ///
+    /// ```
+    /// use plotly::layout::YAxisId;
+    /// use plotly::{Plot, Scatter};
+    ///
+    /// let trace = Scatter::new(vec![1, 2, 3], vec![4, 5]).y_axis(YAxisId::new(2));
+    /// let mut plot = Plot::new();
+    /// plot.add_trace(trace);
+    ///
+    /// let diagnostics = plot.lint();
+    /// assert_eq!(diagnostics.len(), 2);
+    /// assert_eq!(diagnostics[0].trace, Some(0));
+    /// assert_eq!(diagnostics[0].attribute, "yaxis");
+    /// assert_eq!(diagnostics[1].attribute, "y");
+    /// ```
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        let figure: serde_json::Value =
+            serde_json::from_str(self.to_json().as_str()).expect("plot serialized to invalid json");
+        validation::lint_figure(&figure)
+    }
+
     #[cfg(target_os = "linux")]
     fn show_with_default_app(temp_path: &str) {
         Command::new("xdg-open")
@@ -453,6 +664,34 @@ impl Plot {
     }
 }
 
+const ID_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+// FNV-1a is used instead of `DefaultHasher` as the latter is not guaranteed to produce the same
+// output across Rust releases.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+// Expands `seed` into an alphanumeric string using the splitmix64 sequence.
+fn deterministic_id(seed: u64, length: usize) -> String {
+    let mut state = seed;
+    (0..length)
+        .map(|_| {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+            ID_CHARSET[(z % ID_CHARSET.len() as u64) as usize] as char
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -465,6 +704,36 @@ mod tests {
         plot
     }
 
+    #[test]
+    fn test_validate() {
+        use crate::common::ColorBar;
+        use crate::contour::{Contour, Contours};
+        use crate::layout::{Axis, DragMode, UniformText, UniformTextMode};
+        use crate::validation::DiagnosticKind;
+
+        let mut plot = Plot::new();
+        plot.add_trace(
+            Contour::new_z(vec![vec![1, 2], vec![3, 4]])
+                .color_bar(ColorBar::new().tick_text(vec!["a".to_owned()]))
+                .contours(Contours::new().show_lines(false))
+                .n_contours(5),
+        );
+        plot.set_layout(
+            Layout::new()
+                .x_axis(Axis::new().auto_range(true).tick_text(vec!["a".to_owned()]))
+                .uniform_text(UniformText::new().mode(UniformTextMode::Hide).min_size(8))
+                .extend_sunburst_colors(true),
+        );
+        assert_eq!(plot.validate(), vec![]);
+
+        plot.set_layout(Layout::new().drag_mode(DragMode::Raw("zoon".to_owned())));
+        let diagnostics = plot.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].trace, None);
+        assert_eq!(diagnostics[0].attribute, "dragmode");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::InvalidValue);
+    }
+
     #[test]
     fn test_to_json() {
         let plot = create_test_plot();
@@ -482,6 +751,73 @@ mod tests {
         println!("{}", random_div_id);
     }
 
+    #[test]
+    fn test_content_hash_ids() {
+        let mut plot = create_test_plot();
+        plot.set_id_mode(IdMode::ContentHash);
+        let first = plot.to_inline_html(None);
+        assert_eq!(first, plot.to_inline_html(None));
+        assert_eq!(
+            plot.to_jupyter_notebook_html(),
+            plot.to_jupyter_notebook_html()
+        );
+
+        let mut other = Plot::new();
+        other.add_trace(Scatter::new(vec![0, 1, 2], vec![1, 1, 1]));
+        other.set_id_mode(IdMode::ContentHash);
+        assert_ne!(first, other.to_inline_html(None));
+    }
+
+    #[test]
+    fn test_seeded_ids() {
+        let mut plot = create_test_plot();
+        plot.set_id_mode(IdMode::Seed(42));
+        assert_eq!(plot.generate_id(20), plot.generate_id(20));
+        assert_eq!(plot.generate_id(20).len(), 20);
+        assert!(plot
+            .generate_id(20)
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric()));
+
+        let seeded = plot.generate_id(20);
+        plot.set_id_mode(IdMode::Seed(43));
+        assert_ne!(seeded, plot.generate_id(20));
+
+        let mut other = Plot::new();
+        other.add_trace(Scatter::new(vec![0, 1, 2], vec![1, 1, 1]));
+        other.set_id_mode(IdMode::Seed(43));
+        assert_ne!(plot.generate_id(20), other.generate_id(20));
+    }
+
+    #[test]
+    fn test_color_scheme_templates() {
+        use crate::template::{GGPLOT2, PLOTLY_DARK, PLOTLY_WHITE};
+
+        let mut plot = create_test_plot();
+        plot.set_layout(Layout::new().template(GGPLOT2));
+        plot.set_color_scheme_templates(PLOTLY_WHITE, PLOTLY_DARK);
+
+        let figure: serde_json::Value = serde_json::from_str(&plot.to_json()).unwrap();
+        let light = serde_json::to_value(PLOTLY_WHITE.template()).unwrap();
+        assert_eq!(figure["layout"]["template"], light);
+
+        let light = PLOTLY_WHITE.template().to_json();
+        let dark = PLOTLY_DARK.template().to_json();
+        let inline = plot.to_inline_html("themed_plot");
+        assert!(inline.contains(&format!("}})({}, {});", light, dark)));
+        assert!(inline.contains(r#"Plotly.relayout("themed_plot""#));
+        assert!(!inline.contains(&GGPLOT2.template().to_json()));
+        assert!(plot
+            .render(false, "", 0, 0)
+            .contains(r#"Plotly.relayout("plotly-html-element""#));
+
+        let notebook = plot.to_jupyter_notebook_html();
+        assert!(notebook.contains("prefers-color-scheme: dark"));
+        assert!(!create_test_plot()
+            .to_inline_html("plot")
+            .contains("prefers-color-scheme"));
+    }
+
     #[test]
     fn test_jupyter_notebook_plot() {
         let plot = create_test_plot();
@@ -501,6 +837,21 @@ mod tests {
         plot.lab_display();
     }
 
+    #[test]
+    fn test_evcxr_bundle() {
+        let plot = create_test_plot();
+        let bundle = plot.to_evcxr_bundle(None);
+        assert!(bundle.contains("EVCXR_BEGIN_CONTENT application/vnd.plotly.v1+json\n"));
+        assert!(bundle.contains("EVCXR_BEGIN_CONTENT text/html\n"));
+        assert_eq!(bundle.matches("EVCXR_END_CONTENT").count(), 2);
+
+        let bundle = plot.to_evcxr_bundle(Some((ImageFormat::SVG.mime_type(), "<svg></svg>")));
+        assert!(
+            bundle.contains("EVCXR_BEGIN_CONTENT image/svg+xml\n<svg></svg>\nEVCXR_END_CONTENT")
+        );
+        assert_eq!(bundle.matches("EVCXR_END_CONTENT").count(), 3);
+    }
+
     #[test]
     #[cfg(feature = "kaleido")]
     fn test_save_to_png() {
@@ -2,7 +2,7 @@ use crate::common::color::{Color, ColorWrapper};
 use serde::{Serialize, Serializer};
 
 #[cfg(feature = "plotly_ndarray")]
-use ndarray::{Array, Ix2};
+use ndarray::{Array, Ix1, Ix2, Ix3};
 #[cfg(feature = "plotly_ndarray")]
 use crate::ndarray::ArrayTraces;
 
@@ -163,3 +163,73 @@ where
 
     traces
 }
+
+/// Flattens the values of a 3 dimensional grid, indexed by the `x`, `y` and `z` coordinates, into
+/// vectors with the coordinates and value of every point, as plotly.js expects them.
+#[cfg(feature = "plotly_ndarray")]
+pub fn grid_vectors_from<X, Y, Z, V>(
+    x: Array<X, Ix1>,
+    y: Array<Y, Ix1>,
+    z: Array<Z, Ix1>,
+    values: Array<V, Ix3>,
+) -> (Vec<X>, Vec<Y>, Vec<Z>, Vec<V>)
+where
+    X: Clone,
+    Y: Clone,
+    Z: Clone,
+    V: Clone,
+{
+    assert_eq!(
+        values.shape(),
+        &[x.len(), y.len(), z.len()],
+        "the shape of the values must be the lengths of the x, y and z coordinates"
+    );
+    let count = values.len();
+    let mut xs = Vec::with_capacity(count);
+    let mut ys = Vec::with_capacity(count);
+    let mut zs = Vec::with_capacity(count);
+    for xi in x.iter() {
+        for yi in y.iter() {
+            for zi in z.iter() {
+                xs.push(xi.clone());
+                ys.push(yi.clone());
+                zs.push(zi.clone());
+            }
+        }
+    }
+    (xs, ys, zs, values.iter().cloned().collect())
+}
+
+/// Flattens the components of a vector field on a 3 dimensional grid, as `grid_vectors_from` does
+/// for a single value.
+#[cfg(feature = "plotly_ndarray")]
+pub fn grid_vector_field_from<X, Y, Z, U, V, W>(
+    x: Array<X, Ix1>,
+    y: Array<Y, Ix1>,
+    z: Array<Z, Ix1>,
+    u: Array<U, Ix3>,
+    v: Array<V, Ix3>,
+    w: Array<W, Ix3>,
+) -> (Vec<X>, Vec<Y>, Vec<Z>, Vec<U>, Vec<V>, Vec<W>)
+where
+    X: Clone,
+    Y: Clone,
+    Z: Clone,
+    U: Clone,
+    V: Clone,
+    W: Clone,
+{
+    assert!(
+        u.shape() == v.shape() && u.shape() == w.shape(),
+        "the u, v and w components must have the same shape"
+    );
+    let (xs, ys, zs, us) = grid_vectors_from(x, y, z, u);
+    (
+        xs,
+        ys,
+        zs,
+        us,
+        v.iter().cloned().collect(),
+        w.iter().cloned().collect(),
+    )
+}
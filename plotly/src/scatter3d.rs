@@ -0,0 +1,392 @@
+//! 3D scatter plot
+
+use crate::common::color::ColorWrapper;
+use crate::common::{
+    Calendar, ColorBar, ColorScale, DashType, Dim, ErrorData, Font, HoverInfo, Label, Mode,
+    PlotType, Position, SizeMode,
+};
+use crate::layout::SceneId;
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Marker symbols available in 3D scenes.
+#[derive(Serialize, Clone, Debug)]
+pub enum Symbol3D {
+    #[serde(rename = "circle")]
+    Circle,
+    #[serde(rename = "circle-open")]
+    CircleOpen,
+    #[serde(rename = "square")]
+    Square,
+    #[serde(rename = "square-open")]
+    SquareOpen,
+    #[serde(rename = "diamond")]
+    Diamond,
+    #[serde(rename = "diamond-open")]
+    DiamondOpen,
+    #[serde(rename = "cross")]
+    Cross,
+    #[serde(rename = "x")]
+    X,
+}
+
+/// The outline of the markers of a `Scatter3D`.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct MarkerLine3D {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+}
+
+impl MarkerLine3D {
+    pub fn new() -> MarkerLine3D {
+        Default::default()
+    }
+}
+
+/// The markers of a `Scatter3D`. Unlike the 2D `Marker`, they only support the `Symbol3D` symbols
+/// and have no gradient.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Marker3D {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<Dim<Symbol3D>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<Dim<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizeref")]
+    size_ref: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemin")]
+    size_min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemode")]
+    size_mode: Option<SizeMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<MarkerLine3D>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+}
+
+impl Marker3D {
+    pub fn new() -> Marker3D {
+        Default::default()
+    }
+}
+
+/// The line connecting the points of a `Scatter3D`. Unlike the 2D `Line`, it is always drawn
+/// linearly between the points and can be colored along its length with a color scale.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Line3D {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dash: Option<DashType>,
+    /// A single color, or one value per point mapped through the `color_scale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+}
+
+impl Line3D {
+    pub fn new() -> Line3D {
+        Default::default()
+    }
+}
+
+/// The projection of the points on the plane normal to one axis.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct ProjectionAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    /// Scale factor of the projected markers, between 0 and 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<f64>,
+}
+
+impl ProjectionAxis {
+    pub fn new() -> ProjectionAxis {
+        Default::default()
+    }
+}
+
+/// Projections of the points on the walls of the scene, e.g. the `x` projection is drawn on the
+/// y-z plane.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Projection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<ProjectionAxis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<ProjectionAxis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<ProjectionAxis>,
+}
+
+impl Projection {
+    pub fn new() -> Projection {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Scatter3D<X, Y, Z> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    z: Vec<Z>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// The scene the trace is drawn in, `SceneId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<SceneId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker3D>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line3D>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projection: Option<Projection>,
+    /// Fills the polygon formed by the points, projected along the given axis: -1 for none, 0 for
+    /// x, 1 for y and 2 for z.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "surfaceaxis")]
+    surface_axis: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "surfacecolor")]
+    surface_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_x: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_y: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_z: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zcalendar")]
+    z_calendar: Option<Calendar>,
+}
+
+impl<X, Y, Z> Scatter3D<X, Y, Z> {
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+        Z: Serialize + Default,
+    {
+        Scatter3D {
+            x,
+            y,
+            z,
+            r#type: PlotType::Scatter3D,
+            ..Default::default()
+        }
+    }
+}
+
+impl<X, Y, Z> Trace for Scatter3D<X, Y, Z>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ColorScalePalette;
+    use crate::layout::{
+        AspectMode, AspectRatio, Camera, CameraPosition, DragMode, LayoutScene, ProjectionType,
+        SceneAxis,
+    };
+    use crate::validation::validate_figure;
+    use crate::Layout;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_scatter3d() {
+        let trace = Scatter3D::new(vec![1, 2], vec![3, 4], vec![5, 6])
+            .mode(Mode::LinesMarkers)
+            .scene(SceneId::new(2))
+            .marker(
+                Marker3D::new()
+                    .symbol(Symbol3D::DiamondOpen)
+                    .size_array(vec![4, 8])
+                    .line(MarkerLine3D::new().width(1.).color("black")),
+            )
+            .line(
+                Line3D::new()
+                    .width(4.)
+                    .dash(DashType::Dash)
+                    .color_array(vec![0., 1.])
+                    .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+                    .show_scale(true)
+                    .color_bar(ColorBar::new().thickness(10)),
+            )
+            .projection(Projection::new().z(ProjectionAxis::new().show(true).scale(0.5)))
+            .surface_axis(2);
+        let expected = json!({
+            "x": [1, 2],
+            "y": [3, 4],
+            "z": [5, 6],
+            "type": "scatter3d",
+            "mode": "lines+markers",
+            "scene": "scene2",
+            "marker": {
+                "symbol": "diamond-open",
+                "size": [4, 8],
+                "line": {"width": 1., "color": "black"},
+            },
+            "line": {
+                "width": 4.,
+                "dash": "dash",
+                "color": [0., 1.],
+                "colorscale": "Viridis",
+                "showscale": true,
+            },
+            "projection": {"z": {"show": true, "scale": 0.5}},
+            "surfaceaxis": 2,
+        });
+        let value = to_value(&trace).unwrap();
+        // The color bar serializes all of its defaults.
+        assert_eq!(value["line"]["colorbar"]["thickness"], json!(10));
+        let mut compared = value.clone();
+        compared["line"].as_object_mut().unwrap().remove("colorbar");
+        assert_eq!(compared, expected);
+
+        let layout = Layout::new()
+            .scene(LayoutScene::new().aspect_mode(AspectMode::Cube))
+            .scene_n(
+                2,
+                LayoutScene::new()
+                    .camera(
+                        Camera::new()
+                            .eye(CameraPosition::new(1.5, 1.5, 0.5))
+                            .projection(ProjectionType::Orthographic),
+                    )
+                    .aspect_mode(AspectMode::Manual)
+                    .aspect_ratio(AspectRatio::new(1., 1., 2.))
+                    .z_axis(
+                        SceneAxis::new()
+                            .show_background(true)
+                            .background_color("grey"),
+                    )
+                    .drag_mode(DragMode::TurnTable),
+            );
+        let expected_layout = json!({
+            "scene": {"aspectmode": "cube"},
+            "scene2": {
+                "camera": {"eye": {"x": 1.5, "y": 1.5, "z": 0.5}, "projection": {"type": "orthographic"}},
+                "aspectmode": "manual",
+                "aspectratio": {"x": 1., "y": 1., "z": 2.},
+                "zaxis": {"showbackground": true, "backgroundcolor": "grey"},
+                "dragmode": "turntable",
+            },
+        });
+        let layout_value = to_value(&layout).unwrap();
+        assert_eq!(layout_value, expected_layout);
+        assert_eq!(
+            validate_figure(&json!({"data": [value], "layout": layout_value})),
+            vec![]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "scene indices start at 1")]
+    fn test_scene_index_zero() {
+        let _ = Layout::new().scene_n(0, LayoutScene::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "scene indices start at 1")]
+    fn test_scene_id_index_zero() {
+        let _ = Scatter3D::new(vec![1], vec![1], vec![1]).scene(SceneId::new(0));
+    }
+}
@@ -0,0 +1,171 @@
+//! Polar scatter plot
+
+use crate::common::color::ColorWrapper;
+use crate::common::{
+    Dim, Fill, FlagNames, Flags, Font, HoverInfo, Label, Line, Marker, Mode, PlotType, Position,
+};
+use crate::layout::{PolarId, ThetaUnit};
+use crate::private::NumOrStringWrapper;
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Flags of `ScatterPolar::hover_on`, whether hovering highlights the points, the fills or both,
+/// combined with `|`, e.g. `HoverOn::POINTS | HoverOn::FILLS`.
+pub type HoverOn = Flags<HoverOnNames>;
+
+/// The flag names of `HoverOn`.
+pub enum HoverOnNames {}
+
+impl FlagNames for HoverOnNames {
+    const NAMES: &'static [&'static str] = &["points", "fills"];
+    const EMPTY: &'static str = "points";
+}
+
+impl HoverOn {
+    pub const POINTS: HoverOn = Flags::flag(0);
+    pub const FILLS: HoverOn = Flags::flag(1);
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct ScatterPolar<R, Theta> {
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r: Option<Vec<R>>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theta: Option<Vec<Theta>>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// Alternate to `r`, the first radial coordinate of a linear space with step `dr`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r0: Option<NumOrStringWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dr: Option<f64>,
+    /// Alternate to `theta`, the first angular coordinate of a linear space with step `dtheta`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theta0: Option<NumOrStringWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtheta: Option<f64>,
+    /// Unit of the numeric `theta` coordinates, degrees by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    /// The polar subplot the trace is drawn in, `PolarId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subplot: Option<PolarId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "customdata")]
+    custom_data: Option<Vec<NumOrStringWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    /// `Fill::ToSelf`, `Fill::ToNext` or `Fill::None`. `Fill::ToSelf` closes the trace into a
+    /// filled shape, as in radar charts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<Fill>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+}
+
+impl<R, Theta> ScatterPolar<R, Theta> {
+    /// Points at the radial coordinates `r` and angular coordinates `theta`.
+    pub fn new(r: Vec<R>, theta: Vec<Theta>) -> Self
+    where
+        R: Serialize + Default,
+        Theta: Serialize + Default,
+    {
+        ScatterPolar {
+            r: Some(r),
+            theta: Some(theta),
+            r#type: PlotType::ScatterPolar,
+            ..Default::default()
+        }
+    }
+}
+
+impl<R, Theta> Trace for ScatterPolar<R, Theta>
+where
+    R: Serialize,
+    Theta: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_scatter_polar() {
+        let trace = ScatterPolar::new(vec![1., 2., 3.], vec!["a", "b", "c"])
+            .mode(Mode::LinesMarkers)
+            .fill(Fill::ToSelf)
+            .theta_unit(ThetaUnit::Radians)
+            .subplot(PolarId::new(2))
+            .hover_on(HoverOn::POINTS | HoverOn::FILLS);
+        let expected = json!({
+            "r": [1., 2., 3.],
+            "theta": ["a", "b", "c"],
+            "type": "scatterpolar",
+            "mode": "lines+markers",
+            "thetaunit": "radians",
+            "subplot": "polar2",
+            "hoveron": "points+fills",
+            "fill": "toself",
+        });
+        assert_trace_json(&trace, expected);
+    }
+
+    #[test]
+    fn test_serialize_hover_on() {
+        let flags = vec![
+            HoverOn::FILLS,
+            HoverOn::FILLS | HoverOn::POINTS,
+            HoverOn::raw("points"),
+        ];
+        assert_eq!(
+            to_value(flags).unwrap(),
+            json!(["fills", "points+fills", "points"])
+        );
+    }
+}
@@ -0,0 +1,529 @@
+//! Grids of subplots in the style of plotly.py's `make_subplots`.
+//!
+//! `Subplots` computes the domains and anchors of the axes of every cell of a grid and sets the
+//! axis references of the traces added to a cell, so neither has to be written by hand.
+//!
+//! # Examples
+//!
+//! ```
+//! use plotly::{Layout, Scatter, Subplots};
+//!
+//! let mut subplots = Subplots::new(2, 2)
+//!     .shared_x_axes(true)
+//!     .span(2, 1, 1, 2)
+//!     .subplot_titles(vec!["First", "Second", "Wide"]);
+//! subplots.add_trace_at(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]), 1, 1);
+//! subplots.add_trace_at(Scatter::new(vec![1, 2, 3], vec![6, 5, 4]), 1, 2);
+//! subplots.add_trace_at(Scatter::new(vec![1, 2, 3], vec![1, 3, 2]), 2, 1);
+//! let plot = subplots.into_plot(Layout::new().height(600));
+//! assert!(plot.lint().is_empty());
+//! ```
+
+use crate::common::{Anchor, Font, Side};
+use crate::layout::{Annotation, Axis, AxisId, Layout, XAxisId, XRef, YAxisId, YRef};
+use crate::{Plot, Trace};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A grid of subplots with `rows` rows and `cols` columns. Rows and columns are numbered from 1,
+/// starting at the top left cell.
+pub struct Subplots {
+    rows: usize,
+    cols: usize,
+    shared_x_axes: bool,
+    shared_y_axes: bool,
+    horizontal_spacing: Option<f64>,
+    vertical_spacing: Option<f64>,
+    column_widths: Option<Vec<f64>>,
+    row_heights: Option<Vec<f64>>,
+    subplot_titles: Vec<String>,
+    spans: BTreeMap<(usize, usize), (usize, usize)>,
+    x_axes: BTreeMap<(usize, usize), Axis>,
+    y_axes: BTreeMap<(usize, usize), Axis>,
+    secondary_y_axes: BTreeMap<(usize, usize), Axis>,
+    traces: Vec<CellTrace>,
+}
+
+struct CellTrace {
+    trace: Box<dyn Trace>,
+    row: usize,
+    col: usize,
+    secondary_y: bool,
+}
+
+/// The axes and domain of a cell of the grid.
+struct Cell {
+    row: usize,
+    col: usize,
+    x: XAxisId,
+    y: YAxisId,
+    secondary_y: Option<YAxisId>,
+    x_domain: [f64; 2],
+    y_domain: [f64; 2],
+}
+
+impl Subplots {
+    /// Creates a grid of `rows` by `cols` subplots. Panics if either is 0.
+    pub fn new(rows: usize, cols: usize) -> Subplots {
+        assert!(
+            rows > 0 && cols > 0,
+            "a subplot grid needs at least one cell"
+        );
+        Subplots {
+            rows,
+            cols,
+            shared_x_axes: false,
+            shared_y_axes: false,
+            horizontal_spacing: None,
+            vertical_spacing: None,
+            column_widths: None,
+            row_heights: None,
+            subplot_titles: Vec::new(),
+            spans: BTreeMap::new(),
+            x_axes: BTreeMap::new(),
+            y_axes: BTreeMap::new(),
+            secondary_y_axes: BTreeMap::new(),
+            traces: Vec::new(),
+        }
+    }
+
+    /// Links the x axes of every column to the x axis of its bottom cell and only shows the tick
+    /// labels of the bottom cell.
+    pub fn shared_x_axes(mut self, shared_x_axes: bool) -> Subplots {
+        self.shared_x_axes = shared_x_axes;
+        self
+    }
+
+    /// Links the y axes of every row to the y axis of its first cell and only shows the tick
+    /// labels of the first cell.
+    pub fn shared_y_axes(mut self, shared_y_axes: bool) -> Subplots {
+        self.shared_y_axes = shared_y_axes;
+        self
+    }
+
+    /// Space between columns as a fraction of the plot width. Defaults to `0.2 / cols`.
+    pub fn horizontal_spacing(mut self, horizontal_spacing: f64) -> Subplots {
+        self.horizontal_spacing = Some(horizontal_spacing);
+        self
+    }
+
+    /// Space between rows as a fraction of the plot height. Defaults to `0.3 / rows`.
+    pub fn vertical_spacing(mut self, vertical_spacing: f64) -> Subplots {
+        self.vertical_spacing = Some(vertical_spacing);
+        self
+    }
+
+    /// Relative widths of the columns, normalized to fill the width left by the spacing.
+    pub fn column_widths(mut self, column_widths: Vec<f64>) -> Subplots {
+        assert_eq!(
+            column_widths.len(),
+            self.cols,
+            "one width per column is required"
+        );
+        self.column_widths = Some(column_widths);
+        self
+    }
+
+    /// Relative heights of the rows, from the top row down, normalized to fill the height left by
+    /// the spacing.
+    pub fn row_heights(mut self, row_heights: Vec<f64>) -> Subplots {
+        assert_eq!(
+            row_heights.len(),
+            self.rows,
+            "one height per row is required"
+        );
+        self.row_heights = Some(row_heights);
+        self
+    }
+
+    /// Titles of the subplots in row-major order, skipping cells covered by a span. Titles are
+    /// placed above the subplots as annotations.
+    pub fn subplot_titles<S: AsRef<str>>(mut self, subplot_titles: Vec<S>) -> Subplots {
+        self.subplot_titles = crate::private::owned_string_vector(subplot_titles);
+        self
+    }
+
+    /// Extends the subplot of the cell at `row` and `col` over `rowspan` rows and `colspan`
+    /// columns. The cells it covers have no subplot of their own.
+    pub fn span(mut self, row: usize, col: usize, rowspan: usize, colspan: usize) -> Subplots {
+        self.check_cell(row, col);
+        assert!(
+            rowspan > 0
+                && colspan > 0
+                && row + rowspan - 1 <= self.rows
+                && col + colspan - 1 <= self.cols,
+            "the span of cell ({}, {}) does not fit in the {}x{} grid",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        self.spans.insert((row, col), (rowspan, colspan));
+        self
+    }
+
+    /// Sets the x axis of the subplot at `row` and `col`. Its domain and anchor are computed from
+    /// the grid.
+    pub fn x_axis_at(mut self, row: usize, col: usize, axis: Axis) -> Subplots {
+        self.check_cell(row, col);
+        self.x_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Sets the y axis of the subplot at `row` and `col`. Its domain and anchor are computed from
+    /// the grid.
+    pub fn y_axis_at(mut self, row: usize, col: usize, axis: Axis) -> Subplots {
+        self.check_cell(row, col);
+        self.y_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Adds a secondary y axis on the right side of the subplot at `row` and `col`, overlaying its
+    /// y axis. Traces are added to it with `add_secondary_y_trace_at`.
+    pub fn secondary_y(mut self, row: usize, col: usize, axis: Axis) -> Subplots {
+        self.check_cell(row, col);
+        self.secondary_y_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Adds a trace to the subplot at `row` and `col`, setting its axis references.
+    pub fn add_trace_at<T: Trace + 'static>(&mut self, trace: T, row: usize, col: usize) {
+        self.check_cell(row, col);
+        self.traces.push(CellTrace {
+            trace: Box::new(trace),
+            row,
+            col,
+            secondary_y: false,
+        });
+    }
+
+    /// Adds a trace to the secondary y axis of the subplot at `row` and `col`.
+    pub fn add_secondary_y_trace_at<T: Trace + 'static>(
+        &mut self,
+        trace: T,
+        row: usize,
+        col: usize,
+    ) {
+        self.check_cell(row, col);
+        self.traces.push(CellTrace {
+            trace: Box::new(trace),
+            row,
+            col,
+            secondary_y: true,
+        });
+    }
+
+    /// The x and y axes of the subplot at `row` and `col`. Panics if the cell is covered by the
+    /// span of another cell.
+    pub fn axis_ids(&self, row: usize, col: usize) -> (XAxisId, YAxisId) {
+        let cells = self.cells();
+        let cell = find_cell(&cells, row, col);
+        (cell.x, cell.y)
+    }
+
+    /// Adds the axes and subplot titles of the grid to `layout` and returns a `Plot` with the
+    /// traces of the subplots.
+    pub fn into_plot(mut self, layout: Layout) -> Plot {
+        let cells = self.cells();
+        let mut layout = layout;
+        for cell in cells.iter() {
+            let (x_axis, y_axis) = self.cell_axes(&cells, cell);
+            layout = layout.axis(cell.x, x_axis).axis(cell.y, y_axis);
+            if let Some(id) = cell.secondary_y {
+                let axis = self.secondary_y_axes.remove(&(cell.row, cell.col)).unwrap();
+                let axis = axis
+                    .anchor(cell.x.into())
+                    .overlaying(cell.y.into())
+                    .side(Side::Right);
+                layout = layout.axis(id, axis);
+            }
+        }
+        for (cell, title) in cells.iter().zip(self.subplot_titles.iter()) {
+            layout.add_annotation(
+                Annotation::new()
+                    .text(title)
+                    .x((cell.x_domain[0] + cell.x_domain[1]) / 2.0)
+                    .y(cell.y_domain[1])
+                    .x_ref(XRef::Paper)
+                    .y_ref(YRef::Paper)
+                    .x_anchor(Anchor::Center)
+                    .y_anchor(Anchor::Bottom)
+                    .show_arrow(false)
+                    .font(Font::new().size(16)),
+            );
+        }
+
+        let mut plot = Plot::new();
+        for cell_trace in self.traces.drain(..) {
+            let cell = find_cell(&cells, cell_trace.row, cell_trace.col);
+            let y = if cell_trace.secondary_y {
+                cell.secondary_y.unwrap_or_else(|| {
+                    panic!(
+                        "cell ({}, {}) has no secondary y axis",
+                        cell_trace.row, cell_trace.col
+                    )
+                })
+            } else {
+                cell.y
+            };
+            plot.add_trace(CellTraceRef {
+                trace: cell_trace.trace,
+                x_axis: cell.x,
+                y_axis: y,
+            });
+        }
+        plot.set_layout(layout);
+        plot
+    }
+
+    fn check_cell(&self, row: usize, col: usize) {
+        assert!(
+            row >= 1 && row <= self.rows && col >= 1 && col <= self.cols,
+            "cell ({}, {}) is outside of the {}x{} grid",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+    }
+
+    /// The x and y axis of a cell, linked to the axes they share.
+    fn cell_axes(&mut self, cells: &[Cell], cell: &Cell) -> (Axis, Axis) {
+        let key = (cell.row, cell.col);
+        let mut x_axis = self.x_axes.remove(&key).unwrap_or_default();
+        let mut y_axis = self.y_axes.remove(&key).unwrap_or_default();
+        if self.shared_x_axes {
+            let bottom = cells.iter().filter(|c| c.col == cell.col).last().unwrap();
+            if bottom.x != cell.x {
+                x_axis = x_axis.matches(bottom.x.into()).show_tick_labels(false);
+            }
+        }
+        if self.shared_y_axes {
+            let first = cells.iter().find(|c| c.row == cell.row).unwrap();
+            if first.y != cell.y {
+                y_axis = y_axis.matches(first.y.into()).show_tick_labels(false);
+            }
+        }
+        let x_axis = x_axis.domain(&cell.x_domain).anchor(cell.y.into());
+        let y_axis = y_axis.domain(&cell.y_domain).anchor(cell.x.into());
+        (x_axis, y_axis)
+    }
+
+    /// Numbers the axes of the cells in row-major order and computes their domains.
+    fn cells(&self) -> Vec<Cell> {
+        let horizontal_spacing = self.horizontal_spacing.unwrap_or(0.2 / self.cols as f64);
+        let vertical_spacing = self.vertical_spacing.unwrap_or(0.3 / self.rows as f64);
+        let widths = fractions(&self.column_widths, self.cols, horizontal_spacing);
+        let heights = fractions(&self.row_heights, self.rows, vertical_spacing);
+
+        let mut covered = vec![vec![false; self.cols + 1]; self.rows + 1];
+        let mut cells = Vec::new();
+        let (mut x_index, mut y_index) = (0, 0);
+        for row in 1..=self.rows {
+            for col in 1..=self.cols {
+                if covered[row][col] {
+                    continue;
+                }
+                let (rowspan, colspan) = self.spans.get(&(row, col)).copied().unwrap_or((1, 1));
+                for covered_row in covered.iter_mut().skip(row).take(rowspan) {
+                    for covered_cell in covered_row.iter_mut().skip(col).take(colspan) {
+                        assert!(
+                            !*covered_cell,
+                            "the span of cell ({}, {}) overlaps another subplot",
+                            row, col
+                        );
+                        *covered_cell = true;
+                    }
+                }
+
+                x_index += 1;
+                y_index += 1;
+                let y = YAxisId::new(y_index);
+                let secondary_y = if self.secondary_y_axes.contains_key(&(row, col)) {
+                    y_index += 1;
+                    Some(YAxisId::new(y_index))
+                } else {
+                    None
+                };
+
+                let left: f64 =
+                    widths[..col - 1].iter().sum::<f64>() + horizontal_spacing * (col - 1) as f64;
+                let width: f64 = widths[col - 1..col - 1 + colspan].iter().sum::<f64>()
+                    + horizontal_spacing * (colspan - 1) as f64;
+                let top: f64 = 1.0
+                    - heights[..row - 1].iter().sum::<f64>()
+                    - vertical_spacing * (row - 1) as f64;
+                let height: f64 = heights[row - 1..row - 1 + rowspan].iter().sum::<f64>()
+                    + vertical_spacing * (rowspan - 1) as f64;
+                cells.push(Cell {
+                    row,
+                    col,
+                    x: XAxisId::new(x_index),
+                    y,
+                    secondary_y,
+                    x_domain: [round(left), round(left + width)],
+                    y_domain: [round(top - height), round(top)],
+                });
+            }
+        }
+        for (row, col) in self.secondary_y_axes.keys() {
+            find_cell(&cells, *row, *col);
+        }
+        cells
+    }
+}
+
+fn find_cell(cells: &[Cell], row: usize, col: usize) -> &Cell {
+    cells
+        .iter()
+        .find(|cell| cell.row == row && cell.col == col)
+        .unwrap_or_else(|| {
+            panic!(
+                "cell ({}, {}) is covered by the span of another cell",
+                row, col
+            )
+        })
+}
+
+/// Splits the space left by the spacing between `count` rows or columns.
+fn fractions(relative: &Option<Vec<f64>>, count: usize, spacing: f64) -> Vec<f64> {
+    let available = 1.0 - spacing * (count - 1) as f64;
+    assert!(
+        available > 0.0,
+        "the spacing leaves no room for the subplots"
+    );
+    match relative {
+        Some(relative) => {
+            let total: f64 = relative.iter().sum();
+            relative.iter().map(|r| available * r / total).collect()
+        }
+        None => vec![available / count as f64; count],
+    }
+}
+
+/// Rounds away the floating point noise of the domain computations.
+fn round(value: f64) -> f64 {
+    (value * 1e12).round() / 1e12
+}
+
+/// A trace with the axis references of its subplot.
+struct CellTraceRef {
+    trace: Box<dyn Trace>,
+    x_axis: XAxisId,
+    y_axis: YAxisId,
+}
+
+impl Trace for CellTraceRef {
+    fn serialize(&self) -> String {
+        let mut value: Value = serde_json::from_str(&self.trace.serialize()).unwrap();
+        let object = value.as_object_mut().unwrap();
+        let (x_axis, y_axis) = (AxisId::from(self.x_axis), AxisId::from(self.y_axis));
+        object.insert("xaxis".to_owned(), Value::String(x_axis.reference()));
+        object.insert("yaxis".to_owned(), Value::String(y_axis.reference()));
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scatter;
+    use serde_json::json;
+
+    fn figure(plot: &Plot) -> Value {
+        serde_json::from_str(&plot.to_json()).unwrap()
+    }
+
+    #[test]
+    fn test_grid_domains() {
+        let mut subplots = Subplots::new(2, 2)
+            .horizontal_spacing(0.1)
+            .vertical_spacing(0.2)
+            .column_widths(vec![3.0, 1.0])
+            .span(2, 1, 1, 2);
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 2, 1);
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 1, 2);
+        assert_eq!(subplots.axis_ids(2, 1), (XAxisId::new(3), YAxisId::new(3)));
+        let plot = subplots.into_plot(Layout::new());
+        let figure = figure(&plot);
+
+        let layout = &figure["layout"];
+        assert_eq!(layout["xaxis"]["domain"], json!([0.0, 0.675]));
+        assert_eq!(layout["xaxis2"]["domain"], json!([0.775, 1.0]));
+        assert_eq!(layout["xaxis3"]["domain"], json!([0.0, 1.0]));
+        assert_eq!(layout["yaxis"]["domain"], json!([0.6, 1.0]));
+        assert_eq!(layout["yaxis3"]["domain"], json!([0.0, 0.4]));
+        assert_eq!(layout["xaxis2"]["anchor"], json!("y2"));
+        assert_eq!(layout["yaxis2"]["anchor"], json!("x2"));
+        assert!(layout.get("xaxis4").is_none());
+
+        assert_eq!(figure["data"][0]["xaxis"], json!("x3"));
+        assert_eq!(figure["data"][0]["yaxis"], json!("y3"));
+        assert_eq!(figure["data"][1]["xaxis"], json!("x2"));
+        assert_eq!(plot.validate(), vec![]);
+        assert_eq!(plot.lint(), vec![]);
+    }
+
+    #[test]
+    fn test_shared_axes_and_titles() {
+        let subplots = Subplots::new(2, 2)
+            .shared_x_axes(true)
+            .shared_y_axes(true)
+            .subplot_titles(vec!["a", "b", "c"]);
+        let figure = figure(&subplots.into_plot(Layout::new()));
+        let layout = &figure["layout"];
+
+        assert_eq!(layout["xaxis"]["matches"], json!("x3"));
+        assert_eq!(layout["xaxis"]["showticklabels"], json!(false));
+        assert!(layout["xaxis3"].get("matches").is_none());
+        assert_eq!(layout["yaxis2"]["matches"], json!("y"));
+        assert_eq!(layout["yaxis4"]["matches"], json!("y3"));
+        assert!(layout["yaxis3"].get("matches").is_none());
+
+        let annotations = layout["annotations"].as_array().unwrap();
+        assert_eq!(annotations.len(), 3);
+        assert_eq!(annotations[1]["text"], json!("b"));
+        assert_eq!(annotations[1]["x"], json!(0.775));
+        assert_eq!(annotations[1]["y"], json!(1.0));
+        assert_eq!(annotations[2]["y"], layout["yaxis3"]["domain"][1]);
+    }
+
+    #[test]
+    fn test_secondary_y() {
+        let mut subplots = Subplots::new(1, 2)
+            .secondary_y(1, 1, Axis::new().title("right".into()))
+            .y_axis_at(1, 2, Axis::new().title("second".into()));
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 1, 1);
+        subplots.add_secondary_y_trace_at(Scatter::new(vec![1], vec![1]), 1, 1);
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 1, 2);
+        let plot = subplots.into_plot(Layout::new());
+        let figure = figure(&plot);
+        let layout = &figure["layout"];
+
+        assert_eq!(
+            layout["yaxis2"],
+            json!({"title": {"text": "right"}, "anchor": "x", "side": "right", "overlaying": "y"})
+        );
+        assert_eq!(layout["yaxis3"]["title"]["text"], json!("second"));
+        assert_eq!(layout["yaxis3"]["anchor"], json!("x2"));
+        assert_eq!(figure["data"][1]["yaxis"], json!("y2"));
+        assert_eq!(figure["data"][2]["xaxis"], json!("x2"));
+        assert_eq!(figure["data"][2]["yaxis"], json!("y3"));
+        assert_eq!(plot.lint(), vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (1, 2) is covered by the span of another cell")]
+    fn test_covered_cell() {
+        let mut subplots = Subplots::new(1, 2).span(1, 1, 1, 2);
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 1, 2);
+        subplots.into_plot(Layout::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is outside of the 2x2 grid")]
+    fn test_cell_outside_grid() {
+        let mut subplots = Subplots::new(2, 2);
+        subplots.add_trace_at(Scatter::new(vec![1], vec![1]), 3, 1);
+    }
+}
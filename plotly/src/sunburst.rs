@@ -0,0 +1,215 @@
+//! Sunburst chart
+
+use crate::common::color::ColorWrapper;
+use crate::common::{
+    ColorBar, ColorScale, Dim, Domain, FlagNames, Flags, Font, InsideTextOrientation, Label,
+    PlotType,
+};
+use crate::hierarchy::Hierarchy;
+use crate::pie::MarkerLine;
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// How the value of a parent relates to the values of its children.
+#[derive(Serialize, Clone, Debug)]
+pub enum BranchValues {
+    /// The value of a parent is added to those of its children.
+    #[serde(rename = "remainder")]
+    Remainder,
+    /// The value of a parent is the total of its own and those of its children.
+    #[serde(rename = "total")]
+    Total,
+}
+
+/// Flags of the `text_info` of hierarchical traces, combined with `|`, e.g.
+/// `TextInfo::LABEL | TextInfo::PERCENT_PARENT`.
+pub type TextInfo = Flags<TextInfoNames>;
+
+/// The flag names of `TextInfo`.
+pub enum TextInfoNames {}
+
+impl FlagNames for TextInfoNames {
+    const NAMES: &'static [&'static str] = &[
+        "label",
+        "text",
+        "value",
+        "current path",
+        "percent root",
+        "percent entry",
+        "percent parent",
+    ];
+    const EMPTY: &'static str = "none";
+}
+
+impl TextInfo {
+    /// No text on the sectors.
+    pub const NONE: TextInfo = Flags::empty();
+    pub const LABEL: TextInfo = Flags::flag(0);
+    pub const TEXT: TextInfo = Flags::flag(1);
+    pub const VALUE: TextInfo = Flags::flag(2);
+    pub const CURRENT_PATH: TextInfo = Flags::flag(3);
+    pub const PERCENT_ROOT: TextInfo = Flags::flag(4);
+    pub const PERCENT_ENTRY: TextInfo = Flags::flag(5);
+    pub const PERCENT_PARENT: TextInfo = Flags::flag(6);
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Marker {
+    /// Colors of the sectors, in the order of the labels, or numbers mapped to colors with the
+    /// `color_scale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<Vec<ColorWrapper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<MarkerLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+}
+
+impl Marker {
+    pub fn new() -> Marker {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Sunburst<V> {
+    #[field_setter(skip)]
+    labels: Vec<String>,
+    #[field_setter(skip)]
+    parents: Vec<String>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    values: Vec<V>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    /// Unique ids of the sectors. Without ids the labels must be unique and `parents` refer to
+    /// labels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    /// Id of the sector shown at the center, the root by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    /// Number of levels shown from the current `level`, all of them with -1.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxdepth")]
+    max_depth: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "insidetextorientation"
+    )]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<V> Sunburst<V> {
+    /// Sectors with the given labels, the label (or id) of their parent, empty for roots, and their
+    /// values. Without values the sectors are sized by their number of leaves.
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>, values: Vec<V>) -> Self
+    where
+        V: Serialize + Default,
+    {
+        Sunburst {
+            labels: labels.iter().map(|l| l.as_ref().to_owned()).collect(),
+            parents: parents.iter().map(|p| p.as_ref().to_owned()).collect(),
+            values,
+            r#type: PlotType::Sunburst,
+            ..Default::default()
+        }
+    }
+}
+
+impl Sunburst<f64> {
+    /// Sectors of a flattened tree, identified by the ids of the hierarchy.
+    pub fn from_hierarchy(hierarchy: Hierarchy) -> Self {
+        let (ids, labels, parents, values) = hierarchy.into_parts();
+        Sunburst::new(labels, parents, values).ids(ids)
+    }
+}
+
+impl<V> Trace for Sunburst<V>
+where
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_sunburst() {
+        let hierarchy = Hierarchy::from_paths(vec![("a/b", 1.), ("a/c", 2.)], '/').with_totals();
+        let sunburst = Sunburst::from_hierarchy(hierarchy)
+            .branch_values(BranchValues::Total)
+            .max_depth(2)
+            .text_info(TextInfo::LABEL | TextInfo::PERCENT_PARENT)
+            .inside_text_orientation(InsideTextOrientation::Radial)
+            .marker(Marker::new().colors(vec![0., 1., 2.]).show_scale(true));
+        let expected = json!({
+            "labels": ["a", "b", "c"],
+            "parents": ["", "a", "a"],
+            "values": [3., 1., 2.],
+            "type": "sunburst",
+            "ids": ["a", "a/b", "a/c"],
+            "branchvalues": "total",
+            "maxdepth": 2,
+            "marker": {"colors": [0., 1., 2.], "showscale": true},
+            "textinfo": "label+percent parent",
+            "insidetextorientation": "radial",
+        });
+        assert_trace_json(&sunburst, expected);
+
+        let sunburst: Sunburst<f64> = Sunburst::new(vec!["a", "b"], vec!["", "a"], vec![]);
+        assert!(to_value(&sunburst).unwrap().get("values").is_none());
+    }
+}
@@ -0,0 +1,507 @@
+//! Templates setting the default look of traces and layouts.
+//!
+//! A `Template` holds default trace attributes, keyed by trace type, and default layout
+//! attributes. It is applied to a plot with `Layout::template`, or to every plot of the process
+//! that does not set one with `set_default_template`. The crate ships the themes of plotly.py as
+//! the `BuiltinTemplate` constants of this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use plotly::template::{self, PLOTLY_DARK, SIMPLE_WHITE};
+//! use plotly::{Layout, Plot, Scatter};
+//!
+//! let mut dark_plot = Plot::new();
+//! dark_plot.add_trace(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]));
+//! dark_plot.set_layout(Layout::new().template(PLOTLY_DARK));
+//!
+//! // Plots without a template of their own use the default template.
+//! template::set_default_template(SIMPLE_WHITE);
+//! let mut plot = Plot::new();
+//! plot.add_trace(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]));
+//! assert!(plot.to_json().contains(r#""template":"#));
+//! template::clear_default_template();
+//! ```
+
+use crate::{Layout, Trace};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::RwLock;
+
+/// Default attributes for traces and for the layout of a plot, serialized as the `template`
+/// attribute of the layout.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Template {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    data: BTreeMap<String, Vec<Value>>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    layout: Map<String, Value>,
+}
+
+impl Template {
+    /// Creates an empty template, which leaves the plotly.js defaults in place.
+    pub fn new() -> Template {
+        Default::default()
+    }
+
+    /// Parses a template from its json representation, `{"data": {...}, "layout": {...}}`.
+    pub fn from_json(json: &str) -> serde_json::Result<Template> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the template to json.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Reads a template saved with `Template::to_file`, or any json template such as the ones of
+    /// plotly.py.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> std::io::Result<Template> {
+        let json = fs::read_to_string(path)?;
+        Ok(Template::from_json(&json)?)
+    }
+
+    /// Writes the template to a json file.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self).unwrap())
+    }
+
+    /// Default attributes of the traces, keyed by trace type, e.g. `"scatter"`. Traces of the same
+    /// type cycle through the entries of their list.
+    pub fn data(&self) -> &BTreeMap<String, Vec<Value>> {
+        &self.data
+    }
+
+    /// Default attributes of the layout.
+    pub fn layout(&self) -> &Map<String, Value> {
+        &self.layout
+    }
+}
+
+/// Builds a `Template` from traces and a `Layout` carrying the default attributes.
+///
+/// Trace defaults are given as traces of the type they apply to. Their data is not part of the
+/// template, so they are usually constructed from empty vectors.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::common::{Font, Marker, Mode};
+/// use plotly::template::{TemplateBuilder, PLOTLY_WHITE};
+/// use plotly::{Bar, Layout, Scatter};
+///
+/// let empty = Vec::<f64>::new;
+/// let template = TemplateBuilder::from_template(PLOTLY_WHITE)
+///     .trace(Scatter::new(empty(), empty()).mode(Mode::Markers).marker(Marker::new().size(10)))
+///     .trace(Bar::new(empty(), empty()).opacity(0.8))
+///     .layout(Layout::new().font(Font::new().family("Helvetica")))
+///     .build();
+/// assert_eq!(template.layout()["font"]["family"], "Helvetica");
+/// assert_eq!(template.data()["scatter"][0]["mode"], "markers");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TemplateBuilder {
+    template: Template,
+}
+
+impl TemplateBuilder {
+    /// Starts from an empty template.
+    pub fn new() -> TemplateBuilder {
+        Default::default()
+    }
+
+    /// Starts from an existing template, e.g. a built-in one, whose attributes are overridden by
+    /// the ones added to the builder.
+    pub fn from_template<T: Into<Template>>(template: T) -> TemplateBuilder {
+        TemplateBuilder {
+            template: template.into(),
+        }
+    }
+
+    /// Merges the attributes of `trace` into the defaults of its trace type. Empty arrays, such as
+    /// the data of a trace constructed from empty vectors, are left out.
+    pub fn trace<T: Trace>(mut self, trace: T) -> TemplateBuilder {
+        let mut value: Value = serde_json::from_str(&trace.serialize()).unwrap();
+        let attributes = value.as_object_mut().unwrap();
+        attributes.retain(|_, v| v.as_array().map_or(true, |a| !a.is_empty()));
+        let trace_type = attributes
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("scatter")
+            .to_owned();
+        let defaults = self.template.data.entry(trace_type).or_default();
+        if defaults.is_empty() {
+            defaults.push(Value::Object(Map::new()));
+        }
+        for default in defaults.iter_mut() {
+            merge(default, &value);
+        }
+        self
+    }
+
+    /// Merges the trace and layout defaults of another template, e.g. a built-in one, into the
+    /// defaults of the builder. The trace defaults of a type present in both templates are merged
+    /// entry by entry, cycling through the shorter list.
+    pub fn template<T: Into<Template>>(mut self, template: T) -> TemplateBuilder {
+        let template = template.into();
+        for (trace_type, overrides) in template.data {
+            let defaults = self.template.data.entry(trace_type).or_default();
+            if defaults.is_empty() {
+                *defaults = overrides;
+                continue;
+            }
+            let length = defaults.len().max(overrides.len());
+            let mut merged = Vec::with_capacity(length);
+            for index in 0..length {
+                let mut default = defaults[index % defaults.len()].clone();
+                merge(&mut default, &overrides[index % overrides.len()]);
+                merged.push(default);
+            }
+            *defaults = merged;
+        }
+        let mut defaults = Value::Object(std::mem::take(&mut self.template.layout));
+        merge(&mut defaults, &Value::Object(template.layout));
+        if let Value::Object(defaults) = defaults {
+            self.template.layout = defaults;
+        }
+        self
+    }
+
+    /// Merges the attributes of `layout` into the layout defaults. A template set on `layout` is
+    /// ignored.
+    pub fn layout(mut self, layout: Layout) -> TemplateBuilder {
+        let mut value: Value = serde_json::from_str(&Trace::serialize(&layout)).unwrap();
+        value.as_object_mut().unwrap().remove("template");
+        let mut defaults = Value::Object(std::mem::take(&mut self.template.layout));
+        merge(&mut defaults, &value);
+        if let Value::Object(defaults) = defaults {
+            self.template.layout = defaults;
+        }
+        self
+    }
+
+    /// Returns the built template.
+    pub fn build(self) -> Template {
+        self.template
+    }
+}
+
+/// Recursively merges the objects of `overrides` into `target`, other values replace the ones of
+/// `target`.
+fn merge(target: &mut Value, overrides: &Value) {
+    match (target, overrides) {
+        (Value::Object(target), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match target.get_mut(key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (target, overrides) => *target = overrides.clone(),
+    }
+}
+
+/// A template shipped with the crate. Converts into a `Template` wherever one is expected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuiltinTemplate {
+    name: &'static str,
+    json: &'static str,
+}
+
+impl BuiltinTemplate {
+    /// Looks up a built-in template by its plotly.py name, e.g. `"plotly_white"`.
+    pub fn from_name(name: &str) -> Option<BuiltinTemplate> {
+        BUILTIN_TEMPLATES.iter().find(|t| t.name == name).copied()
+    }
+
+    /// The plotly.py name of the template.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The template as a `Template` value.
+    pub fn template(&self) -> Template {
+        Template::from_json(self.json).expect("built-in templates are valid json")
+    }
+}
+
+impl From<BuiltinTemplate> for Template {
+    fn from(template: BuiltinTemplate) -> Self {
+        template.template()
+    }
+}
+
+/// The default plotly theme: light blue-grey plot area with white gridlines.
+pub const PLOTLY: BuiltinTemplate = BuiltinTemplate {
+    name: "plotly",
+    json: include_str!("../templates/themes/plotly.json"),
+};
+
+/// Like `PLOTLY` on a white plot area with light gridlines.
+pub const PLOTLY_WHITE: BuiltinTemplate = BuiltinTemplate {
+    name: "plotly_white",
+    json: include_str!("../templates/themes/plotly_white.json"),
+};
+
+/// Like `PLOTLY` with light text on a dark background.
+pub const PLOTLY_DARK: BuiltinTemplate = BuiltinTemplate {
+    name: "plotly_dark",
+    json: include_str!("../templates/themes/plotly_dark.json"),
+};
+
+/// Grey plot area and colors in the style of R's ggplot2.
+pub const GGPLOT2: BuiltinTemplate = BuiltinTemplate {
+    name: "ggplot2",
+    json: include_str!("../templates/themes/ggplot2.json"),
+};
+
+/// Colors and plot area in the style of Python's seaborn.
+pub const SEABORN: BuiltinTemplate = BuiltinTemplate {
+    name: "seaborn",
+    json: include_str!("../templates/themes/seaborn.json"),
+};
+
+/// White background, axis lines and outside ticks, without gridlines.
+pub const SIMPLE_WHITE: BuiltinTemplate = BuiltinTemplate {
+    name: "simple_white",
+    json: include_str!("../templates/themes/simple_white.json"),
+};
+
+/// Larger fonts, lines and markers for slides. Only sets sizes, so it is usually merged into a
+/// template providing the colors with `TemplateBuilder::template`.
+pub const PRESENTATION: BuiltinTemplate = BuiltinTemplate {
+    name: "presentation",
+    json: include_str!("../templates/themes/presentation.json"),
+};
+
+/// An empty template: the plotly.js defaults, overriding the default template.
+pub const NONE: BuiltinTemplate = BuiltinTemplate {
+    name: "none",
+    json: include_str!("../templates/themes/none.json"),
+};
+
+/// All built-in templates.
+pub const BUILTIN_TEMPLATES: [BuiltinTemplate; 8] = [
+    PLOTLY,
+    PLOTLY_WHITE,
+    PLOTLY_DARK,
+    GGPLOT2,
+    SEABORN,
+    SIMPLE_WHITE,
+    PRESENTATION,
+    NONE,
+];
+
+static DEFAULT_TEMPLATE: Lazy<RwLock<Option<Template>>> = Lazy::new(|| RwLock::new(None));
+
+/// Sets the template used by every `Plot` of the process whose `Layout` has no template.
+pub fn set_default_template<T: Into<Template>>(template: T) {
+    *DEFAULT_TEMPLATE.write().unwrap() = Some(template.into());
+}
+
+/// Removes the default template set with `set_default_template`.
+pub fn clear_default_template() {
+    *DEFAULT_TEMPLATE.write().unwrap() = None;
+}
+
+/// The template set with `set_default_template`, if any.
+pub fn default_template() -> Option<Template> {
+    DEFAULT_TEMPLATE.read().unwrap().clone()
+}
+
+/// Adds the default template to a serialized layout that has no template of its own.
+pub(crate) fn apply_default_template(layout: String) -> String {
+    match default_template() {
+        Some(template) => insert_template(layout, &template),
+        None => layout,
+    }
+}
+
+/// Sets the template of a serialized layout, replacing its own, or removes it for `None`.
+pub(crate) fn replace_template(layout: String, template: Option<&Template>) -> String {
+    let mut value: Value = serde_json::from_str(&layout).unwrap();
+    let object = value.as_object_mut().unwrap();
+    match template {
+        Some(template) => object.insert(
+            "template".to_owned(),
+            serde_json::to_value(template).unwrap(),
+        ),
+        None => object.remove("template"),
+    };
+    value.to_string()
+}
+
+fn insert_template(layout: String, template: &Template) -> String {
+    let mut value: Value = serde_json::from_str(&layout).unwrap();
+    let object = value.as_object_mut().unwrap();
+    if object.contains_key("template") {
+        return layout;
+    }
+    object.insert(
+        "template".to_owned(),
+        serde_json::to_value(template).unwrap(),
+    );
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Font, Marker};
+    use crate::validation::validate_figure;
+    use crate::{Bar, HeatMap, Layout, Plot, Scatter};
+    use serde_json::json;
+
+    fn layout_template(plot: &Plot) -> Template {
+        let figure: Value = serde_json::from_str(&plot.to_json()).unwrap();
+        serde_json::from_value(figure["layout"]["template"].clone()).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_templates() {
+        for builtin in BUILTIN_TEMPLATES.iter() {
+            let template = builtin.template();
+            assert_eq!(BuiltinTemplate::from_name(builtin.name()), Some(*builtin));
+
+            // Item defaults such as `annotationdefaults` only exist in templates, they are checked
+            // as an item of their array instead.
+            let mut layout = template.layout().clone();
+            let defaults: Vec<String> = layout
+                .keys()
+                .filter(|key| key.ends_with("defaults"))
+                .cloned()
+                .collect();
+            for key in defaults {
+                let item = layout.remove(&key).unwrap();
+                layout.insert(key.replace("defaults", "s"), json!([item]));
+            }
+            let data: Vec<Value> = template.data().values().flatten().cloned().collect();
+            let figure = json!({"data": data, "layout": layout});
+            assert_eq!(validate_figure(&figure), vec![], "{}", builtin.name());
+        }
+        assert_eq!(BuiltinTemplate::from_name("plotly_purple"), None);
+        assert_eq!(NONE.template(), Template::new());
+    }
+
+    #[test]
+    fn test_template_json() {
+        let template = PLOTLY_DARK.template();
+        assert_eq!(template.layout()["paper_bgcolor"], json!("rgb(17,17,17)"));
+        assert_eq!(template.data()["scatter"][0]["type"], json!("scatter"));
+        assert_eq!(Template::from_json(&template.to_json()).unwrap(), template);
+        assert_eq!(Template::new().to_json(), "{}");
+
+        let mut plot = Plot::new();
+        plot.set_layout(Layout::new().template(SEABORN));
+        assert_eq!(plot.validate(), vec![]);
+        assert_eq!(layout_template(&plot), SEABORN.template());
+    }
+
+    #[test]
+    fn test_template_builder() {
+        let empty = Vec::<f64>::new;
+        let template = TemplateBuilder::from_template(PLOTLY)
+            .trace(Scatter::new(empty(), empty()).marker(Marker::new().size(12)))
+            .trace(HeatMap::new_z(Vec::<Vec<f64>>::new()).zsmooth("fast"))
+            .layout(Layout::new().font(Font::new().family("Georgia")))
+            .build();
+
+        let scatter = &template.data()["scatter"][0];
+        assert_eq!(scatter["marker"]["size"], json!(12));
+        assert_eq!(
+            scatter["marker"]["line"],
+            PLOTLY.template().data()["scatter"][0]["marker"]["line"]
+        );
+        assert!(scatter.get("x").is_none());
+        assert_eq!(template.data()["heatmap"][0]["zsmooth"], json!("fast"));
+        assert_eq!(
+            template.layout()["font"],
+            json!({"color": "#2a3f5f", "family": "Georgia"})
+        );
+        assert_eq!(
+            template.layout()["colorway"],
+            PLOTLY.template().layout()["colorway"]
+        );
+
+        let empty = TemplateBuilder::new()
+            .trace(Bar::new(empty(), empty()))
+            .build();
+        assert_eq!(empty.to_json(), r#"{"data":{"bar":[{"type":"bar"}]}}"#);
+    }
+
+    #[test]
+    fn test_template_builder_merge() {
+        let template = TemplateBuilder::from_template(PLOTLY_WHITE)
+            .template(PRESENTATION)
+            .build();
+        assert_eq!(
+            template.layout()["font"],
+            json!({"color": "#2a3f5f", "size": 18})
+        );
+        assert_eq!(
+            json!(template.data()["scatter"]),
+            json!([{
+                "line": {"width": 3},
+                "marker": {"colorbar": {"outlinewidth": 0, "ticks": ""}, "size": 9},
+                "type": "scatter",
+            }])
+        );
+        assert_eq!(
+            template.data()["pie"],
+            PRESENTATION.template().data()["pie"]
+        );
+        assert_eq!(
+            template.data()["bar"],
+            PLOTLY_WHITE.template().data()["bar"]
+        );
+
+        let cycled = TemplateBuilder::new()
+            .template(Template::from_json(r#"{"data": {"bar": [{"opacity": 1}]}}"#).unwrap())
+            .template(
+                Template::from_json(r#"{"data": {"bar": [{"width": 1}, {"width": 2}]}}"#).unwrap(),
+            )
+            .build();
+        assert_eq!(
+            json!(cycled.data()["bar"]),
+            json!([{"opacity": 1, "width": 1}, {"opacity": 1, "width": 2}])
+        );
+    }
+
+    #[test]
+    fn test_template_file() {
+        let template = TemplateBuilder::new()
+            .layout(Layout::new().title("Report".into()))
+            .build();
+        let path = std::env::temp_dir().join("plotly_test_template_file.json");
+        template.to_file(&path).unwrap();
+        assert_eq!(Template::from_file(&path).unwrap(), template);
+        std::fs::remove_file(&path).unwrap();
+        assert!(Template::from_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_insert_template() {
+        let template = Template::from_json(r#"{"layout": {"font": {"size": 18}}}"#).unwrap();
+        let layout = r#"{"title":{"text":"t"}}"#.to_owned();
+        let inserted: Value = serde_json::from_str(&insert_template(layout, &template)).unwrap();
+        assert_eq!(
+            inserted["template"],
+            json!({"layout": {"font": {"size": 18}}})
+        );
+
+        let own = r#"{"template":{}}"#.to_owned();
+        assert_eq!(insert_template(own.clone(), &template), own);
+        assert_eq!(
+            replace_template(own.clone(), Some(&template)),
+            r#"{"template":{"layout":{"font":{"size":18}}}}"#
+        );
+        assert_eq!(replace_template(own, None), "{}");
+    }
+}
@@ -0,0 +1,365 @@
+//! Assertions and helpers for testing code that builds plots. This module requires the `testing`
+//! feature.
+//!
+//! Comparing the output of `Plot::to_json` as strings is brittle; field order and float
+//! formatting are not part of the plotly.js contract. The helpers in this module parse the
+//! serialized figure and compare it semantically instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use plotly::testing::{assert_figure_eq, trace_attribute};
+//! use plotly::{Plot, Scatter};
+//! use serde_json::json;
+//!
+//! let mut expected = Plot::new();
+//! expected.add_trace(Scatter::new(vec![1, 2], vec![0.1, 0.2]));
+//!
+//! let mut actual = Plot::new();
+//! actual.add_trace(Scatter::new(vec![1, 2], vec![0.1, 0.2000000001]));
+//!
+//! assert_figure_eq(&actual, &expected);
+//! assert_eq!(trace_attribute(&actual, 0, "x[1]"), Some(json!(2)));
+//! ```
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::Plot;
+
+/// Default relative tolerance used when comparing floating point numbers.
+pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+/// When this environment variable is set, `assert_snapshot` overwrites snapshot files instead of
+/// comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "PLOTLY_UPDATE_SNAPSHOTS";
+
+/// A single difference between two json documents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonDifference {
+    /// Location of the difference, e.g. `data[0].marker.color`.
+    pub path: String,
+    /// Value on the left hand side, `None` if the attribute is missing.
+    pub left: Option<Value>,
+    /// Value on the right hand side, `None` if the attribute is missing.
+    pub right: Option<Value>,
+}
+
+impl fmt::Display for JsonDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |v: &Option<Value>| match v {
+            Some(v) => v.to_string(),
+            None => "<missing>".to_owned(),
+        };
+        write!(
+            f,
+            "{}: {} != {}",
+            self.path,
+            show(&self.left),
+            show(&self.right)
+        )
+    }
+}
+
+/// Returns the `Plot` parsed into a json value with `data` and `layout` members.
+pub fn figure_json(plot: &Plot) -> Value {
+    serde_json::from_str(plot.to_json().as_str()).expect("plot serialized to invalid json")
+}
+
+/// Compares two json documents and returns all the differences between them. Objects are
+/// compared irrespective of key order and numbers are considered equal if their relative
+/// difference, `|left - right| / max(|left|, |right|)`, is within `tolerance`. Hence a number is
+/// only equal to zero if it is zero.
+pub fn json_diff(left: &Value, right: &Value, tolerance: f64) -> Vec<JsonDifference> {
+    let mut differences = Vec::new();
+    diff_values("", left, right, tolerance, &mut differences);
+    differences
+}
+
+/// Asserts that two plots are semantically equal, using `DEFAULT_TOLERANCE` for floating point
+/// comparisons. On failure the panic message lists every differing attribute.
+#[track_caller]
+pub fn assert_figure_eq(left: &Plot, right: &Plot) {
+    assert_figure_eq_with_tolerance(left, right, DEFAULT_TOLERANCE);
+}
+
+/// Asserts that two plots are semantically equal, comparing floating point numbers with the
+/// supplied relative `tolerance`.
+#[track_caller]
+pub fn assert_figure_eq_with_tolerance(left: &Plot, right: &Plot, tolerance: f64) {
+    assert_json_eq(&figure_json(left), &figure_json(right), tolerance);
+}
+
+/// Asserts that two json documents are semantically equal. See `json_diff` for the comparison
+/// rules.
+#[track_caller]
+pub fn assert_json_eq(left: &Value, right: &Value, tolerance: f64) {
+    let differences = json_diff(left, right, tolerance);
+    if !differences.is_empty() {
+        panic!(
+            "figures differ in {} place(s) (left != right):\n{}",
+            differences.len(),
+            format_differences(&differences)
+        );
+    }
+}
+
+/// Compares the `Plot` against the snapshot stored at `path`.
+///
+/// If the snapshot does not exist, or the `PLOTLY_UPDATE_SNAPSHOTS` environment variable is set,
+/// the snapshot is (re)written from the `Plot` and the assertion passes. Snapshots are stored as
+/// pretty printed json so they can be reviewed in version control.
+#[track_caller]
+pub fn assert_snapshot<P: AsRef<Path>>(plot: &Plot, path: P) {
+    let path = path.as_ref();
+    let actual = figure_json(plot);
+    if env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .unwrap_or_else(|_| panic!("failed to create snapshot directory {:?}", parent));
+        }
+        let pretty = serde_json::to_string_pretty(&actual).unwrap();
+        fs::write(path, pretty).unwrap_or_else(|_| panic!("failed to write snapshot {:?}", path));
+        return;
+    }
+
+    let snapshot =
+        fs::read_to_string(path).unwrap_or_else(|_| panic!("failed to read snapshot {:?}", path));
+    let expected: Value = serde_json::from_str(snapshot.as_str())
+        .unwrap_or_else(|_| panic!("snapshot {:?} is not valid json", path));
+    let differences = json_diff(&actual, &expected, DEFAULT_TOLERANCE);
+    if !differences.is_empty() {
+        panic!(
+            "plot does not match snapshot {:?} (actual != snapshot); set {} to update it:\n{}",
+            path,
+            UPDATE_SNAPSHOTS_ENV,
+            format_differences(&differences)
+        );
+    }
+}
+
+/// Looks up a value by path, e.g. `marker.line.width` or `x[2]`.
+pub fn query<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = value;
+    for segment in path_segments(path)? {
+        current = match segment {
+            Segment::Key(key) => current.as_object()?.get(key)?,
+            Segment::Index(index) => current.as_array()?.get(index)?,
+        };
+    }
+    Some(current)
+}
+
+/// Returns the attribute at `path` of the trace at index `trace`, e.g.
+/// `trace_attribute(&plot, 0, "marker.color")`.
+pub fn trace_attribute(plot: &Plot, trace: usize, path: &str) -> Option<Value> {
+    let figure = figure_json(plot);
+    let trace = figure.get("data")?.get(trace)?;
+    query(trace, path).cloned()
+}
+
+/// Returns the `Layout` attribute at `path`, e.g. `layout_attribute(&plot, "xaxis.title.text")`.
+pub fn layout_attribute(plot: &Plot, path: &str) -> Option<Value> {
+    let figure = figure_json(plot);
+    query(figure.get("layout")?, path).cloned()
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+fn path_segments(path: &str) -> Option<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    for part in path.split('.').filter(|p| !p.is_empty()) {
+        let (key, mut rest) = match part.find('[') {
+            Some(pos) => (&part[..pos], &part[pos..]),
+            None => (part, ""),
+        };
+        if !key.is_empty() {
+            segments.push(Segment::Key(key));
+        }
+        while !rest.is_empty() {
+            let end = rest.find(']')?;
+            if !rest.starts_with('[') {
+                return None;
+            }
+            segments.push(Segment::Index(rest[1..end].trim().parse().ok()?));
+            rest = &rest[end + 1..];
+        }
+    }
+    Some(segments)
+}
+
+fn format_differences(differences: &[JsonDifference]) -> String {
+    differences
+        .iter()
+        .map(|d| format!("  {}", d))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+fn numbers_equal(left: f64, right: f64, tolerance: f64) -> bool {
+    if left == right {
+        return true;
+    }
+    (left - right).abs() <= tolerance * left.abs().max(right.abs())
+}
+
+fn diff_values(
+    path: &str,
+    left: &Value,
+    right: &Value,
+    tolerance: f64,
+    differences: &mut Vec<JsonDifference>,
+) {
+    match (left, right) {
+        (Value::Object(l), Value::Object(r)) => {
+            let mut keys: Vec<&String> = l.keys().chain(r.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let p = child_path(path, key);
+                match (l.get(key), r.get(key)) {
+                    (Some(lv), Some(rv)) => diff_values(p.as_str(), lv, rv, tolerance, differences),
+                    (lv, rv) => differences.push(JsonDifference {
+                        path: p,
+                        left: lv.cloned(),
+                        right: rv.cloned(),
+                    }),
+                }
+            }
+        }
+        (Value::Array(l), Value::Array(r)) => {
+            for index in 0..l.len().max(r.len()) {
+                let p = format!("{}[{}]", path, index);
+                match (l.get(index), r.get(index)) {
+                    (Some(lv), Some(rv)) => diff_values(p.as_str(), lv, rv, tolerance, differences),
+                    (lv, rv) => differences.push(JsonDifference {
+                        path: p,
+                        left: lv.cloned(),
+                        right: rv.cloned(),
+                    }),
+                }
+            }
+        }
+        (Value::Number(l), Value::Number(r)) => {
+            let equal = match (l.as_f64(), r.as_f64()) {
+                (Some(lf), Some(rf)) => numbers_equal(lf, rf, tolerance),
+                _ => l == r,
+            };
+            if !equal {
+                differences.push(JsonDifference {
+                    path: path.to_owned(),
+                    left: Some(left.clone()),
+                    right: Some(right.clone()),
+                });
+            }
+        }
+        _ => {
+            if left != right {
+                differences.push(JsonDifference {
+                    path: path.to_owned(),
+                    left: Some(left.clone()),
+                    right: Some(right.clone()),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Marker;
+    use crate::{Layout, Scatter};
+    use serde_json::json;
+
+    #[test]
+    fn test_json_diff_ignores_key_order_and_float_noise() {
+        let left = json!({"a": 1, "b": [0.1, 0.2], "c": {"d": "x"}});
+        let right = json!({"c": {"d": "x"}, "b": [0.1, 0.2 + 1e-12], "a": 1.0});
+        assert!(json_diff(&left, &right, DEFAULT_TOLERANCE).is_empty());
+    }
+
+    #[test]
+    fn test_json_diff_tolerance_is_relative() {
+        let small = json!([1e-12, 0.]);
+        assert_eq!(
+            json_diff(&small, &json!([5e-12, 1e-15]), DEFAULT_TOLERANCE).len(),
+            2
+        );
+        assert!(json_diff(&small, &json!([1e-12 + 1e-22, 0.]), DEFAULT_TOLERANCE).is_empty());
+        assert!(json_diff(&json!(1e12), &json!(1e12 + 1.), DEFAULT_TOLERANCE).is_empty());
+    }
+
+    #[test]
+    fn test_json_diff_reports_paths() {
+        let left = json!({"data": [{"x": [1, 2, 3], "name": "a"}], "layout": {}});
+        let right = json!({"data": [{"x": [1, 5], "name": "a"}], "layout": {"width": 10}});
+        let differences = json_diff(&left, &right, DEFAULT_TOLERANCE);
+        let paths: Vec<&str> = differences.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, vec!["data[0].x[1]", "data[0].x[2]", "layout.width"]);
+        assert_eq!(differences[1].to_string(), "data[0].x[2]: 3 != <missing>");
+    }
+
+    #[test]
+    fn test_assert_figure_eq() {
+        let mut left = Plot::new();
+        left.add_trace(Scatter::new(vec![1, 2], vec![0.3, 0.6]));
+        let mut right = Plot::new();
+        right.add_trace(Scatter::new(vec![1, 2], vec![0.1 + 0.2, 0.6]));
+        assert_figure_eq(&left, &right);
+    }
+
+    #[test]
+    #[should_panic(expected = "layout.height: 300 != <missing>")]
+    fn test_assert_figure_eq_fails() {
+        let mut left = Plot::new();
+        left.set_layout(Layout::new().height(300));
+        let right = Plot::new();
+        assert_figure_eq(&left, &right);
+    }
+
+    #[test]
+    fn test_query() {
+        let value = json!({"marker": {"size": [1, 2, 3]}, "grid": [[0, 1], [2, 3]]});
+        assert_eq!(query(&value, "marker.size[2]"), Some(&json!(3)));
+        assert_eq!(query(&value, "grid[1][0]"), Some(&json!(2)));
+        assert_eq!(query(&value, "marker.color"), None);
+        assert_eq!(query(&value, "marker.size[x]"), None);
+    }
+
+    #[test]
+    fn test_trace_and_layout_attribute() {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]).marker(Marker::new().size(5)));
+        plot.set_layout(Layout::new().width(640));
+        assert_eq!(trace_attribute(&plot, 0, "marker.size"), Some(json!(5)));
+        assert_eq!(trace_attribute(&plot, 1, "marker.size"), None);
+        assert_eq!(layout_attribute(&plot, "width"), Some(json!(640)));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let path = env::temp_dir().join(format!("plotly_snapshot_{}.json", std::process::id()));
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]));
+        assert_snapshot(&plot, &path);
+        assert!(path.exists());
+        assert_snapshot(&plot, &path);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+//! Treemap chart
+
+use crate::common::{Dim, Domain, Font, Label, PlotType, Position, Side};
+use crate::hierarchy::Hierarchy;
+use crate::sunburst::{BranchValues, Marker, TextInfo};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Algorithm laying out the rectangles of a `Treemap`.
+#[derive(Serialize, Clone, Debug)]
+pub enum Packing {
+    #[serde(rename = "squarify")]
+    Squarify,
+    #[serde(rename = "binary")]
+    Binary,
+    #[serde(rename = "dice")]
+    Dice,
+    #[serde(rename = "slice")]
+    Slice,
+    #[serde(rename = "slice-dice")]
+    SliceDice,
+    #[serde(rename = "dice-slice")]
+    DiceSlice,
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Tiling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    packing: Option<Packing>,
+    /// Ratio of the rectangles sought by `Packing::Squarify`, at least 1.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "squarifyratio")]
+    squarify_ratio: Option<f64>,
+    /// Padding in pixels between a parent and its children.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pad: Option<f64>,
+}
+
+impl Tiling {
+    pub fn new() -> Tiling {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum EdgeShape {
+    #[serde(rename = ">")]
+    RightArrow,
+    #[serde(rename = "<")]
+    LeftArrow,
+    #[serde(rename = "|")]
+    Vertical,
+    #[serde(rename = "/")]
+    Slash,
+    #[serde(rename = "\\")]
+    BackSlash,
+}
+
+/// The bar above or below a `Treemap` showing the path to the current level.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct PathBar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    /// `Side::Top` or `Side::Bottom`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "edgeshape")]
+    edge_shape: Option<EdgeShape>,
+    /// Thickness in pixels, at least 12.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thickness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+}
+
+impl PathBar {
+    pub fn new() -> PathBar {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Treemap<V> {
+    #[field_setter(skip)]
+    labels: Vec<String>,
+    #[field_setter(skip)]
+    parents: Vec<String>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    values: Vec<V>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    /// Unique ids of the rectangles. Without ids the labels must be unique and `parents` refer to
+    /// labels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    /// Id of the rectangle shown at the top, the root by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    /// Number of levels shown from the current `level`, all of them with -1.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxdepth")]
+    max_depth: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tiling: Option<Tiling>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "pathbar")]
+    path_bar: Option<PathBar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<V> Treemap<V> {
+    /// Rectangles with the given labels, the label (or id) of their parent, empty for roots, and
+    /// their values. Without values the rectangles are sized by their number of leaves.
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>, values: Vec<V>) -> Self
+    where
+        V: Serialize + Default,
+    {
+        Treemap {
+            labels: labels.iter().map(|l| l.as_ref().to_owned()).collect(),
+            parents: parents.iter().map(|p| p.as_ref().to_owned()).collect(),
+            values,
+            r#type: PlotType::Treemap,
+            ..Default::default()
+        }
+    }
+}
+
+impl Treemap<f64> {
+    /// Rectangles of a flattened tree, identified by the ids of the hierarchy.
+    pub fn from_hierarchy(hierarchy: Hierarchy) -> Self {
+        let (ids, labels, parents, values) = hierarchy.into_parts();
+        Treemap::new(labels, parents, values).ids(ids)
+    }
+}
+
+impl<V> Trace for Treemap<V>
+where
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_treemap() {
+        let hierarchy = Hierarchy::from_paths(vec![("a/b", 1.), ("a/c", 2.)], '/');
+        let treemap = Treemap::from_hierarchy(hierarchy)
+            .tiling(Tiling::new().packing(Packing::SliceDice).pad(2.))
+            .path_bar(
+                PathBar::new()
+                    .side(Side::Bottom)
+                    .edge_shape(EdgeShape::Slash),
+            )
+            .text_info(TextInfo::LABEL | TextInfo::VALUE)
+            .text_position(Position::MiddleCenter);
+        let expected = json!({
+            "labels": ["a", "b", "c"],
+            "parents": ["", "a", "a"],
+            "values": [0., 1., 2.],
+            "type": "treemap",
+            "ids": ["a", "a/b", "a/c"],
+            "tiling": {"packing": "slice-dice", "pad": 2.},
+            "pathbar": {"side": "bottom", "edgeshape": "/"},
+            "textinfo": "label+value",
+            "textposition": "middle center",
+        });
+        assert_trace_json(&treemap, expected);
+    }
+}
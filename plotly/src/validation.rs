@@ -0,0 +1,979 @@
+//! Offline validation of figures against the plotly.js plot schema, and lints for inconsistencies
+//! between attributes that the schema cannot express.
+//!
+//! The schema in `templates/plot-schema.json` is derived from the output of
+//! `Plotly.PlotSchema.get()` for the bundled plotly.js version. The validator does not use the
+//! documentation in the schema, so the `description` of every attribute and the definitions of
+//! the value types are removed to keep the file small. It must be regenerated whenever the
+//! bundled library is updated, by saving `JSON.stringify(Plotly.PlotSchema.get())` from a page
+//! that loads the bundled `plotly.min.js` to `schema.json` and running
+//!
+//! ```text
+//! jq --indent 1 'del(.. | .description?) | .defs.valObjects |= map_values({})' schema.json \
+//!     > plotly/templates/plot-schema.json
+//! ```
+
+use std::fmt;
+
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+
+const PLOT_SCHEMA: &str = include_str!("../templates/plot-schema.json");
+
+/// The bundled schema, parsed on first use.
+static SCHEMA: Lazy<Value> =
+    Lazy::new(|| serde_json::from_str(PLOT_SCHEMA).expect("invalid bundled plot schema"));
+
+/// The attributes of the layout, including the ones contributed by trace modules.
+static LAYOUT_ATTRIBUTES: Lazy<Value> = Lazy::new(|| layout_attributes(&SCHEMA));
+
+/// Schema keys that describe an attribute container rather than name one of its attributes.
+const SCHEMA_METADATA: [&str; 7] = [
+    "role",
+    "editType",
+    "description",
+    "impliedEdits",
+    "_isSubplotObj",
+    "_isLinkedToArray",
+    "_arrayAttrRegexps",
+];
+
+/// The kind of problem reported by a `Diagnostic`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// The attribute does not exist in the plotly.js schema.
+    UnknownAttribute,
+    /// The value has the wrong json type for the attribute, e.g. a string instead of a number.
+    WrongType,
+    /// The value is not one of the accepted enumerated values or flags.
+    InvalidValue,
+    /// The number lies outside the range accepted by the attribute.
+    OutOfRange,
+    /// A trace or axis refers to an axis that is not defined in the layout.
+    MissingAxis,
+    /// Arrays that describe the same points have different lengths.
+    LengthMismatch,
+}
+
+/// A problem found in a `Plot`. The `attribute` path is relative to the trace, or to the layout if
+/// `trace` is `None`, and uses the plotly.js attribute names, e.g. `marker.line.width`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub trace: Option<usize>,
+    pub attribute: String,
+    pub kind: DiagnosticKind,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn new<S: Into<String>>(
+        trace: Option<usize>,
+        attribute: &str,
+        kind: DiagnosticKind,
+        message: S,
+    ) -> Diagnostic {
+        Diagnostic {
+            trace,
+            attribute: attribute.to_owned(),
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.trace {
+            Some(index) => write!(f, "data[{}].{}: {}", index, self.attribute, self.message),
+            None => write!(f, "layout.{}: {}", self.attribute, self.message),
+        }
+    }
+}
+
+/// Validates a figure of the form `{"data": [...], "layout": {...}}`.
+pub(crate) fn validate_figure(figure: &Value) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        schema: &SCHEMA,
+        trace: None,
+        diagnostics: Vec::new(),
+    };
+
+    if let Some(traces) = figure.get("data").and_then(Value::as_array) {
+        for (index, trace) in traces.iter().enumerate() {
+            validator.trace = Some(index);
+            validator.validate_trace(trace);
+        }
+    }
+
+    validator.trace = None;
+    if let Some(layout) = figure.get("layout").and_then(Value::as_object) {
+        validator.validate_layout(layout);
+    }
+
+    validator.diagnostics
+}
+
+struct Validator<'a> {
+    schema: &'a Value,
+    trace: Option<usize>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn report<S: Into<String>>(&mut self, path: &str, kind: DiagnosticKind, message: S) {
+        self.diagnostics
+            .push(Diagnostic::new(self.trace, path, kind, message));
+    }
+
+    fn validate_trace(&mut self, trace: &Value) {
+        let trace = match trace.as_object() {
+            Some(trace) => trace,
+            None => {
+                self.report("", DiagnosticKind::WrongType, "trace is not an object");
+                return;
+            }
+        };
+        let trace_type = trace
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("scatter");
+        let schema = self.schema;
+        match schema["traces"].get(trace_type) {
+            Some(trace_schema) => self.validate_object("", trace, &trace_schema["attributes"]),
+            None => self.report(
+                "type",
+                DiagnosticKind::InvalidValue,
+                format!("unknown trace type \"{}\"", trace_type),
+            ),
+        }
+    }
+
+    fn validate_layout(&mut self, layout: &Map<String, Value>) {
+        self.validate_object("", layout, &LAYOUT_ATTRIBUTES);
+    }
+
+    fn validate_object(&mut self, path: &str, object: &Map<String, Value>, schema: &Value) {
+        for (key, value) in object {
+            // Traces and transforms record their type as a plain string, checked by the caller.
+            if key == "type" && schema.get(key).map_or(false, Value::is_string) {
+                continue;
+            }
+            let child_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            match attribute_schema(schema, key) {
+                Some(candidates) => self.validate_candidates(&child_path, value, &candidates),
+                None => self.report(
+                    &child_path,
+                    DiagnosticKind::UnknownAttribute,
+                    format!("unknown attribute \"{}\"", key),
+                ),
+            }
+        }
+    }
+
+    /// Deprecated attributes may share their name with a current attribute of a different type,
+    /// e.g. a string `title`; the value is accepted if it matches either definition.
+    fn validate_candidates(&mut self, path: &str, value: &Value, candidates: &[&Value]) {
+        let mark = self.diagnostics.len();
+        self.validate_value(path, value, candidates[0]);
+        for candidate in &candidates[1..] {
+            if self.diagnostics.len() == mark {
+                return;
+            }
+            let mut alternative = Validator {
+                schema: self.schema,
+                trace: self.trace,
+                diagnostics: Vec::new(),
+            };
+            alternative.validate_value(path, value, candidate);
+            if alternative.diagnostics.is_empty() {
+                self.diagnostics.truncate(mark);
+            }
+        }
+    }
+
+    fn validate_value(&mut self, path: &str, value: &Value, schema: &Value) {
+        if value.is_null() {
+            return;
+        }
+        if let Some(val_type) = schema.get("valType").and_then(Value::as_str) {
+            self.validate_primitive(path, value, val_type, schema);
+        } else if let Some(items) = schema.get("items").and_then(Value::as_object) {
+            let item_schema = match items.values().next() {
+                Some(item_schema) => item_schema,
+                None => return,
+            };
+            match value.as_array() {
+                Some(array) => {
+                    for (index, item) in array.iter().enumerate() {
+                        let item_path = format!("{}[{}]", path, index);
+                        match item.as_object() {
+                            Some(object) if path.ends_with("transforms") => {
+                                self.validate_transform(&item_path, object)
+                            }
+                            Some(object) => self.validate_object(&item_path, object, item_schema),
+                            None if item.is_null() => {}
+                            None => self.report(
+                                &item_path,
+                                DiagnosticKind::WrongType,
+                                "expected an object",
+                            ),
+                        }
+                    }
+                }
+                None => self.report(path, DiagnosticKind::WrongType, "expected an array"),
+            }
+        } else {
+            match value.as_object() {
+                Some(object) => self.validate_object(path, object, schema),
+                None => self.report(path, DiagnosticKind::WrongType, "expected an object"),
+            }
+        }
+    }
+
+    fn validate_transform(&mut self, path: &str, transform: &Map<String, Value>) {
+        let transform_type = transform.get("type").and_then(Value::as_str).unwrap_or("");
+        let schema = self.schema;
+        match schema["transforms"].get(transform_type) {
+            Some(transform_schema) => {
+                self.validate_object(path, transform, &transform_schema["attributes"])
+            }
+            None => self.report(
+                &format!("{}.type", path),
+                DiagnosticKind::InvalidValue,
+                format!("unknown transform type \"{}\"", transform_type),
+            ),
+        }
+    }
+
+    fn validate_primitive(&mut self, path: &str, value: &Value, val_type: &str, schema: &Value) {
+        if let Some(extras) = schema.get("extras").and_then(Value::as_array) {
+            if extras.contains(value) {
+                return;
+            }
+        }
+        let array_ok = schema.get("arrayOk").and_then(Value::as_bool) == Some(true);
+        match value.as_array() {
+            Some(array) if array_ok && accepts_arrays(val_type) => {
+                for (index, item) in array.iter().enumerate() {
+                    if !item.is_null() {
+                        self.check_primitive(
+                            &format!("{}[{}]", path, index),
+                            item,
+                            val_type,
+                            schema,
+                            true,
+                        );
+                    }
+                }
+            }
+            _ => self.check_primitive(path, value, val_type, schema, false),
+        }
+    }
+
+    fn check_primitive(
+        &mut self,
+        path: &str,
+        value: &Value,
+        val_type: &str,
+        schema: &Value,
+        in_array: bool,
+    ) {
+        match val_type {
+            "any" => {}
+            "data_array" => {
+                if !value.is_array() {
+                    self.report(path, DiagnosticKind::WrongType, "expected an array");
+                }
+            }
+            "boolean" => {
+                if !value.is_boolean() {
+                    self.report(path, DiagnosticKind::WrongType, "expected a boolean");
+                }
+            }
+            "number" | "integer" => self.check_number(path, value, val_type == "integer", schema),
+            "angle" => {
+                if as_number(value).is_none() && value != "auto" {
+                    self.report(
+                        path,
+                        DiagnosticKind::WrongType,
+                        "expected an angle in degrees",
+                    );
+                }
+            }
+            "string" => {
+                let strict = schema.get("strict").and_then(Value::as_bool) == Some(true);
+                if !(value.is_string() || (!strict && value.is_number())) {
+                    self.report(path, DiagnosticKind::WrongType, "expected a string");
+                } else if let Some(values) = schema.get("values").and_then(Value::as_array) {
+                    if !values.contains(value) {
+                        self.report_invalid_value(path, value, values);
+                    }
+                }
+            }
+            "color" => {
+                // Numeric colors are mapped through the colorscale.
+                if !(value.is_string() || (in_array && value.is_number())) {
+                    self.report(path, DiagnosticKind::WrongType, "expected a color");
+                }
+            }
+            "colorlist" => match value.as_array() {
+                Some(colors) if colors.iter().all(Value::is_string) => {}
+                _ => self.report(
+                    path,
+                    DiagnosticKind::WrongType,
+                    "expected an array of colors",
+                ),
+            },
+            "colorscale" => {
+                let valid = match value {
+                    Value::String(_) => true,
+                    Value::Array(stops) => stops.iter().all(|stop| match stop.as_array() {
+                        Some(pair) => {
+                            pair.len() == 2 && as_number(&pair[0]).is_some() && pair[1].is_string()
+                        }
+                        None => false,
+                    }),
+                    _ => false,
+                };
+                if !valid {
+                    self.report(
+                        path,
+                        DiagnosticKind::WrongType,
+                        "expected a colorscale name or an array of [number, color] pairs",
+                    );
+                }
+            }
+            "enumerated" => {
+                let values = schema["values"].as_array().cloned().unwrap_or_default();
+                let coerce_number =
+                    schema.get("coerceNumber").and_then(Value::as_bool) == Some(true);
+                let found = values.iter().any(|allowed| {
+                    enumerated_matches(allowed, value)
+                        || (coerce_number
+                            && as_number(value).is_some()
+                            && as_number(value) == as_number(allowed))
+                });
+                if !found {
+                    self.report_invalid_value(path, value, &values);
+                }
+            }
+            "flaglist" => self.check_flaglist(path, value, schema),
+            "subplotid" => {
+                let pattern = match schema.get("regex").and_then(Value::as_str) {
+                    Some(regex) => regex.to_owned(),
+                    None => format!(
+                        "/^{}([2-9]|[1-9][0-9]+)?$/",
+                        schema["dflt"].as_str().unwrap_or("")
+                    ),
+                };
+                match value.as_str() {
+                    Some(id) if subplot_pattern_matches(&pattern, id) => {}
+                    Some(id) => self.report(
+                        path,
+                        DiagnosticKind::InvalidValue,
+                        format!("\"{}\" is not a valid subplot id", id),
+                    ),
+                    None => self.report(path, DiagnosticKind::WrongType, "expected a subplot id"),
+                }
+            }
+            "info_array" => self.check_info_array(path, value, schema),
+            _ => {}
+        }
+    }
+
+    fn check_number(&mut self, path: &str, value: &Value, integer: bool, schema: &Value) {
+        let number = match as_number(value) {
+            Some(number) => number,
+            None => {
+                let expected = if integer { "an integer" } else { "a number" };
+                self.report(
+                    path,
+                    DiagnosticKind::WrongType,
+                    format!("expected {}", expected),
+                );
+                return;
+            }
+        };
+        if integer && number.fract() != 0.0 {
+            self.report(path, DiagnosticKind::WrongType, "expected an integer");
+            return;
+        }
+        let min = schema.get("min").and_then(Value::as_f64);
+        let max = schema.get("max").and_then(Value::as_f64);
+        if min.map_or(false, |min| number < min) || max.map_or(false, |max| number > max) {
+            let range = match (min, max) {
+                (Some(min), Some(max)) => format!("between {} and {}", min, max),
+                (Some(min), None) => format!("at least {}", min),
+                (None, Some(max)) => format!("at most {}", max),
+                (None, None) => unreachable!(),
+            };
+            self.report(
+                path,
+                DiagnosticKind::OutOfRange,
+                format!("{} is out of range, expected a value {}", number, range),
+            );
+        }
+    }
+
+    fn check_flaglist(&mut self, path: &str, value: &Value, schema: &Value) {
+        let flags = match value.as_str() {
+            Some(flags) => flags,
+            None => {
+                self.report(
+                    path,
+                    DiagnosticKind::WrongType,
+                    "expected a string of flags",
+                );
+                return;
+            }
+        };
+        let allowed = schema["flags"].as_array().cloned().unwrap_or_default();
+        for flag in flags.split('+') {
+            if !allowed.iter().any(|allowed| allowed == flag) {
+                let expected: Vec<String> = allowed.iter().map(Value::to_string).collect();
+                self.report(
+                    path,
+                    DiagnosticKind::InvalidValue,
+                    format!(
+                        "invalid flag \"{}\", expected flags from {} joined with '+'",
+                        flag,
+                        expected.join(", ")
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_info_array(&mut self, path: &str, value: &Value, schema: &Value) {
+        let array = match value.as_array() {
+            Some(array) => array,
+            None => {
+                self.report(path, DiagnosticKind::WrongType, "expected an array");
+                return;
+            }
+        };
+        // Two dimensional info arrays are accepted as they are.
+        if schema.get("dimensions").is_some() {
+            return;
+        }
+        match schema.get("items") {
+            Some(Value::Array(items)) => {
+                for (index, (item, item_schema)) in array.iter().zip(items).enumerate() {
+                    self.validate_value(&format!("{}[{}]", path, index), item, item_schema);
+                }
+            }
+            Some(item_schema) => {
+                for (index, item) in array.iter().enumerate() {
+                    self.validate_value(&format!("{}[{}]", path, index), item, item_schema);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn report_invalid_value(&mut self, path: &str, value: &Value, values: &[Value]) {
+        let expected: Vec<String> = values.iter().map(Value::to_string).collect();
+        self.report(
+            path,
+            DiagnosticKind::InvalidValue,
+            format!(
+                "invalid value {}, expected one of {}",
+                value,
+                expected.join(", ")
+            ),
+        );
+    }
+}
+
+/// The layout attributes of the schema, including those contributed by the trace modules.
+fn layout_attributes(schema: &Value) -> Value {
+    let mut attributes = schema["layout"]["layoutAttributes"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+    // Trace modules contribute layout attributes such as `barmode` and `boxgap`.
+    if let Some(traces) = schema["traces"].as_object() {
+        for (name, trace) in traces {
+            let extra = match trace.get("layoutAttributes").and_then(Value::as_object) {
+                Some(extra) => extra,
+                None => continue,
+            };
+            // The `barmode` and `bargap` of polar bars are set on each polar subplot.
+            let target = if name == "barpolar" {
+                match attributes.get_mut("polar").and_then(Value::as_object_mut) {
+                    Some(polar) => polar,
+                    None => continue,
+                }
+            } else {
+                &mut attributes
+            };
+            for (key, value) in extra {
+                target.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+    }
+    Value::Object(attributes)
+}
+
+/// Returns the schema definitions an attribute of a container may be validated against, with the
+/// current definition first. Numbered subplots such as `xaxis2` share the definition of `xaxis`.
+fn attribute_schema<'a>(schema: &'a Value, key: &str) -> Option<Vec<&'a Value>> {
+    if SCHEMA_METADATA.contains(&key) || key.starts_with('_') {
+        return None;
+    }
+    let mut candidates = Vec::new();
+    if let Some(current) = schema.get(key).filter(|s| s.is_object()) {
+        candidates.push(current);
+    } else {
+        let base = key.trim_end_matches(|c: char| c.is_ascii_digit());
+        let number = &key[base.len()..];
+        if let Some(subplot) = schema.get(base) {
+            if subplot.get("_isSubplotObj").and_then(Value::as_bool) == Some(true)
+                && is_subplot_number(number)
+            {
+                candidates.push(subplot);
+            }
+        }
+    }
+    if let Some(deprecated) = schema.get("_deprecated").and_then(|d| d.get(key)) {
+        candidates.push(deprecated);
+    }
+    if candidates.is_empty() {
+        None
+    } else {
+        Some(candidates)
+    }
+}
+
+/// Subplot numbers start at 2 and have no leading zeros.
+fn is_subplot_number(number: &str) -> bool {
+    !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+        && !number.starts_with('0')
+        && number != "1"
+}
+
+/// Matches subplot id patterns of the form `/^x([2-9]|[1-9][0-9]+)?y([2-9]|[1-9][0-9]+)?$/`
+/// used by the schema. Patterns of any other form are not checked.
+fn subplot_pattern_matches(pattern: &str, id: &str) -> bool {
+    const NUMBER: &str = "([2-9]|[1-9][0-9]+)?";
+    let body = match pattern
+        .strip_prefix("/^")
+        .and_then(|p| p.strip_suffix("$/"))
+    {
+        Some(body) => body,
+        None => return true,
+    };
+    let parts: Vec<&str> = body.split(NUMBER).collect();
+    if parts
+        .iter()
+        .any(|part| part.chars().any(|c| !c.is_ascii_alphanumeric() && c != ' '))
+    {
+        return true;
+    }
+    let mut rest = id;
+    for (index, part) in parts.iter().enumerate() {
+        rest = match rest.strip_prefix(part) {
+            Some(rest) => rest,
+            None => return false,
+        };
+        if index + 1 < parts.len() {
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits > 0 {
+                if !is_subplot_number(&rest[..digits]) {
+                    return false;
+                }
+                rest = &rest[digits..];
+            }
+        }
+    }
+    rest.is_empty()
+}
+
+fn enumerated_matches(allowed: &Value, value: &Value) -> bool {
+    match (allowed, value) {
+        (Value::String(pattern), Value::String(id))
+            if pattern.len() > 2 && pattern.starts_with("/^") =>
+        {
+            subplot_pattern_matches(pattern, id)
+        }
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => allowed == value,
+    }
+}
+
+fn accepts_arrays(val_type: &str) -> bool {
+    !matches!(
+        val_type,
+        "data_array" | "info_array" | "colorlist" | "colorscale"
+    )
+}
+
+/// plotly.js accepts numeric strings wherever a number is expected.
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.trim().parse::<f64>().ok().filter(|n| n.is_finite()),
+        _ => None,
+    }
+}
+
+/// Attributes holding one value per point of traces that are not defined on a grid.
+const POINT_ATTRIBUTES: [&str; 7] = ["x", "y", "z", "open", "high", "low", "close"];
+
+/// Trace types whose `x` and `y` arrays describe the axes of the `z` grid instead of points.
+const GRIDDED_TRACES: [&str; 4] = ["heatmap", "heatmapgl", "contour", "surface"];
+
+/// Precomputed box plot statistics, one value per box.
+const BOX_STATISTICS: [&str; 8] = [
+    "q1",
+    "median",
+    "q3",
+    "lowerfence",
+    "upperfence",
+    "mean",
+    "sd",
+    "notchspan",
+];
+
+/// Checks a figure of the form `{"data": [...], "layout": {...}}` for references to undefined axes
+/// and for data arrays of inconsistent lengths.
+pub(crate) fn lint_figure(figure: &Value) -> Vec<Diagnostic> {
+    let empty = Map::new();
+    let layout = figure
+        .get("layout")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut diagnostics = Vec::new();
+
+    if let Some(traces) = figure.get("data").and_then(Value::as_array) {
+        for (index, trace) in traces.iter().enumerate() {
+            if let Some(trace) = trace.as_object() {
+                lint_trace(index, trace, layout, &mut diagnostics);
+            }
+        }
+    }
+
+    for (key, axis) in layout {
+        if axis_id(key).is_none() {
+            continue;
+        }
+        for attribute in &["anchor", "overlaying"] {
+            if let Some(reference) = axis.get(*attribute).and_then(Value::as_str) {
+                if let Some(layout_key) = missing_axis(reference, layout) {
+                    diagnostics.push(Diagnostic::new(
+                        None,
+                        &format!("{}.{}", key, attribute),
+                        DiagnosticKind::MissingAxis,
+                        format!(
+                            "references axis \"{}\" but the layout has no \"{}\"",
+                            reference, layout_key
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+fn lint_trace(
+    index: usize,
+    trace: &Map<String, Value>,
+    layout: &Map<String, Value>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for attribute in &["xaxis", "yaxis"] {
+        if let Some(reference) = trace.get(*attribute).and_then(Value::as_str) {
+            if let Some(layout_key) = missing_axis(reference, layout) {
+                diagnostics.push(Diagnostic::new(
+                    Some(index),
+                    attribute,
+                    DiagnosticKind::MissingAxis,
+                    format!(
+                        "references axis \"{}\" but the layout has no \"{}\"",
+                        reference, layout_key
+                    ),
+                ));
+            }
+        }
+    }
+
+    let trace_type = trace
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("scatter");
+    let points = if GRIDDED_TRACES.contains(&trace_type) {
+        None
+    } else {
+        check_lengths(index, trace, &POINT_ATTRIBUTES, "values", diagnostics)
+    };
+
+    if let (Some(points), Some(sizes)) = (
+        points,
+        trace
+            .get("marker")
+            .and_then(|marker| marker.get("size"))
+            .and_then(Value::as_array),
+    ) {
+        if sizes.len() < points {
+            diagnostics.push(Diagnostic::new(
+                Some(index),
+                "marker.size",
+                DiagnosticKind::LengthMismatch,
+                format!(
+                    "marker.size has {} values but the trace has {} points",
+                    sizes.len(),
+                    points
+                ),
+            ));
+        }
+    }
+
+    if trace_type == "box" {
+        check_lengths(index, trace, &BOX_STATISTICS, "boxes", diagnostics);
+    }
+}
+
+/// Reports every array among `attributes` whose length differs from the first one present and
+/// returns that length.
+fn check_lengths(
+    index: usize,
+    trace: &Map<String, Value>,
+    attributes: &[&str],
+    unit: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<usize> {
+    let mut reference: Option<(&str, usize)> = None;
+    for attribute in attributes {
+        let length = match trace.get(*attribute).and_then(Value::as_array) {
+            Some(array) => array.len(),
+            None => continue,
+        };
+        match reference {
+            None => reference = Some((attribute, length)),
+            Some((first, expected)) if length != expected => diagnostics.push(Diagnostic::new(
+                Some(index),
+                attribute,
+                DiagnosticKind::LengthMismatch,
+                format!(
+                    "{} has {} {} but {} has {}",
+                    attribute, length, unit, first, expected
+                ),
+            )),
+            Some(_) => {}
+        }
+    }
+    reference.map(|(_, length)| length)
+}
+
+/// Splits a layout key such as `xaxis3` into the axis id `x3`.
+fn axis_id(key: &str) -> Option<String> {
+    for letter in &["x", "y"] {
+        if let Some(number) = key.strip_prefix(&format!("{}axis", letter)) {
+            if number.is_empty() || is_subplot_number(number) {
+                return Some(format!("{}{}", letter, number));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the layout key an axis reference such as `y3` requires if the layout does not define
+/// it. References to the default axes, `free` and `paper` are always satisfied.
+fn missing_axis(reference: &str, layout: &Map<String, Value>) -> Option<String> {
+    let reference = reference.trim_end_matches(" domain");
+    let letter = match reference.chars().next() {
+        Some(letter) if letter == 'x' || letter == 'y' => letter,
+        _ => return None,
+    };
+    let number = &reference[1..];
+    if number.is_empty() || !is_subplot_number(number) {
+        return None;
+    }
+    let layout_key = format!("{}axis{}", letter, number);
+    if layout.contains_key(&layout_key) {
+        None
+    } else {
+        Some(layout_key)
+    }
+}
+
+/// Asserts that `trace` serializes as `expected`, and that the schema accepts it in a figure of
+/// its own.
+#[cfg(test)]
+pub(crate) fn assert_trace_json<T: serde::Serialize>(trace: &T, expected: Value) {
+    let value = serde_json::to_value(trace).unwrap();
+    assert_eq!(value, expected);
+    let figure = serde_json::json!({"data": [value], "layout": {}});
+    assert_eq!(validate_figure(&figure), vec![]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn validate(figure: Value) -> Vec<Diagnostic> {
+        validate_figure(&figure)
+    }
+
+    #[test]
+    fn test_valid_figure() {
+        let figure = json!({
+            "data": [{"type": "scatter", "x": [1, 2], "y": [3, 4], "mode": "lines+markers",
+                      "marker": {"size": [1, 2], "color": ["red", 3]}, "xaxis": "x2"}],
+            "layout": {"title": "deprecated string title", "xaxis2": {"ticks": "outside"},
+                       "barmode": "stack", "dragmode": "zoom"}
+        });
+        assert_eq!(validate(figure), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_attribute() {
+        let figure = json!({"data": [{"type": "bar", "colour": "red"}], "layout": {"xaxis1": {}}});
+        let diagnostics = validate(figure);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].trace, Some(0));
+        assert_eq!(diagnostics[0].attribute, "colour");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::UnknownAttribute);
+        assert_eq!(diagnostics[1].trace, None);
+        assert_eq!(diagnostics[1].attribute, "xaxis1");
+    }
+
+    #[test]
+    fn test_invalid_enumerated_value() {
+        let figure = json!({"layout": {"dragmode": "zoon", "xaxis": {"ticks": "outsid"}}});
+        let diagnostics = validate(figure);
+        let attributes: Vec<&str> = diagnostics.iter().map(|d| d.attribute.as_str()).collect();
+        assert_eq!(attributes, vec!["dragmode", "xaxis.ticks"]);
+        assert!(diagnostics
+            .iter()
+            .all(|d| d.kind == DiagnosticKind::InvalidValue));
+        assert!(diagnostics[0]
+            .to_string()
+            .starts_with("layout.dragmode: invalid value \"zoon\""));
+    }
+
+    #[test]
+    fn test_polar_bar_attributes() {
+        let figure = json!({"layout": {"polar2": {"barmode": "overlay", "bargap": 0.2}}});
+        assert_eq!(validate(figure), vec![]);
+        let diagnostics = validate(json!({"layout": {"polar": {"barmode": "group"}}}));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].attribute, "polar.barmode");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_wrong_type_and_range() {
+        let figure = json!({
+            "data": [{"type": "scatter", "x": 1, "opacity": 2.0, "marker": {"size": "big"},
+                      "mode": "lines+dots", "yaxis": "z"}]
+        });
+        let kinds: Vec<(String, DiagnosticKind)> = validate(figure)
+            .into_iter()
+            .map(|d| (d.attribute, d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("marker.size".to_owned(), DiagnosticKind::WrongType),
+                ("mode".to_owned(), DiagnosticKind::InvalidValue),
+                ("opacity".to_owned(), DiagnosticKind::OutOfRange),
+                ("x".to_owned(), DiagnosticKind::WrongType),
+                ("yaxis".to_owned(), DiagnosticKind::InvalidValue),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_linked_arrays() {
+        let figure = json!({"layout": {"shapes": [{"type": "rect", "x0": 0, "fill": "red"}]}});
+        let diagnostics = validate(figure);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].attribute, "shapes[0].fill");
+    }
+
+    #[test]
+    fn test_unknown_trace_type() {
+        let diagnostics = validate(json!({"data": [{"type": "scater"}]}));
+        assert_eq!(diagnostics[0].attribute, "type");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_subplot_pattern() {
+        let pattern = "/^x([2-9]|[1-9][0-9]+)?y([2-9]|[1-9][0-9]+)?$/";
+        assert!(subplot_pattern_matches(pattern, "xy"));
+        assert!(subplot_pattern_matches(pattern, "x2y13"));
+        assert!(!subplot_pattern_matches(pattern, "x1y"));
+        assert!(!subplot_pattern_matches(pattern, "x2"));
+        assert!(!subplot_pattern_matches(pattern, "x02y"));
+    }
+
+    #[test]
+    fn test_lint_axis_references() {
+        let figure = json!({
+            "data": [{"type": "scatter", "x": [1], "y": [2], "xaxis": "x2", "yaxis": "y3"}],
+            "layout": {"xaxis2": {"anchor": "y"}, "yaxis2": {"overlaying": "y4", "anchor": "free"}}
+        });
+        let diagnostics = lint_figure(&figure);
+        let found: Vec<(Option<usize>, &str)> = diagnostics
+            .iter()
+            .map(|d| (d.trace, d.attribute.as_str()))
+            .collect();
+        assert_eq!(found, vec![(Some(0), "yaxis"), (None, "yaxis2.overlaying")]);
+        assert!(diagnostics
+            .iter()
+            .all(|d| d.kind == DiagnosticKind::MissingAxis));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "data[0].yaxis: references axis \"y3\" but the layout has no \"yaxis3\""
+        );
+    }
+
+    #[test]
+    fn test_lint_lengths() {
+        let figure = json!({
+            "data": [
+                {"type": "scatter", "x": [1, 2, 3], "y": [1, 2], "marker": {"size": [4, 5]}},
+                {"type": "heatmap", "x": [1, 2], "y": [1], "z": [[1, 2]]},
+                {"type": "box", "q1": [1, 2], "median": [2, 3], "q3": [3], "x": ["a", "b"]},
+                {"type": "bar", "x": [1, 2], "y": [1, 2], "marker": {"size": 3}},
+                {"type": "surface", "x": [1, 2, 3], "y": [1, 2], "z": [[1, 2, 3], [4, 5, 6]]},
+                {"type": "scatter3d", "x": [1, 2], "y": [1, 2], "z": [1], "marker": {"size": [1]}},
+                {"type": "mesh3d", "x": [0, 1, 0], "y": [0, 0, 1], "z": [0, 0]},
+                {"type": "histogram2d", "x": [1, 2], "y": [1, 2], "z": [1, 2]}
+            ]
+        });
+        let found: Vec<(Option<usize>, String, DiagnosticKind)> = lint_figure(&figure)
+            .into_iter()
+            .map(|d| (d.trace, d.attribute, d.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(0), "y".to_owned(), DiagnosticKind::LengthMismatch),
+                (
+                    Some(0),
+                    "marker.size".to_owned(),
+                    DiagnosticKind::LengthMismatch
+                ),
+                (Some(2), "q3".to_owned(), DiagnosticKind::LengthMismatch),
+                (Some(5), "z".to_owned(), DiagnosticKind::LengthMismatch),
+                (
+                    Some(5),
+                    "marker.size".to_owned(),
+                    DiagnosticKind::LengthMismatch
+                ),
+                (Some(6), "z".to_owned(), DiagnosticKind::LengthMismatch),
+            ]
+        );
+    }
+}
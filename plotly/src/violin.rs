@@ -0,0 +1,305 @@
+//! Violin plot
+
+use crate::box_plot::BoxPoints;
+use crate::common::color::ColorWrapper;
+use crate::common::{Dim, FlagNames, Flags, HoverInfo, Label, Line, Marker, Orientation, PlotType};
+use crate::layout::{XAxisId, YAxisId};
+use crate::private;
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// Side of the axis on which the density is drawn.
+#[derive(Serialize, Clone, Debug)]
+pub enum ViolinSide {
+    #[serde(rename = "both")]
+    Both,
+    #[serde(rename = "positive")]
+    Positive,
+    #[serde(rename = "negative")]
+    Negative,
+}
+
+/// How violins of the same `scale_group` are scaled against each other.
+#[derive(Serialize, Clone, Debug)]
+pub enum ScaleMode {
+    /// Every violin has the same maximum width.
+    #[serde(rename = "width")]
+    Width,
+    /// The widths are proportional to the number of samples.
+    #[serde(rename = "count")]
+    Count,
+}
+
+/// Extent of the density along the data axis.
+#[derive(Serialize, Clone, Debug)]
+pub enum SpanMode {
+    /// From the smallest sample minus two bandwidths to the largest one plus two bandwidths.
+    #[serde(rename = "soft")]
+    Soft,
+    /// From the smallest to the largest sample.
+    #[serde(rename = "hard")]
+    Hard,
+    /// The range given with `Violin::span`.
+    #[serde(rename = "manual")]
+    Manual,
+}
+
+/// Flags of `Violin::hover_on`, the parts of a violin that show hover labels, combined with `|`,
+/// e.g. `HoverOn::VIOLINS | HoverOn::POINTS`. `ALL` is ignored when combined with other flags.
+pub type HoverOn = Flags<HoverOnNames>;
+
+/// The flag names of `HoverOn`.
+pub enum HoverOnNames {}
+
+impl FlagNames for HoverOnNames {
+    const NAMES: &'static [&'static str] = &["violins", "points", "kde"];
+    const EXCLUSIVE: &'static [&'static str] = &["all"];
+    const EMPTY: &'static str = "all";
+}
+
+impl HoverOn {
+    pub const VIOLINS: HoverOn = Flags::flag(0);
+    pub const POINTS: HoverOn = Flags::flag(1);
+    /// The kernel density estimate, a label for the density at the hovered position.
+    pub const KDE: HoverOn = Flags::flag(2);
+    pub const ALL: HoverOn = Flags::flag(3);
+}
+
+/// A box plot drawn inside the violin.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct ViolinBox {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    /// Width relative to the width of the violin, between 0 and 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+}
+
+impl ViolinBox {
+    pub fn new() -> ViolinBox {
+        Default::default()
+    }
+}
+
+/// A line at the mean of the samples.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct MeanLine {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+}
+
+impl MeanLine {
+    pub fn new() -> MeanLine {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Violin<Y, X>
+where
+    Y: Serialize + Default,
+    X: Serialize + Default,
+{
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Vec<X>>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Vec<Y>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<XAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<YAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "offsetgroup")]
+    offset_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<ColorWrapper>,
+    /// Bandwidth of the kernel density estimate, computed with Silverman's rule of thumb when
+    /// unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bandwidth: Option<f64>,
+    /// Violins with the same scale group are scaled together according to the `scale_mode`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalegroup")]
+    scale_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalemode")]
+    scale_mode: Option<ScaleMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spanmode")]
+    span_mode: Option<SpanMode>,
+    /// Range of the density with `SpanMode::Manual`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    span: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<ViolinSide>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "box")]
+    box_: Option<ViolinBox>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "meanline")]
+    mean_line: Option<MeanLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    points: Option<private::TruthyEnum<BoxPoints>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "pointpos")]
+    point_pos: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jitter: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+}
+
+impl<Y> Violin<Y, f64>
+where
+    Y: Serialize + Default,
+{
+    pub fn new(y: Vec<Y>) -> Violin<Y, f64> {
+        Violin {
+            r#type: PlotType::Violin,
+            x: None,
+            y: Some(y),
+            ..Default::default()
+        }
+    }
+}
+
+impl<Y, X> Violin<Y, X>
+where
+    Y: Serialize + Default,
+    X: Serialize + Default,
+{
+    pub fn new_xy(x: Vec<X>, y: Vec<Y>) -> Violin<Y, X> {
+        Violin {
+            r#type: PlotType::Violin,
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        }
+    }
+}
+
+impl<X> Violin<f64, X>
+where
+    X: Serialize + Default,
+{
+    /// A violin of the `x` samples, drawn along the x axis.
+    pub fn horizontal(x: Vec<X>) -> Violin<f64, X> {
+        Violin {
+            r#type: PlotType::Violin,
+            x: Some(x),
+            y: None,
+            ..Default::default()
+        }
+    }
+}
+
+impl<X, Y> Trace for Violin<X, Y>
+where
+    X: Serialize + Default,
+    Y: Serialize + Default,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_horizontal_violin() {
+        let violin = Violin::horizontal(vec![1.0, 2.0, 2.5]);
+        let expected = json!({"type": "violin", "x": [1.0, 2.0, 2.5]});
+        assert_eq!(to_value(violin).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_violin() {
+        let violin = Violin::new_xy(vec!["a", "a", "b"], vec![1.0, 2.5, 3.0])
+            .side(ViolinSide::Positive)
+            .bandwidth(0.5)
+            .scale_group("group")
+            .scale_mode(ScaleMode::Count)
+            .span_mode(SpanMode::Manual)
+            .span(vec![0., 4.])
+            .mean_line(MeanLine::new().visible(true))
+            .box_(ViolinBox::new().visible(true).width(0.2))
+            .points(BoxPoints::False)
+            .jitter(0.3)
+            .point_pos(-1.5)
+            .hover_on(HoverOn::VIOLINS | HoverOn::KDE);
+        let expected = json!({
+            "type": "violin",
+            "x": ["a", "a", "b"],
+            "y": [1.0, 2.5, 3.0],
+            "bandwidth": 0.5,
+            "scalegroup": "group",
+            "scalemode": "count",
+            "spanmode": "manual",
+            "span": [0., 4.],
+            "side": "positive",
+            "box": {"visible": true, "width": 0.2},
+            "meanline": {"visible": true},
+            "points": false,
+            "pointpos": -1.5,
+            "jitter": 0.3,
+            "hoveron": "violins+kde",
+        });
+        assert_trace_json(&violin, expected);
+    }
+
+    #[test]
+    fn test_serialize_hover_on() {
+        let flags = vec![
+            HoverOn::ALL,
+            HoverOn::POINTS | HoverOn::VIOLINS,
+            HoverOn::ALL | HoverOn::KDE,
+            HoverOn::raw("violins+points"),
+        ];
+        assert_eq!(
+            to_value(flags).unwrap(),
+            json!(["all", "violins+points", "kde", "violins+points"])
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! Volume plot
+
+use crate::common::{ColorBar, ColorScale, Dim, FlagNames, Flags, HoverInfo, Label, PlotType};
+use crate::layout::SceneId;
+use crate::mesh3d::Contour;
+use crate::surface::{Lighting, Position};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+#[cfg(feature = "plotly_ndarray")]
+use ndarray::{Array, Ix1, Ix3};
+
+/// Flags of `IsoSurfaces::pattern`, the faces of the cells drawn on the isosurfaces, combined with
+/// `|`, e.g. `SurfacePattern::A | SurfacePattern::C`. `ALL`, `ODD` and `EVEN` are ignored when
+/// combined with other flags.
+pub type SurfacePattern = Flags<SurfacePatternNames>;
+
+/// The flag names of `SurfacePattern`.
+pub enum SurfacePatternNames {}
+
+impl FlagNames for SurfacePatternNames {
+    const NAMES: &'static [&'static str] = &["A", "B", "C", "D", "E"];
+    const EXCLUSIVE: &'static [&'static str] = &["all", "odd", "even"];
+    const EMPTY: &'static str = "all";
+}
+
+impl SurfacePattern {
+    pub const A: SurfacePattern = Flags::flag(0);
+    pub const B: SurfacePattern = Flags::flag(1);
+    pub const C: SurfacePattern = Flags::flag(2);
+    pub const D: SurfacePattern = Flags::flag(3);
+    pub const E: SurfacePattern = Flags::flag(4);
+    /// Every face, the default.
+    pub const ALL: SurfacePattern = Flags::flag(5);
+    pub const ODD: SurfacePattern = Flags::flag(6);
+    pub const EVEN: SurfacePattern = Flags::flag(7);
+}
+
+/// The isosurfaces drawn between `isomin` and `isomax`.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct IsoSurfaces {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    /// Number of isosurfaces, including the ones at `isomin` and `isomax`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+    /// Fraction of the surfaces filled, between 0 and 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<SurfacePattern>,
+}
+
+impl IsoSurfaces {
+    pub fn new() -> IsoSurfaces {
+        Default::default()
+    }
+}
+
+/// The faces of the cells between the isosurfaces.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct SpaceFrame {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<f64>,
+}
+
+impl SpaceFrame {
+    pub fn new() -> SpaceFrame {
+        Default::default()
+    }
+}
+
+/// Slices of the volume normal to one axis.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Slice {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    /// Coordinates of the slices, all the grid points along the axis when empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locations: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<f64>,
+}
+
+impl Slice {
+    pub fn new() -> Slice {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Slices {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Slice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Slice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Slice>,
+}
+
+impl Slices {
+    pub fn new() -> Slices {
+        Default::default()
+    }
+}
+
+/// The surface closing the volume at the two ends of one axis.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Cap {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<f64>,
+}
+
+impl Cap {
+    pub fn new() -> Cap {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Caps {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Cap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Cap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Cap>,
+}
+
+impl Caps {
+    pub fn new() -> Caps {
+        Default::default()
+    }
+}
+
+/// Opacity of the values of a `Volume`, relative to its `opacity`.
+#[derive(Serialize, Clone, Debug)]
+pub enum OpacityScale {
+    /// Opaque at the lowest value, transparent at the highest.
+    #[serde(rename = "min")]
+    Min,
+    /// Opaque at the highest value, transparent at the lowest.
+    #[serde(rename = "max")]
+    Max,
+    /// Opaque at both ends of the values, transparent in the middle.
+    #[serde(rename = "extremes")]
+    Extremes,
+    #[serde(rename = "uniform")]
+    Uniform,
+    /// Pairs of a normalized value and its opacity, both between 0 and 1, e.g.
+    /// `vec![(0., 1.), (0.5, 0.), (1., 1.)]`.
+    #[serde(untagged)]
+    Custom(Vec<(f64, f64)>),
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Volume<X, Y, Z, V> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    z: Vec<Z>,
+    #[field_setter(skip)]
+    value: Vec<V>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "opacityscale")]
+    opacity_scale: Option<OpacityScale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// The scene the volume is drawn in, `SceneId::new(1)` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<SceneId>,
+    /// Lowest value drawn, the minimum of the values by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "isomin")]
+    iso_min: Option<f64>,
+    /// Highest value drawn, the maximum of the values by default.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "isomax")]
+    iso_max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    surface: Option<IsoSurfaces>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slices: Option<Slices>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caps: Option<Caps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contour: Option<Contour>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lighting: Option<Lighting>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lightposition")]
+    light_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<X, Y, Z, V> Volume<X, Y, Z, V> {
+    /// A volume with the coordinates and value of every point of the grid.
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>, value: Vec<V>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+        Z: Serialize + Default,
+        V: Serialize + Default,
+    {
+        Volume {
+            x,
+            y,
+            z,
+            value,
+            r#type: PlotType::Volume,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "plotly_ndarray")]
+impl<X, Y, Z, V> Volume<X, Y, Z, V>
+where
+    X: Serialize + Clone + Default,
+    Y: Serialize + Clone + Default,
+    Z: Serialize + Clone + Default,
+    V: Serialize + Clone + Default,
+{
+    /// A volume from the `values` of a grid, where `values[[i, j, k]]` is the value at
+    /// `(x[i], y[j], z[k])`. This function requires the `plotly_ndarray` feature.
+    ///
+    /// # Panics
+    /// When the shape of `values` is not the lengths of `x`, `y` and `z`.
+    pub fn from_array(
+        x: Array<X, Ix1>,
+        y: Array<Y, Ix1>,
+        z: Array<Z, Ix1>,
+        values: Array<V, Ix3>,
+    ) -> Self {
+        let (x, y, z, value) = crate::private::grid_vectors_from(x, y, z, values);
+        Volume::new(x, y, z, value)
+    }
+}
+
+impl<X, Y, Z, V> Trace for Volume<X, Y, Z, V>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::assert_trace_json;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn test_serialize_volume() {
+        let volume = Volume::new(
+            vec![0, 0, 1, 1],
+            vec![0, 1, 0, 1],
+            vec![0, 0, 0, 0],
+            vec![1., 2., 3., 4.],
+        )
+        .iso_min(1.5)
+        .iso_max(3.5)
+        .opacity(0.2)
+        .opacity_scale(OpacityScale::Custom(vec![(0., 1.), (1., 0.)]))
+        .surface(
+            IsoSurfaces::new()
+                .count(5)
+                .pattern(SurfacePattern::A | SurfacePattern::E),
+        )
+        .space_frame(SpaceFrame::new().show(true).fill(0.5))
+        .slices(Slices::new().z(Slice::new().show(true).locations(vec![0.])))
+        .caps(Caps::new().x(Cap::new().show(false)));
+        let expected = json!({
+            "x": [0, 0, 1, 1],
+            "y": [0, 1, 0, 1],
+            "z": [0, 0, 0, 0],
+            "value": [1., 2., 3., 4.],
+            "type": "volume",
+            "opacity": 0.2,
+            "opacityscale": [[0., 1.], [1., 0.]],
+            "isomin": 1.5,
+            "isomax": 3.5,
+            "surface": {"count": 5, "pattern": "A+E"},
+            "spaceframe": {"show": true, "fill": 0.5},
+            "slices": {"z": {"show": true, "locations": [0.]}},
+            "caps": {"x": {"show": false}},
+        });
+        assert_trace_json(&volume, expected);
+
+        let pattern = |p: SurfacePattern| to_value(p).unwrap();
+        assert_eq!(pattern(SurfacePattern::ALL), json!("all"));
+        assert_eq!(pattern(SurfacePattern::ODD), json!("odd"));
+        assert_eq!(
+            pattern(SurfacePattern::B | SurfacePattern::EVEN),
+            json!("B")
+        );
+        assert_eq!(to_value(OpacityScale::Extremes).unwrap(), json!("extremes"));
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    fn test_volume_from_array() {
+        let values = Array::from_shape_fn((2, 1, 2), |(i, j, k)| (4 * i + 2 * j + k) as f64);
+        let volume = Volume::from_array(
+            Array::from(vec![0., 1.]),
+            Array::from(vec![5.]),
+            Array::from(vec![-1., 1.]),
+            values,
+        );
+        let value = to_value(&volume).unwrap();
+        assert_eq!(value["x"], json!([0., 0., 1., 1.]));
+        assert_eq!(value["y"], json!([5., 5., 5., 5.]));
+        assert_eq!(value["z"], json!([-1., 1., -1., 1.]));
+        assert_eq!(value["value"], json!([0., 1., 4., 5.]));
+    }
+}
@@ -0,0 +1,256 @@
+//! Waterfall chart
+
+use crate::common::color::ColorWrapper;
+use crate::common::{
+    ConstrainText, Dim, FlagNames, Flags, Font, Label, Line, Orientation, PlotType, TextAnchor,
+    TextPosition,
+};
+use crate::layout::{XAxisId, YAxisId};
+use crate::Trace;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+/// How the value of a step is interpreted.
+#[derive(Serialize, Clone, Debug)]
+pub enum Measure {
+    /// The value is added to the running total.
+    #[serde(rename = "relative")]
+    Relative,
+    /// The step shows the running total, its value is ignored.
+    #[serde(rename = "total")]
+    Total,
+    /// The value resets the running total.
+    #[serde(rename = "absolute")]
+    Absolute,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum ConnectorMode {
+    #[serde(rename = "spanning")]
+    Spanning,
+    #[serde(rename = "between")]
+    Between,
+}
+
+/// The lines joining the ends of consecutive steps.
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Connector {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<ConnectorMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+}
+
+impl Connector {
+    pub fn new() -> Connector {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, FieldSetter)]
+pub struct Marker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+}
+
+impl Marker {
+    pub fn new() -> Marker {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+struct Steps {
+    marker: Marker,
+}
+
+/// Flags of `Waterfall::text_info`, combined with `|`, e.g. `TextInfo::LABEL | TextInfo::DELTA`.
+pub type TextInfo = Flags<TextInfoNames>;
+
+/// The flag names of `TextInfo`.
+pub enum TextInfoNames {}
+
+impl FlagNames for TextInfoNames {
+    const NAMES: &'static [&'static str] = &["label", "text", "initial", "delta", "final"];
+    const EMPTY: &'static str = "none";
+}
+
+impl TextInfo {
+    /// No text on the steps.
+    pub const NONE: TextInfo = Flags::empty();
+    pub const LABEL: TextInfo = Flags::flag(0);
+    pub const TEXT: TextInfo = Flags::flag(1);
+    /// The running total before the step.
+    pub const INITIAL: TextInfo = Flags::flag(2);
+    /// The change of the step.
+    pub const DELTA: TextInfo = Flags::flag(3);
+    /// The running total after the step.
+    pub const FINAL: TextInfo = Flags::flag(4);
+}
+
+#[derive(Serialize, Debug, Default, FieldSetter)]
+pub struct Waterfall<X, Y> {
+    #[field_setter(skip)]
+    x: Vec<X>,
+    #[field_setter(skip)]
+    y: Vec<Y>,
+    #[field_setter(skip)]
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    /// Measure of every step, `Measure::Relative` for steps without one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    measure: Option<Vec<Measure>>,
+    /// Value the first step starts from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connector: Option<Connector>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    increasing: Option<Steps>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decreasing: Option<Steps>,
+    #[field_setter(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    totals: Option<Steps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textangle")]
+    text_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextanchor")]
+    inside_text_anchor: Option<TextAnchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "constraintext")]
+    constrain_text: Option<ConstrainText>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<XAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<YAxisId>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "offsetgroup")]
+    offset_group: Option<String>,
+}
+
+impl<X, Y> Waterfall<X, Y> {
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Self
+    where
+        X: Serialize + Default,
+        Y: Serialize + Default,
+    {
+        Waterfall {
+            x,
+            y,
+            r#type: PlotType::Waterfall,
+            ..Default::default()
+        }
+    }
+
+    /// Style of the steps increasing the running total.
+    pub fn increasing(mut self, marker: Marker) -> Self {
+        self.increasing = Some(Steps { marker });
+        self
+    }
+
+    /// Style of the steps decreasing the running total.
+    pub fn decreasing(mut self, marker: Marker) -> Self {
+        self.decreasing = Some(Steps { marker });
+        self
+    }
+
+    /// Style of the `Measure::Total` and `Measure::Absolute` steps.
+    pub fn totals(mut self, marker: Marker) -> Self {
+        self.totals = Some(Steps { marker });
+        self
+    }
+}
+
+impl<X, Y> Trace for Waterfall<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::DashType;
+    use crate::validation::assert_trace_json;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_waterfall() {
+        let waterfall = Waterfall::new(vec!["Sales", "Costs", "Profit"], vec![60, -20, 0])
+            .measure(vec![Measure::Relative, Measure::Relative, Measure::Total])
+            .base(100.)
+            .connector(
+                Connector::new()
+                    .mode(ConnectorMode::Between)
+                    .line(Line::new().dash(DashType::Dot)),
+            )
+            .increasing(Marker::new().color("green"))
+            .decreasing(Marker::new().color("red"))
+            .totals(Marker::new().line(Line::new().width(2.)))
+            .text_info(TextInfo::DELTA | TextInfo::FINAL)
+            .orientation(Orientation::Vertical);
+        let expected = json!({
+            "x": ["Sales", "Costs", "Profit"],
+            "y": [60, -20, 0],
+            "type": "waterfall",
+            "measure": ["relative", "relative", "total"],
+            "base": 100.,
+            "orientation": "v",
+            "connector": {"mode": "between", "line": {"dash": "dot"}},
+            "increasing": {"marker": {"color": "green"}},
+            "decreasing": {"marker": {"color": "red"}},
+            "totals": {"marker": {"line": {"width": 2.}}},
+            "textinfo": "delta+final",
+        });
+        assert_trace_json(&waterfall, expected);
+    }
+}
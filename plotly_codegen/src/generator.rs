@@ -0,0 +1,821 @@
+//! Translates the plotly.js plot schema into Rust modules.
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::{Map, Value};
+
+const HEADER: &str =
+    "// This file is generated by plotly_codegen from the plotly.js plot schema. Do not edit.\n";
+
+/// Schema keys that describe an attribute container rather than name one of its attributes.
+const SCHEMA_METADATA: [&str; 9] = [
+    "role",
+    "editType",
+    "description",
+    "impliedEdits",
+    "items",
+    "_deprecated",
+    "_isSubplotObj",
+    "_isLinkedToArray",
+    "_arrayAttrRegexps",
+];
+
+const PRELUDE: [&str; 5] = ["Box", "Option", "Result", "String", "Vec"];
+
+const KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Generates one module per trace type, a `layout` module and a `common` module holding the types
+/// shared between them. The result maps file names to their contents.
+pub struct Generator<'a> {
+    schema: &'a Value,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(schema: &'a Value) -> Generator<'a> {
+        Generator { schema }
+    }
+
+    pub fn generate(&self) -> BTreeMap<String, String> {
+        let mut files = BTreeMap::new();
+        let traces = self.schema["traces"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut mod_rs = String::from(HEADER);
+        mod_rs.push_str("\npub mod common;\npub mod layout;\n");
+        for name in traces.keys() {
+            mod_rs.push_str(&format!("pub mod {};\n", module_name(name)));
+        }
+        mod_rs.push_str(
+            "\npub use self::common::{ArrayOk, PlotType};\npub use self::layout::Layout;\n",
+        );
+        for name in traces.keys() {
+            mod_rs.push_str(&format!(
+                "pub use self::{}::{};\n",
+                module_name(name),
+                trace_name(name)
+            ));
+        }
+        files.insert("mod.rs".to_owned(), mod_rs);
+        files.insert("common.rs".to_owned(), common_module(traces.keys()));
+
+        for (name, trace) in &traces {
+            let mut module = Module::new();
+            module.trace(name, &trace["attributes"]);
+            files.insert(format!("{}.rs", module_name(name)), module.render());
+        }
+
+        let mut layout = self.schema["layout"]["layoutAttributes"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+        for trace in traces.values() {
+            if let Some(extra) = trace.get("layoutAttributes").and_then(Value::as_object) {
+                for (key, value) in extra {
+                    layout.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+        let mut module = Module::new();
+        module.object(
+            "Layout",
+            "layout",
+            &layout,
+            "Layout of a plot.".to_owned(),
+            None,
+        );
+        files.insert("layout.rs".to_owned(), module.render());
+
+        files
+    }
+}
+
+fn common_module<'b, I: Iterator<Item = &'b String>>(traces: I) -> String {
+    let mut out = String::from(HEADER);
+    out.push_str(
+        r#"
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// An attribute that accepts either a single value or one value per data point.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ArrayOk<T> {
+    Scalar(T),
+    Array(Vec<T>),
+}
+
+pub(crate) fn to_value<T: Serialize>(value: T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+pub(crate) fn to_values<T: Serialize>(values: Vec<T>) -> Vec<Value> {
+    values.into_iter().map(to_value).collect()
+}
+
+/// The trace types known to the plot schema.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlotType {
+"#,
+    );
+    let traces: Vec<&String> = traces.collect();
+    for name in &traces {
+        out.push_str(&format!("    {},\n", trace_name(name)));
+    }
+    out.push_str(
+        "}\n\nimpl Serialize for PlotType {\n    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n        serializer.serialize_str(match self {\n",
+    );
+    for name in &traces {
+        out.push_str(&format!(
+            "            PlotType::{} => \"{}\",\n",
+            trace_name(name),
+            name
+        ));
+    }
+    out.push_str("        })\n    }\n}\n");
+    out
+}
+
+/// How an attribute is stored in its struct.
+#[derive(Clone, Debug, PartialEq)]
+enum FieldType {
+    Bool,
+    Number,
+    Integer,
+    Str,
+    Value,
+    Values,
+    Strings,
+    Enum(String),
+    Object(String),
+    Items(String),
+}
+
+impl FieldType {
+    fn rust_type(&self) -> String {
+        match self {
+            FieldType::Bool => "bool".to_owned(),
+            FieldType::Number => "f64".to_owned(),
+            FieldType::Integer => "i64".to_owned(),
+            FieldType::Str => "String".to_owned(),
+            FieldType::Value => "Value".to_owned(),
+            FieldType::Values => "Vec<Value>".to_owned(),
+            FieldType::Strings => "Vec<String>".to_owned(),
+            FieldType::Enum(name) | FieldType::Object(name) => name.clone(),
+            FieldType::Items(name) => format!("Vec<{}>", name),
+        }
+    }
+
+    /// Setter parameter type, generic parameters and the expression converting the parameter
+    /// `arg` into the stored type.
+    fn setter(&self, arg: &str) -> (String, &'static str, String) {
+        match self {
+            FieldType::Str => ("&str".to_owned(), "", format!("{}.to_owned()", arg)),
+            FieldType::Value => (
+                "T".to_owned(),
+                "<T: Serialize>",
+                format!("to_value({})", arg),
+            ),
+            FieldType::Values => (
+                "Vec<T>".to_owned(),
+                "<T: Serialize>",
+                format!("to_values({})", arg),
+            ),
+            FieldType::Strings => (
+                "Vec<S>".to_owned(),
+                "<S: AsRef<str>>",
+                format!("{}.iter().map(|s| s.as_ref().to_owned()).collect()", arg),
+            ),
+            _ => (self.rust_type(), "", arg.to_owned()),
+        }
+    }
+
+    /// Setter of the array variant of an `arrayOk` attribute.
+    fn array_setter(&self, arg: &str) -> (String, &'static str, String) {
+        match self {
+            FieldType::Str => (
+                "Vec<S>".to_owned(),
+                "<S: AsRef<str>>",
+                format!("{}.iter().map(|s| s.as_ref().to_owned()).collect()", arg),
+            ),
+            FieldType::Value => (
+                "Vec<T>".to_owned(),
+                "<T: Serialize>",
+                format!("to_values({})", arg),
+            ),
+            _ => (format!("Vec<{}>", self.rust_type()), "", arg.to_owned()),
+        }
+    }
+}
+
+struct Field {
+    key: String,
+    field_type: FieldType,
+    array_ok: bool,
+    subplot: bool,
+    doc: String,
+}
+
+struct Module {
+    items: Vec<String>,
+    type_names: HashSet<String>,
+    uses_serializer: bool,
+}
+
+impl Module {
+    fn new() -> Module {
+        Module {
+            items: Vec::new(),
+            type_names: HashSet::new(),
+            uses_serializer: false,
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::from(HEADER);
+        out.push_str("#![allow(unused_imports)]\n\n");
+        out.push_str("use std::collections::BTreeMap;\n\n");
+        if self.uses_serializer {
+            out.push_str("use serde::{Serialize, Serializer};\n");
+        } else {
+            out.push_str("use serde::Serialize;\n");
+        }
+        out.push_str("use serde_json::Value;\n\n");
+        out.push_str("use super::common::{to_value, to_values, ArrayOk, PlotType};\n");
+        for item in &self.items {
+            out.push('\n');
+            out.push_str(item);
+        }
+        out
+    }
+
+    fn unique_name(&mut self, name: String) -> String {
+        let mut candidate = name.clone();
+        let mut suffix = 2;
+        while self.type_names.contains(&candidate) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.type_names.insert(candidate.clone());
+        candidate
+    }
+
+    fn trace(&mut self, trace: &str, attributes: &Value) {
+        let attributes = attributes.as_object().cloned().unwrap_or_default();
+        let doc = format!("The `{}` trace.", trace);
+        self.object(&trace_name(trace), trace, &attributes, doc, Some(trace));
+    }
+
+    /// Emits a struct for an attribute container, and the types of its attributes, returning the
+    /// name of the struct.
+    fn object(
+        &mut self,
+        name: &str,
+        path: &str,
+        attributes: &Map<String, Value>,
+        doc: String,
+        trace: Option<&str>,
+    ) -> String {
+        let name = self.unique_name(name.to_owned());
+        let index = self.items.len();
+        self.items.push(String::new());
+
+        let mut fields = Vec::new();
+        for (key, schema) in attributes {
+            if SCHEMA_METADATA.contains(&key.as_str()) || !schema.is_object() {
+                continue;
+            }
+            // `*src` attributes reference Chart Studio grid columns and are of no use offline.
+            if key.ends_with("src") && attributes.contains_key(&key[..key.len() - 3]) {
+                continue;
+            }
+            let child_path = format!("{}.{}", path, key);
+            if let Some(field) = self.field(&name, key, &child_path, schema) {
+                fields.push(field);
+            }
+        }
+
+        self.items[index] = render_struct(&name, &doc, &fields, trace);
+        name
+    }
+
+    fn field(&mut self, parent: &str, key: &str, path: &str, schema: &Value) -> Option<Field> {
+        let array_ok = schema.get("arrayOk").and_then(Value::as_bool) == Some(true);
+        let subplot = schema.get("_isSubplotObj").and_then(Value::as_bool) == Some(true);
+        let child_name = format!("{}{}", parent, type_name(key));
+
+        let (field_type, doc) = match schema.get("valType").and_then(Value::as_str) {
+            Some(val_type) => {
+                let doc = attribute_doc(path, val_type, schema);
+                let field_type = match val_type {
+                    "boolean" => FieldType::Bool,
+                    "number" | "angle" => FieldType::Number,
+                    "integer" => FieldType::Integer,
+                    "string" | "color" | "subplotid" | "flaglist" => FieldType::Str,
+                    "data_array" | "info_array" => FieldType::Values,
+                    "colorlist" => FieldType::Strings,
+                    "enumerated" => {
+                        let values = schema["values"].as_array().cloned().unwrap_or_default();
+                        FieldType::Enum(self.enumeration(&child_name, path, &values))
+                    }
+                    _ => FieldType::Value,
+                };
+                (field_type, doc)
+            }
+            None => match schema.get("items").and_then(Value::as_object) {
+                Some(items) => {
+                    let (item_key, item_schema) = items.iter().next()?;
+                    let item_attributes = item_schema.as_object().cloned().unwrap_or_default();
+                    let has_attributes = item_attributes
+                        .iter()
+                        .any(|(k, v)| v.is_object() && !SCHEMA_METADATA.contains(&k.as_str()));
+                    let doc = with_description(format!("Sets the `{}` items.", path), schema);
+                    if has_attributes {
+                        let item_doc = format!("An item of `{}`.", path);
+                        let item_name = format!("{}{}", parent, type_name(item_key));
+                        let name = self.object(&item_name, path, &item_attributes, item_doc, None);
+                        (FieldType::Items(name), doc)
+                    } else {
+                        (FieldType::Values, doc)
+                    }
+                }
+                None => {
+                    let attributes = schema.as_object().cloned().unwrap_or_default();
+                    let object_doc =
+                        with_description(format!("The `{}` attributes.", path), schema);
+                    let name = self.object(&child_name, path, &attributes, object_doc, None);
+                    (
+                        FieldType::Object(name),
+                        with_description(format!("Sets the `{}` attributes.", path), schema),
+                    )
+                }
+            },
+        };
+
+        Some(Field {
+            key: key.to_owned(),
+            array_ok: array_ok && field_type != FieldType::Values,
+            field_type,
+            subplot,
+            doc,
+        })
+    }
+
+    fn enumeration(&mut self, name: &str, path: &str, values: &[Value]) -> String {
+        let name = self.unique_name(name.to_owned());
+        self.uses_serializer = true;
+
+        let mut variants: Vec<(String, String)> = Vec::new();
+        let mut taken = HashSet::new();
+        let mut numbers = false;
+        let mut ids = false;
+        for value in values {
+            match value {
+                Value::String(s) if s.len() > 2 && s.starts_with("/^") => ids = true,
+                Value::String(s) => {
+                    let variant = unique_variant(&mut taken, variant_name(s));
+                    variants.push((variant, format!("serializer.serialize_str({:?})", s)));
+                }
+                Value::Bool(b) => {
+                    let variant = unique_variant(&mut taken, variant_name(&b.to_string()));
+                    variants.push((variant, format!("serializer.serialize_bool({})", b)));
+                }
+                Value::Number(_) => numbers = true,
+                _ => {}
+            }
+        }
+
+        let mut out = format!(
+            "/// Values of `{}`.\n#[derive(Clone, Debug, PartialEq)]\npub enum {} {{\n",
+            path, name
+        );
+        for (variant, _) in &variants {
+            out.push_str(&format!("    {},\n", variant));
+        }
+        let number_variant = unique_variant(&mut taken, "Number".to_owned());
+        let id_variant = unique_variant(&mut taken, "Id".to_owned());
+        if numbers {
+            out.push_str(&format!("    {}(f64),\n", number_variant));
+        }
+        if ids {
+            out.push_str("    /// A subplot or axis id such as `x2`.\n");
+            out.push_str(&format!("    {}(String),\n", id_variant));
+        }
+        out.push_str(&format!(
+            "}}\n\nimpl Serialize for {} {{\n    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{\n        match self {{\n",
+            name
+        ));
+        for (variant, body) in &variants {
+            out.push_str(&format!("            {}::{} => {},\n", name, variant, body));
+        }
+        if numbers {
+            out.push_str(&format!(
+                "            {}::{}(n) => serializer.serialize_f64(*n),\n",
+                name, number_variant
+            ));
+        }
+        if ids {
+            out.push_str(&format!(
+                "            {}::{}(id) => serializer.serialize_str(id),\n",
+                name, id_variant
+            ));
+        }
+        if variants.is_empty() && !numbers && !ids {
+            out.push_str("            _ => serializer.serialize_unit(),\n");
+        }
+        out.push_str("        }\n    }\n}\n");
+        self.items.push(out);
+        name
+    }
+}
+
+fn render_struct(name: &str, doc: &str, fields: &[Field], trace: Option<&str>) -> String {
+    let mut out = doc_comment("", doc);
+    if trace.is_some() {
+        out.push_str("#[derive(Serialize, Clone, Debug)]\n");
+    } else {
+        out.push_str("#[derive(Serialize, Clone, Debug, Default)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", name));
+    if trace.is_some() {
+        out.push_str("    r#type: PlotType,\n");
+    }
+    for field in fields {
+        out.push_str(&format!(
+            "    #[serde(skip_serializing_if = \"Option::is_none\")]\n    {}: Option<{}>,\n",
+            field_name(&field.key),
+            stored_type(field)
+        ));
+        if field.subplot {
+            out.push_str(&format!(
+                "    #[serde(flatten)]\n    {}_n: BTreeMap<String, {}>,\n",
+                field.key,
+                field.field_type.rust_type()
+            ));
+        }
+    }
+    out.push_str("}\n");
+
+    if let Some(trace) = trace {
+        out.push_str(&format!(
+            "\nimpl Default for {} {{\n    fn default() -> Self {{\n        {} {{\n            r#type: PlotType::{},\n",
+            name,
+            name,
+            trace_name(trace)
+        ));
+        for field in fields {
+            out.push_str(&format!("            {}: None,\n", field_name(&field.key)));
+            if field.subplot {
+                out.push_str(&format!("            {}_n: BTreeMap::new(),\n", field.key));
+            }
+        }
+        out.push_str("        }\n    }\n}\n");
+        out.push_str(&format!(
+            "\nimpl crate::Trace for {} {{\n    fn serialize(&self) -> String {{\n        serde_json::to_string(self).unwrap()\n    }}\n}}\n",
+            name
+        ));
+    }
+
+    out.push_str(&format!(
+        "\nimpl {} {{\n    pub fn new() -> Self {{\n        Default::default()\n    }}\n",
+        name
+    ));
+    for field in fields {
+        out.push_str(&render_setters(field));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn stored_type(field: &Field) -> String {
+    if field.array_ok {
+        format!("ArrayOk<{}>", field.field_type.rust_type())
+    } else {
+        field.field_type.rust_type()
+    }
+}
+
+fn render_setters(field: &Field) -> String {
+    let setter = setter_name(&field.key);
+    let arg = setter.as_str();
+    let target = field_name(&field.key);
+    let (param, generics, value) = field.field_type.setter(arg);
+    let value = if field.array_ok {
+        format!("ArrayOk::Scalar({})", value)
+    } else {
+        value
+    };
+    let mut out = format!(
+        "\n{}    pub fn {}{}(mut self, {}: {}) -> Self {{\n        self.{} = Some({});\n        self\n    }}\n",
+        doc_comment("    ", &field.doc),
+        setter,
+        generics,
+        arg,
+        param,
+        target,
+        value
+    );
+
+    if field.array_ok {
+        let (param, generics, value) = field.field_type.array_setter(arg);
+        // The note belongs to the summary, ahead of the schema description.
+        let summary_end = field.doc.find("\n\n").unwrap_or(field.doc.len());
+        let doc = format!(
+            "{} One value per data point.{}",
+            &field.doc[..summary_end],
+            &field.doc[summary_end..]
+        );
+        out.push_str(&format!(
+            "\n{}    pub fn {}_array{}(mut self, {}: {}) -> Self {{\n        self.{} = Some(ArrayOk::Array({}));\n        self\n    }}\n",
+            doc_comment("    ", &doc),
+            field.key,
+            generics,
+            arg,
+            param,
+            target,
+            value
+        ));
+    }
+
+    if field.subplot {
+        out.push_str(&format!(
+            "\n    /// Sets the `{key}` attributes of the subplot with index `n`; indices 0 and 1 both refer to\n    /// `{key}` itself.\n    pub fn {key}_n(mut self, n: usize, {key}: {ty}) -> Self {{\n        if n <= 1 {{\n            self.{key} = Some({key});\n        }} else {{\n            self.{key}_n.insert(format!(\"{key}{{}}\", n), {key});\n        }}\n        self\n    }}\n",
+            key = field.key,
+            ty = field.field_type.rust_type()
+        ));
+    }
+    out
+}
+
+fn attribute_doc(path: &str, val_type: &str, schema: &Value) -> String {
+    let path = path.find('.').map_or(path, |dot| &path[dot + 1..]);
+    let mut doc = format!("Sets `{}`, ", path);
+    doc.push_str(match val_type {
+        "data_array" => "a data array",
+        "enumerated" => "an enumerated value",
+        "boolean" => "a boolean",
+        "number" => "a number",
+        "integer" => "an integer",
+        "string" => "a string",
+        "color" => "a color",
+        "colorlist" => "a list of colors",
+        "colorscale" => "a colorscale",
+        "angle" => "an angle in degrees",
+        "subplotid" => "a subplot id",
+        "flaglist" => "a combination of flags",
+        "info_array" => "an array",
+        _ => "any value",
+    });
+    match (
+        schema.get("min").and_then(Value::as_f64),
+        schema.get("max").and_then(Value::as_f64),
+    ) {
+        (Some(min), Some(max)) => doc.push_str(&format!(" between {} and {}", min, max)),
+        (Some(min), None) => doc.push_str(&format!(" of at least {}", min)),
+        (None, Some(max)) => doc.push_str(&format!(" of at most {}", max)),
+        (None, None) => {}
+    }
+    if let Some(flags) = schema.get("flags").and_then(Value::as_array) {
+        let flags: Vec<String> = flags
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|f| format!("`{}`", f))
+            .collect();
+        doc.push_str(&format!(" from {} joined with `+`", flags.join(", ")));
+    }
+    match schema.get("dflt") {
+        Some(Value::Null) | None => doc.push('.'),
+        Some(default) => doc.push_str(&format!(". Defaults to `{}`.", default)),
+    }
+    with_description(doc, schema)
+}
+
+/// Appends the `description` of an attribute in the schema as a second paragraph.
+fn with_description(summary: String, schema: &Value) -> String {
+    match schema.get("description").and_then(Value::as_str) {
+        Some(description) if !description.trim().is_empty() => {
+            format!("{}\n\n{}", summary, description.trim())
+        }
+        _ => summary,
+    }
+}
+
+/// Renders `doc` as `///` comment lines at the given indentation, wrapping its paragraphs at 100
+/// columns.
+fn doc_comment(indent: &str, doc: &str) -> String {
+    let width = 100 - indent.len() - 4;
+    let mut out = String::new();
+    for (index, paragraph) in doc.split("\n\n").enumerate() {
+        if index > 0 {
+            out.push_str(&format!("{}///\n", indent));
+        }
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.len() + 1 + word.len() > width {
+                out.push_str(&format!("{}/// {}\n", indent, line));
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        out.push_str(&format!("{}/// {}\n", indent, line));
+    }
+    out
+}
+
+/// Converts a schema name such as `scatter3d` or `error_x` into a type name.
+pub fn type_name(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(capitalize)
+        .collect()
+}
+
+/// Names of trace types; `box` becomes `BoxPlot` to avoid shadowing `std::boxed::Box`.
+pub fn trace_name(trace: &str) -> String {
+    let name = type_name(trace);
+    if PRELUDE.contains(&name.as_str()) {
+        format!("{}Plot", name)
+    } else {
+        name
+    }
+}
+
+fn module_name(trace: &str) -> String {
+    if KEYWORDS.contains(&trace) {
+        format!("{}_", trace)
+    } else {
+        trace.to_owned()
+    }
+}
+
+fn field_name(key: &str) -> String {
+    if KEYWORDS.contains(&key) {
+        format!("r#{}", key)
+    } else {
+        key.to_owned()
+    }
+}
+
+fn setter_name(key: &str) -> String {
+    if KEYWORDS.contains(&key) || key == "new" {
+        format!("{}_", key)
+    } else {
+        key.to_owned()
+    }
+}
+
+/// Converts an enumerated value such as `top left` or `>=` into a variant name.
+pub fn variant_name(value: &str) -> String {
+    let name = if value.chars().any(|c| c.is_ascii_alphanumeric()) {
+        type_name(value)
+    } else if value.is_empty() {
+        "Empty".to_owned()
+    } else {
+        value.chars().map(symbol_name).collect()
+    };
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("V{}", name)
+    } else if name == "Self" {
+        "SelfValue".to_owned()
+    } else {
+        name
+    }
+}
+
+fn unique_variant(taken: &mut HashSet<String>, name: String) -> String {
+    let mut candidate = name.clone();
+    let mut suffix = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{}{}", name, suffix);
+        suffix += 1;
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
+
+fn symbol_name(c: char) -> &'static str {
+    match c {
+        '=' => "Equal",
+        '<' => "Less",
+        '>' => "Greater",
+        '[' => "LeftBracket",
+        ']' => "RightBracket",
+        '(' => "LeftParen",
+        ')' => "RightParen",
+        '+' => "Plus",
+        '-' => "Minus",
+        '/' => "Slash",
+        '%' => "Percent",
+        '.' => "Dot",
+        ',' => "Comma",
+        '*' => "Star",
+        ' ' => "Space",
+        _ => "Symbol",
+    }
+}
+
+fn capitalize(part: &str) -> String {
+    let mut chars = part.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(type_name("scatter3d"), "Scatter3d");
+        assert_eq!(type_name("error_x"), "ErrorX");
+        assert_eq!(trace_name("box"), "BoxPlot");
+        assert_eq!(variant_name("top left"), "TopLeft");
+        assert_eq!(variant_name(">="), "GreaterEqual");
+        assert_eq!(variant_name(""), "Empty");
+        assert_eq!(variant_name("3d"), "V3d");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(setter_name("type"), "type_");
+    }
+
+    #[test]
+    fn test_generate() {
+        let schema = json!({
+            "traces": {
+                "scatter": {
+                    "attributes": {
+                        "type": "scatter",
+                        "x": {"valType": "data_array", "editType": "calc"},
+                        "xsrc": {"valType": "string", "editType": "none"},
+                        "mode": {"valType": "flaglist", "flags": ["lines", "markers"]},
+                        "marker": {
+                            "size": {
+                                "valType": "number",
+                                "min": 0,
+                                "dflt": 6,
+                                "arrayOk": true,
+                                "description": "Sets the marker size (in px)."
+                            },
+                            "symbol": {"valType": "enumerated", "values": [0, "circle", false]},
+                            "role": "object"
+                        }
+                    },
+                    "layoutAttributes": {
+                        "scattergap": {"valType": "number", "min": 0, "max": 1}
+                    }
+                }
+            },
+            "layout": {
+                "layoutAttributes": {
+                    "xaxis": {
+                        "_isSubplotObj": true,
+                        "anchor": {"valType": "enumerated", "values": ["free", "/^x([2-9]|[1-9][0-9]+)?$/"]}
+                    },
+                    "shapes": {"items": {"shape": {"x0": {"valType": "any"}}}, "role": "object"}
+                }
+            }
+        });
+        let files = Generator::new(&schema).generate();
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["common.rs", "layout.rs", "mod.rs", "scatter.rs"]
+        );
+
+        let scatter = &files["scatter.rs"];
+        assert!(scatter.contains("pub struct Scatter {\n    r#type: PlotType,"));
+        assert!(scatter.contains("x: Option<Vec<Value>>,"));
+        assert!(!scatter.contains("xsrc"));
+        assert!(scatter.contains("marker: Option<ScatterMarker>,"));
+        assert!(scatter.contains("size: Option<ArrayOk<f64>>,"));
+        assert!(scatter.contains("pub fn size_array(mut self, size: Vec<f64>) -> Self {"));
+        assert!(scatter.contains(
+            "    /// Sets `marker.size`, a number of at least 0. Defaults to `6`.\n    ///\n    /// Sets the marker size (in px).\n    pub fn size("
+        ));
+        assert!(scatter.contains("Defaults to `6`. One value per data point.\n    ///\n    /// Sets the marker size (in px).\n    pub fn size_array("));
+        assert!(scatter.contains(
+            "pub enum ScatterMarkerSymbol {\n    Circle,\n    False,\n    Number(f64),\n}"
+        ));
+        assert!(scatter.contains("impl crate::Trace for Scatter {"));
+
+        let layout = &files["layout.rs"];
+        assert!(layout.contains("scattergap: Option<f64>,"));
+        assert!(layout.contains("xaxis_n: BTreeMap<String, LayoutXaxis>,"));
+        assert!(layout.contains("pub fn xaxis_n(mut self, n: usize, xaxis: LayoutXaxis) -> Self {"));
+        assert!(layout.contains("Id(String),"));
+        assert!(layout.contains("shapes: Option<Vec<LayoutShape>>,"));
+
+        assert!(files["common.rs"].contains("PlotType::Scatter => \"scatter\","));
+        assert!(files["mod.rs"].contains("pub use self::scatter::Scatter;"));
+    }
+}
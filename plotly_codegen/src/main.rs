@@ -0,0 +1,61 @@
+//! # Plotly Codegen
+//!
+//! Generates trace and layout types for Plotly.rs from the plotly.js plot schema.
+//!
+//! ```text
+//! cargo run -p plotly_codegen -- <plot-schema.json> <output directory>
+//! ```
+//!
+//! The schema should be the unmodified output of `Plotly.PlotSchema.get()`; the copy bundled with
+//! the `plotly` crate has the attribute descriptions stripped, which the generated doc comments
+//! are built from.
+//!
+//! The output directory receives a `mod.rs`, a `common.rs` with the types shared by all modules,
+//! a `layout.rs` and one module per trace type. The files are formatted with `rustfmt` when it is
+//! available.
+
+mod generator;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use crate::generator::Generator;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <plot-schema.json> <output directory>", args[0]);
+        process::exit(2);
+    }
+    if let Err(e) = run(Path::new(&args[1]), Path::new(&args[2])) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(schema_path: &Path, output: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let schema: serde_json::Value = serde_json::from_str(&fs::read_to_string(schema_path)?)?;
+    let files = Generator::new(&schema).generate();
+
+    fs::create_dir_all(output)?;
+    let mut written: Vec<PathBuf> = Vec::new();
+    for (name, contents) in &files {
+        let path = output.join(name);
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .args(&written)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if !formatted {
+        eprintln!("warning: rustfmt failed, the generated files are not formatted");
+    }
+    println!("wrote {} files to {}", written.len(), output.display());
+    Ok(())
+}
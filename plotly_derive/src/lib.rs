@@ -0,0 +1,264 @@
+//! # Plotly Derive
+//!
+//! Derive macros used by [Plotly.rs](https://github.com/igiagkiozis/plotly) to generate the builder
+//! methods of traces, layouts and their components. The generated code refers to items of the
+//! `plotly` crate through `crate::` paths, so the macros are only usable from within `plotly`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Meta,
+    NestedMeta, PathArguments, Type,
+};
+
+/// Generates a by-value setter for every field of a struct with named fields.
+///
+/// The setter takes the name of the field (`type_` for `r#type`) and its signature depends on the
+/// type of the field, with `Option<T>` fields being set to `Some`:
+///
+/// * `String` takes a `&str`.
+/// * `ColorWrapper` takes any `C: Color`, `Vec<ColorWrapper>` a `Vec<C>`.
+/// * `NumOrStringWrapper` takes any `V: NumOrString`, `Vec<NumOrStringWrapper>` a `Vec<V>`.
+/// * `Vec<String>` takes a `Vec<S>` with `S: AsRef<str>`.
+/// * `TruthyEnum<E>` takes the enum `E`.
+/// * `Dim<T>` generates two setters, `field` taking a single value and `field_array` taking a
+///   `Vec`, with the conversions above applied to `T`.
+/// * Any other type `T` is taken as is.
+///
+/// The documentation of a field is copied to its setters. Fields marked with
+/// `#[field_setter(skip)]` get no setter.
+#[proc_macro_derive(FieldSetter, attributes(field_setter))]
+pub fn derive_field_setter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match field_setters(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn field_setters(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "FieldSetter requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FieldSetter can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut setters = Vec::new();
+    for field in fields {
+        if is_skipped(&field.attrs)? {
+            continue;
+        }
+        let ident = field.ident.as_ref().unwrap();
+        let docs: Vec<&Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .collect();
+        let (ty, optional) = match generic_argument(&field.ty, "Option") {
+            Some(inner) => (inner, true),
+            None => (&field.ty, false),
+        };
+        setters.push(setter(ident, ty, optional, &docs));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#setters)*
+        }
+    })
+}
+
+fn is_skipped(attrs: &[Attribute]) -> Result<bool, Error> {
+    let mut skip = false;
+    for attr in attrs.iter().filter(|a| a.path.is_ident("field_setter")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => skip = true,
+                        _ => return Err(Error::new_spanned(nested, "unknown field_setter option")),
+                    }
+                }
+            }
+            meta => return Err(Error::new_spanned(meta, "expected #[field_setter(...)]")),
+        }
+    }
+    Ok(skip)
+}
+
+/// The value a setter receives and how it is converted into the stored type.
+struct Conversion {
+    generics: TokenStream2,
+    param: TokenStream2,
+    value: TokenStream2,
+}
+
+fn setter(ident: &Ident, ty: &Type, optional: bool, docs: &[&Attribute]) -> TokenStream2 {
+    let field_name = ident.to_string();
+    let base_name = field_name.trim_start_matches("r#");
+    let setter_name = if field_name.starts_with("r#") {
+        Ident::new(&format!("{}_", base_name), Span::call_site())
+    } else {
+        ident.clone()
+    };
+    let wrap = |value: TokenStream2| {
+        if optional {
+            quote!(Some(#value))
+        } else {
+            value
+        }
+    };
+
+    if let Some(inner) = generic_argument(ty, "Dim") {
+        let Conversion {
+            generics: scalar_generics,
+            param: scalar_param,
+            value: scalar_value,
+        } = scalar_conversion(inner, ident);
+        let Conversion {
+            generics: array_generics,
+            param: array_param,
+            value: array_value,
+        } = array_conversion(inner, ident);
+        let array_name = Ident::new(&format!("{}_array", base_name), Span::call_site());
+        let scalar_value = wrap(quote!(crate::common::Dim::Scalar(#scalar_value)));
+        let array_value = wrap(quote!(crate::common::Dim::Vector(#array_value)));
+        return quote! {
+            #(#docs)*
+            pub fn #setter_name #scalar_generics(mut self, #ident: #scalar_param) -> Self {
+                self.#ident = #scalar_value;
+                self
+            }
+
+            #(#docs)*
+            pub fn #array_name #array_generics(mut self, #ident: #array_param) -> Self {
+                self.#ident = #array_value;
+                self
+            }
+        };
+    }
+
+    let Conversion {
+        generics,
+        param,
+        value,
+    } = match generic_argument(ty, "Vec") {
+        Some(inner) => array_conversion(inner, ident),
+        None => scalar_conversion(ty, ident),
+    };
+    let value = wrap(value);
+    quote! {
+        #(#docs)*
+        pub fn #setter_name #generics(mut self, #ident: #param) -> Self {
+            self.#ident = #value;
+            self
+        }
+    }
+}
+
+fn scalar_conversion(ty: &Type, arg: &Ident) -> Conversion {
+    if is_type(ty, "String") {
+        Conversion {
+            generics: quote!(),
+            param: quote!(&str),
+            value: quote!(#arg.to_owned()),
+        }
+    } else if is_type(ty, "ColorWrapper") {
+        Conversion {
+            generics: quote!(<C: crate::common::color::Color>),
+            param: quote!(C),
+            value: quote!(crate::common::color::Color::to_color(&#arg)),
+        }
+    } else if is_type(ty, "NumOrStringWrapper") {
+        Conversion {
+            generics: quote!(<V: crate::private::NumOrString>),
+            param: quote!(V),
+            value: quote!(crate::private::NumOrString::to_num_or_string(&#arg)),
+        }
+    } else if let Some(inner) = generic_argument(ty, "TruthyEnum") {
+        Conversion {
+            generics: quote!(),
+            param: quote!(#inner),
+            value: quote!(crate::private::TruthyEnum { e: #arg }),
+        }
+    } else {
+        Conversion {
+            generics: quote!(),
+            param: quote!(#ty),
+            value: quote!(#arg),
+        }
+    }
+}
+
+fn array_conversion(inner: &Type, arg: &Ident) -> Conversion {
+    if is_type(inner, "String") {
+        Conversion {
+            generics: quote!(<S: AsRef<str>>),
+            param: quote!(Vec<S>),
+            value: quote!(crate::private::owned_string_vector(#arg)),
+        }
+    } else if is_type(inner, "ColorWrapper") {
+        Conversion {
+            generics: quote!(<C: crate::common::color::Color>),
+            param: quote!(Vec<C>),
+            value: quote!(crate::private::to_color_array(#arg)),
+        }
+    } else if is_type(inner, "NumOrStringWrapper") {
+        Conversion {
+            generics: quote!(<V: crate::private::NumOrString>),
+            param: quote!(Vec<V>),
+            value: quote!(crate::private::to_num_or_string_wrapper(#arg)),
+        }
+    } else {
+        Conversion {
+            generics: quote!(),
+            param: quote!(Vec<#inner>),
+            value: quote!(#arg),
+        }
+    }
+}
+
+/// Returns `T` if `ty` is `wrapper<T>`.
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_type(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) => segment.ident == name && segment.arguments.is_empty(),
+            None => false,
+        },
+        _ => false,
+    }
+}
@@ -133,6 +133,32 @@ impl Kaleido {
         let mut dst = PathBuf::from(dst);
         dst.set_extension(image_format);
 
+        if let Some(image_data) =
+            self.image_data(plotly_data, image_format, width, height, scale)?
+        {
+            let data: Vec<u8> = match image_format {
+                "svg" | "eps" => image_data.as_bytes().to_vec(),
+                _ => base64::decode(image_data).unwrap(),
+            };
+            let mut file = File::create(dst.as_path())?;
+            file.write_all(&data)?;
+            file.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the plot and returns the image exactly as produced by Kaleido, without writing it
+    /// to disk. Text formats ("svg" and "eps") are returned verbatim while binary formats are
+    /// base64 encoded. Returns `None` if Kaleido did not produce an image.
+    pub fn image_data(
+        &self,
+        plotly_data: &str,
+        image_format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
         let p = self.cmd_path.as_path();
         let p = p.to_str().unwrap();
         let p = String::from(p);
@@ -156,23 +182,18 @@ impl Kaleido {
             process_stdin.flush()?;
         }
 
+        let mut image_data = None;
         let output_lines = BufReader::new(process.stdout.unwrap()).lines();
         for line in output_lines {
             if let Ok(l) = line {
                 let res = KaleidoResult::from(l.as_str());
-                if let Some(image_data) = res.result {
-                    let data: Vec<u8> = match image_format {
-                        "svg" | "eps" => image_data.as_bytes().to_vec(),
-                        _ => base64::decode(image_data).unwrap(),
-                    };
-                    let mut file = File::create(dst.as_path())?;
-                    file.write_all(&data)?;
-                    file.flush()?;
+                if res.result.is_some() {
+                    image_data = res.result;
                 }
             }
         }
 
-        Ok(())
+        Ok(image_data)
     }
 }
 